@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{interest_bearing_mint::InterestBearingConfig, BaseStateWithExtensions, StateWithExtensions},
+    state::Mint,
+};
+use crate::client::pda::*;
+use crate::state::*;
+
+/// Anything that can return raw account data, e.g. an RPC client or a test bank
+pub trait AccountFetcher {
+    fn get_account_data(&self, address: &Pubkey) -> Option<Vec<u8>>;
+}
+
+impl AccountFetcher for HashMap<Pubkey, Vec<u8>> {
+    fn get_account_data(&self, address: &Pubkey) -> Option<Vec<u8>> {
+        self.get(address).cloned()
+    }
+}
+
+pub fn decode_vault_config(data: &[u8]) -> Result<VaultConfig> {
+    VaultConfig::try_deserialize(&mut &data[..])
+}
+
+pub fn decode_whitelist_entry(data: &[u8]) -> Result<WhitelistEntry> {
+    WhitelistEntry::try_deserialize(&mut &data[..])
+}
+
+pub fn decode_vault_registry(data: &[u8]) -> Result<VaultRegistry> {
+    VaultRegistry::try_deserialize(&mut &data[..])
+}
+
+pub fn decode_multi_vault(data: &[u8]) -> Result<MultiVault> {
+    MultiVault::try_deserialize(&mut &data[..])
+}
+
+pub fn decode_vault_asset(data: &[u8]) -> Result<VaultAsset> {
+    VaultAsset::try_deserialize(&mut &data[..])
+}
+
+pub fn decode_withdrawal_ticket(data: &[u8]) -> Result<WithdrawalTicket> {
+    WithdrawalTicket::try_deserialize(&mut &data[..])
+}
+
+pub fn decode_vesting_schedule(data: &[u8]) -> Result<VestingSchedule> {
+    VestingSchedule::try_deserialize(&mut &data[..])
+}
+
+pub fn decode_proposal(data: &[u8]) -> Result<Proposal> {
+    Proposal::try_deserialize(&mut &data[..])
+}
+
+/// The mint's current interest rate in basis points, if it is interest-bearing
+pub fn decode_interest_rate(mint_data: &[u8]) -> Result<Option<i16>> {
+    let mint = StateWithExtensions::<Mint>::unpack(mint_data)?;
+    Ok(mint.get_extension::<InterestBearingConfig>().ok().map(|config| i16::from(config.current_rate)))
+}
+
+/// `amount` as Token-2022's `amount_to_ui_amount` would show it at
+/// `unix_timestamp`, including accrued interest
+pub fn decode_ui_amount(mint_data: &[u8], amount: u64, unix_timestamp: i64) -> Result<String> {
+    let mint = StateWithExtensions::<Mint>::unpack(mint_data)?;
+    let decimals = mint.base.decimals;
+    match mint.get_extension::<InterestBearingConfig>() {
+        Ok(config) => config
+            .amount_to_ui_amount(amount, decimals, unix_timestamp)
+            .ok_or_else(|| ProgramError::ArithmeticOverflow.into()),
+        Err(_) => Ok(spl_token_2022::amount_to_ui_amount_string_trimmed(amount, decimals)),
+    }
+}
+
+pub fn fetch_vault_config(fetcher: &impl AccountFetcher, mint: &Pubkey) -> Result<Option<VaultConfig>> {
+    fetcher.get_account_data(&derive_vault_config(mint).0).map(|d| decode_vault_config(&d)).transpose()
+}
+
+pub fn fetch_whitelist_entry(
+    fetcher: &impl AccountFetcher,
+    mint: &Pubkey,
+    user: &Pubkey,
+) -> Result<Option<WhitelistEntry>> {
+    let vault_config = derive_vault_config(mint).0;
+    fetcher
+        .get_account_data(&derive_whitelist_entry(&vault_config, user).0)
+        .map(|d| decode_whitelist_entry(&d))
+        .transpose()
+}
+
+pub fn fetch_vault_registry(fetcher: &impl AccountFetcher, group_mint: &Pubkey) -> Result<Option<VaultRegistry>> {
+    fetcher.get_account_data(&derive_vault_registry(group_mint).0).map(|d| decode_vault_registry(&d)).transpose()
+}
+
+pub fn fetch_multi_vault(fetcher: &impl AccountFetcher, multi_vault: &Pubkey) -> Result<Option<MultiVault>> {
+    fetcher.get_account_data(multi_vault).map(|d| decode_multi_vault(&d)).transpose()
+}
+
+pub fn fetch_vault_asset(fetcher: &impl AccountFetcher, multi_vault: &Pubkey, mint: &Pubkey) -> Result<Option<VaultAsset>> {
+    fetcher.get_account_data(&derive_vault_asset(multi_vault, mint).0).map(|d| decode_vault_asset(&d)).transpose()
+}
+
+pub fn fetch_withdrawal_ticket(
+    fetcher: &impl AccountFetcher,
+    mint: &Pubkey,
+    user: &Pubkey,
+) -> Result<Option<WithdrawalTicket>> {
+    let vault_config = derive_vault_config(mint).0;
+    fetcher
+        .get_account_data(&derive_withdrawal_ticket(&vault_config, user).0)
+        .map(|d| decode_withdrawal_ticket(&d))
+        .transpose()
+}
+
+pub fn fetch_vesting_schedule(
+    fetcher: &impl AccountFetcher,
+    mint: &Pubkey,
+    beneficiary: &Pubkey,
+    schedule_id: u64,
+) -> Result<Option<VestingSchedule>> {
+    let vault_config = derive_vault_config(mint).0;
+    fetcher
+        .get_account_data(&derive_vesting_schedule(&vault_config, beneficiary, schedule_id).0)
+        .map(|d| decode_vesting_schedule(&d))
+        .transpose()
+}
+
+pub fn fetch_proposal(fetcher: &impl AccountFetcher, mint: &Pubkey, withdrawer: &Pubkey) -> Result<Option<Proposal>> {
+    let vault_config = derive_vault_config(mint).0;
+    fetcher.get_account_data(&derive_proposal(&vault_config, withdrawer).0).map(|d| decode_proposal(&d)).transpose()
+}
+
+pub fn is_whitelisted(fetcher: &impl AccountFetcher, mint: &Pubkey, user: &Pubkey) -> Result<bool> {
+    Ok(fetch_whitelist_entry(fetcher, mint, user)?.is_some())
+}
@@ -0,0 +1,780 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{instruction::Instruction, system_program, sysvar};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::token_2022::spl_token_2022;
+use crate::client::pda::*;
+use crate::instructions::{ExtensionArgs, MetadataField};
+use crate::{accounts, instruction};
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn create_mint(authority: &Pubkey, mint: &Pubkey, decimals: u8, extension_args: ExtensionArgs) -> Instruction {
+    build(
+        accounts::CreateMint {
+            authority: *authority,
+            mint: *mint,
+            vault_config: derive_vault_config(mint).0,
+            group_mint: None,
+            group_vault_config: None,
+            registry: None,
+            token_program: spl_token_2022::ID,
+            system_program: system_program::ID,
+        },
+        instruction::CreateMint { decimals, extension_args },
+    )
+}
+
+/// Like `create_mint`, but the new mint joins `group_mint`'s token group and
+/// registry. The group's vault and registry must already exist.
+pub fn create_member_mint(
+    authority: &Pubkey,
+    mint: &Pubkey,
+    group_mint: &Pubkey,
+    decimals: u8,
+    extension_args: ExtensionArgs,
+) -> Instruction {
+    build(
+        accounts::CreateMint {
+            authority: *authority,
+            mint: *mint,
+            vault_config: derive_vault_config(mint).0,
+            group_mint: Some(*group_mint),
+            group_vault_config: Some(derive_vault_config(group_mint).0),
+            registry: Some(derive_vault_registry(group_mint).0),
+            token_program: spl_token_2022::ID,
+            system_program: system_program::ID,
+        },
+        instruction::CreateMint { decimals, extension_args },
+    )
+}
+
+/// `token_program` owns the mint: Token-2022, or legacy Token for a plain
+/// SPL mint
+pub fn initialize(authority: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Instruction {
+    build(
+        accounts::Initialize {
+            authority: *authority,
+            mint: *mint,
+            vault_config: derive_vault_config(mint).0,
+            vault: derive_vault(mint).0,
+            token_program: *token_program,
+            system_program: system_program::ID,
+        },
+        instruction::Initialize {},
+    )
+}
+
+/// `token_program` owns `mint`; it's only passed along with holder or
+/// credential accounts
+pub fn add_to_whitelist(
+    authority: &Pubkey,
+    mint: &Pubkey,
+    user: &Pubkey,
+    max_amount: u64,
+    holder_token_account: Option<&Pubkey>,
+    credential_account: Option<&Pubkey>,
+    token_program: &Pubkey,
+) -> Instruction {
+    let vault_config = derive_vault_config(mint).0;
+    build(
+        accounts::AddToWhitelist {
+            authority: *authority,
+            vault_config,
+            whitelist_entry: derive_whitelist_entry(&vault_config, user).0,
+            system_program: system_program::ID,
+            mint: holder_token_account.map(|_| *mint),
+            holder_token_account: holder_token_account.copied(),
+            token_program: (holder_token_account.is_some() || credential_account.is_some()).then_some(*token_program),
+            credential_mint: credential_account.map(|_| derive_credential_mint(&vault_config).0),
+            credential_account: credential_account.copied(),
+        },
+        instruction::AddToWhitelist { user: *user, max_amount },
+    )
+}
+
+pub fn remove_from_whitelist(
+    authority: &Pubkey,
+    mint: &Pubkey,
+    user: &Pubkey,
+    holder_token_account: Option<&Pubkey>,
+    credential_account: Option<&Pubkey>,
+    token_program: &Pubkey,
+) -> Instruction {
+    let vault_config = derive_vault_config(mint).0;
+    build(
+        accounts::RemoveFromWhitelist {
+            authority: *authority,
+            vault_config,
+            whitelist_entry: derive_whitelist_entry(&vault_config, user).0,
+            mint: holder_token_account.map(|_| *mint),
+            holder_token_account: holder_token_account.copied(),
+            token_program: (holder_token_account.is_some() || credential_account.is_some()).then_some(*token_program),
+            credential_mint: credential_account.map(|_| derive_credential_mint(&vault_config).0),
+            credential_account: credential_account.copied(),
+        },
+        instruction::RemoveFromWhitelist { user: *user },
+    )
+}
+
+pub fn update_whitelist_entry(authority: &Pubkey, mint: &Pubkey, user: &Pubkey, max_amount: u64) -> Instruction {
+    let vault_config = derive_vault_config(mint).0;
+    build(
+        accounts::UpdateWhitelistEntry {
+            authority: *authority,
+            vault_config,
+            whitelist_entry: derive_whitelist_entry(&vault_config, user).0,
+        },
+        instruction::UpdateWhitelistEntry { user: *user, max_amount },
+    )
+}
+
+/// Pass the token program the vault was initialized with
+pub fn deposit(
+    depositor: &Pubkey,
+    mint: &Pubkey,
+    depositor_token_account: &Pubkey,
+    amount: u64,
+    token_program: &Pubkey,
+) -> Instruction {
+    build(
+        accounts::Deposit {
+            depositor: *depositor,
+            mint: *mint,
+            vault_config: derive_vault_config(mint).0,
+            depositor_token_account: *depositor_token_account,
+            vault: derive_vault(mint).0,
+            token_program: *token_program,
+        },
+        instruction::Deposit { amount },
+    )
+}
+
+pub fn withdraw(
+    withdrawer: &Pubkey,
+    mint: &Pubkey,
+    withdrawer_token_account: &Pubkey,
+    amount: u64,
+    token_program: &Pubkey,
+) -> Instruction {
+    let vault_config = derive_vault_config(mint).0;
+    build(
+        accounts::Withdraw {
+            withdrawer: *withdrawer,
+            mint: *mint,
+            vault_config,
+            whitelist_entry: derive_whitelist_entry(&vault_config, withdrawer).0,
+            withdrawer_token_account: *withdrawer_token_account,
+            vault: derive_vault(mint).0,
+            token_program: *token_program,
+        },
+        instruction::Withdraw { amount },
+    )
+}
+
+pub fn freeze_account(authority: &Pubkey, mint: &Pubkey, token_account: &Pubkey, token_program: &Pubkey) -> Instruction {
+    build(
+        accounts::FreezeAccount {
+            authority: *authority,
+            vault_config: derive_vault_config(mint).0,
+            mint: *mint,
+            token_account: *token_account,
+            token_program: *token_program,
+        },
+        instruction::FreezeAccount {},
+    )
+}
+
+pub fn thaw_account(authority: &Pubkey, mint: &Pubkey, token_account: &Pubkey, token_program: &Pubkey) -> Instruction {
+    build(
+        accounts::ThawAccount {
+            authority: *authority,
+            vault_config: derive_vault_config(mint).0,
+            mint: *mint,
+            token_account: *token_account,
+            token_program: *token_program,
+        },
+        instruction::ThawAccount {},
+    )
+}
+
+/// Claws back into the vault unless a recovery `destination` is given
+pub fn clawback(
+    clawback_authority: &Pubkey,
+    mint: &Pubkey,
+    source: &Pubkey,
+    destination: Option<&Pubkey>,
+    amount: u64,
+    reason_code: u16,
+) -> Instruction {
+    build(
+        accounts::Clawback {
+            clawback_authority: *clawback_authority,
+            vault_config: derive_vault_config(mint).0,
+            mint: *mint,
+            source: *source,
+            destination: destination.copied().unwrap_or_else(|| derive_vault(mint).0),
+            token_program: spl_token_2022::ID,
+        },
+        instruction::Clawback { amount, reason_code },
+    )
+}
+
+pub fn set_clawback_authority(authority: &Pubkey, mint: &Pubkey, new_clawback_authority: &Pubkey) -> Instruction {
+    build(
+        accounts::SetClawbackAuthority {
+            authority: *authority,
+            vault_config: derive_vault_config(mint).0,
+        },
+        instruction::SetClawbackAuthority { new_clawback_authority: *new_clawback_authority },
+    )
+}
+
+pub fn update_token_metadata_field(authority: &Pubkey, mint: &Pubkey, field: MetadataField, value: String) -> Instruction {
+    build(
+        accounts::UpdateTokenMetadataField {
+            authority: *authority,
+            vault_config: derive_vault_config(mint).0,
+            mint: *mint,
+            token_program: spl_token_2022::ID,
+            system_program: system_program::ID,
+        },
+        instruction::UpdateTokenMetadataField { field, value },
+    )
+}
+
+pub fn remove_metadata_key(authority: &Pubkey, mint: &Pubkey, key: String, idempotent: bool) -> Instruction {
+    build(
+        accounts::RemoveMetadataKey {
+            authority: *authority,
+            vault_config: derive_vault_config(mint).0,
+            mint: *mint,
+            token_program: spl_token_2022::ID,
+        },
+        instruction::RemoveMetadataKey { key, idempotent },
+    )
+}
+
+pub fn update_interest_rate(authority: &Pubkey, mint: &Pubkey, rate: i16) -> Instruction {
+    build(
+        accounts::UpdateInterestRate {
+            authority: *authority,
+            vault_config: derive_vault_config(mint).0,
+            mint: *mint,
+            token_program: spl_token_2022::ID,
+        },
+        instruction::UpdateInterestRate { rate },
+    )
+}
+
+pub fn create_credential_mint(authority: &Pubkey, mint: &Pubkey) -> Instruction {
+    let vault_config = derive_vault_config(mint).0;
+    build(
+        accounts::CreateCredentialMint {
+            authority: *authority,
+            vault_config,
+            mint: *mint,
+            credential_mint: derive_credential_mint(&vault_config).0,
+            token_program: spl_token_2022::ID,
+            system_program: system_program::ID,
+        },
+        instruction::CreateCredentialMint {},
+    )
+}
+
+/// `entries` are the vault's remaining `WhitelistEntry`, `VestingSchedule`,
+/// `WithdrawalTicket` and `Proposal` addresses, each ticket or proposal
+/// followed by its user; pass the credential mint if the vault has one. `token_program` owns `mint`.
+pub fn close_vault(
+    authority: &Pubkey,
+    mint: &Pubkey,
+    credential_mint: Option<&Pubkey>,
+    entries: &[Pubkey],
+    token_program: &Pubkey,
+) -> Instruction {
+    let mut ix = build(
+        accounts::CloseVault {
+            authority: *authority,
+            vault_config: derive_vault_config(mint).0,
+            mint: *mint,
+            vault: derive_vault(mint).0,
+            credential_mint: credential_mint.copied(),
+            token_program: *token_program,
+        },
+        instruction::CloseVault {},
+    );
+    ix.accounts.extend(entries.iter().map(|address| AccountMeta::new(*address, false)));
+    ix
+}
+
+/// Where Token-2022 finds a confidential transfer proof: pre-verified in a
+/// context state account, or otherwise in the instruction right after this one
+fn proof_location(proof_context: Option<&Pubkey>) -> (Pubkey, i8) {
+    match proof_context {
+        Some(context) => (*context, 0),
+        None => (sysvar::instructions::ID, 1),
+    }
+}
+
+pub fn configure_confidential_vault(
+    authority: &Pubkey,
+    mint: &Pubkey,
+    decryptable_zero_balance: [u8; 36],
+    maximum_pending_balance_credit_counter: u64,
+    proof_context: Option<&Pubkey>,
+) -> Instruction {
+    let (proof, proof_instruction_offset) = proof_location(proof_context);
+    build(
+        accounts::ConfigureConfidentialVault {
+            authority: *authority,
+            vault_config: derive_vault_config(mint).0,
+            mint: *mint,
+            vault: derive_vault(mint).0,
+            proof,
+            token_program: spl_token_2022::ID,
+            system_program: system_program::ID,
+        },
+        instruction::ConfigureConfidentialVault {
+            decryptable_zero_balance,
+            maximum_pending_balance_credit_counter,
+            proof_instruction_offset,
+        },
+    )
+}
+
+pub fn approve_confidential_account(authority: &Pubkey, mint: &Pubkey, owner: &Pubkey, token_account: &Pubkey) -> Instruction {
+    let vault_config = derive_vault_config(mint).0;
+    build(
+        accounts::ApproveConfidentialAccount {
+            authority: *authority,
+            vault_config,
+            mint: *mint,
+            token_account: *token_account,
+            whitelist_entry: derive_whitelist_entry(&vault_config, owner).0,
+            token_program: spl_token_2022::ID,
+        },
+        instruction::ApproveConfidentialAccount {},
+    )
+}
+
+pub fn confidential_vault_deposit(authority: &Pubkey, mint: &Pubkey, amount: u64) -> Instruction {
+    build(
+        accounts::ConfidentialVaultDeposit {
+            authority: *authority,
+            vault_config: derive_vault_config(mint).0,
+            mint: *mint,
+            vault: derive_vault(mint).0,
+            token_program: spl_token_2022::ID,
+        },
+        instruction::ConfidentialVaultDeposit { amount },
+    )
+}
+
+pub fn apply_vault_pending_balance(
+    authority: &Pubkey,
+    mint: &Pubkey,
+    expected_pending_balance_credit_counter: u64,
+    new_decryptable_available_balance: [u8; 36],
+) -> Instruction {
+    build(
+        accounts::ApplyVaultPendingBalance {
+            authority: *authority,
+            vault_config: derive_vault_config(mint).0,
+            mint: *mint,
+            vault: derive_vault(mint).0,
+            token_program: spl_token_2022::ID,
+        },
+        instruction::ApplyVaultPendingBalance {
+            expected_pending_balance_credit_counter,
+            new_decryptable_available_balance,
+        },
+    )
+}
+
+/// `recipient` owns `recipient_token_account` and must be whitelisted
+pub fn confidential_withdraw(
+    authority: &Pubkey,
+    mint: &Pubkey,
+    recipient: &Pubkey,
+    recipient_token_account: &Pubkey,
+    new_source_decryptable_available_balance: [u8; 36],
+    proof_context: Option<&Pubkey>,
+) -> Instruction {
+    let vault_config = derive_vault_config(mint).0;
+    let (proof, proof_instruction_offset) = proof_location(proof_context);
+    build(
+        accounts::ConfidentialWithdraw {
+            authority: *authority,
+            vault_config,
+            mint: *mint,
+            vault: derive_vault(mint).0,
+            recipient_token_account: *recipient_token_account,
+            whitelist_entry: derive_whitelist_entry(&vault_config, recipient).0,
+            proof,
+            token_program: spl_token_2022::ID,
+        },
+        instruction::ConfidentialWithdraw {
+            new_source_decryptable_available_balance,
+            proof_instruction_offset,
+        },
+    )
+}
+
+pub fn create_vault_registry(authority: &Pubkey, group_mint: &Pubkey) -> Instruction {
+    build(
+        accounts::CreateVaultRegistry {
+            authority: *authority,
+            vault_config: derive_vault_config(group_mint).0,
+            mint: *group_mint,
+            registry: derive_vault_registry(group_mint).0,
+            system_program: system_program::ID,
+        },
+        instruction::CreateVaultRegistry {},
+    )
+}
+
+// Multi-mint vaults; `token_program` is whichever program owns `mint`
+
+pub fn create_multi_vault(authority: &Pubkey, vault_id: u64) -> Instruction {
+    build(
+        accounts::CreateMultiVault {
+            authority: *authority,
+            multi_vault: derive_multi_vault(authority, vault_id).0,
+            system_program: system_program::ID,
+        },
+        instruction::CreateMultiVault { vault_id },
+    )
+}
+
+pub fn add_vault_mint(
+    authority: &Pubkey,
+    multi_vault: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    max_amount: u64,
+) -> Instruction {
+    build(
+        accounts::AddVaultMint {
+            authority: *authority,
+            multi_vault: *multi_vault,
+            mint: *mint,
+            asset: derive_vault_asset(multi_vault, mint).0,
+            vault: derive_multi_vault_account(multi_vault, mint).0,
+            token_program: *token_program,
+            system_program: system_program::ID,
+        },
+        instruction::AddVaultMint { max_amount },
+    )
+}
+
+pub fn set_vault_mint_limit(authority: &Pubkey, multi_vault: &Pubkey, mint: &Pubkey, max_amount: u64) -> Instruction {
+    build(
+        accounts::SetVaultMintLimit {
+            authority: *authority,
+            multi_vault: *multi_vault,
+            asset: derive_vault_asset(multi_vault, mint).0,
+        },
+        instruction::SetVaultMintLimit { max_amount },
+    )
+}
+
+pub fn add_multi_vault_member(authority: &Pubkey, multi_vault: &Pubkey, user: &Pubkey) -> Instruction {
+    build(
+        accounts::AddMultiVaultMember {
+            authority: *authority,
+            multi_vault: *multi_vault,
+            whitelist_entry: derive_whitelist_entry(multi_vault, user).0,
+            system_program: system_program::ID,
+        },
+        instruction::AddMultiVaultMember { user: *user },
+    )
+}
+
+pub fn remove_multi_vault_member(authority: &Pubkey, multi_vault: &Pubkey, user: &Pubkey) -> Instruction {
+    build(
+        accounts::RemoveMultiVaultMember {
+            authority: *authority,
+            multi_vault: *multi_vault,
+            whitelist_entry: derive_whitelist_entry(multi_vault, user).0,
+        },
+        instruction::RemoveMultiVaultMember { user: *user },
+    )
+}
+
+pub fn multi_vault_deposit(
+    depositor: &Pubkey,
+    multi_vault: &Pubkey,
+    mint: &Pubkey,
+    depositor_token_account: &Pubkey,
+    amount: u64,
+    token_program: &Pubkey,
+) -> Instruction {
+    build(
+        accounts::MultiVaultDeposit {
+            depositor: *depositor,
+            multi_vault: *multi_vault,
+            mint: *mint,
+            asset: derive_vault_asset(multi_vault, mint).0,
+            depositor_token_account: *depositor_token_account,
+            vault: derive_multi_vault_account(multi_vault, mint).0,
+            token_program: *token_program,
+        },
+        instruction::MultiVaultDeposit { amount },
+    )
+}
+
+pub fn multi_vault_withdraw(
+    withdrawer: &Pubkey,
+    multi_vault: &Pubkey,
+    mint: &Pubkey,
+    withdrawer_token_account: &Pubkey,
+    amount: u64,
+    token_program: &Pubkey,
+) -> Instruction {
+    build(
+        accounts::MultiVaultWithdraw {
+            withdrawer: *withdrawer,
+            multi_vault: *multi_vault,
+            mint: *mint,
+            asset: derive_vault_asset(multi_vault, mint).0,
+            whitelist_entry: derive_whitelist_entry(multi_vault, withdrawer).0,
+            withdrawer_token_account: *withdrawer_token_account,
+            vault: derive_multi_vault_account(multi_vault, mint).0,
+            token_program: *token_program,
+        },
+        instruction::MultiVaultWithdraw { amount },
+    )
+}
+
+// Timelocked withdrawals on single-mint vaults
+
+pub fn set_withdrawal_timelock(authority: &Pubkey, mint: &Pubkey, withdrawal_delay: i64, guardian: &Pubkey) -> Instruction {
+    build(
+        accounts::SetWithdrawalTimelock {
+            authority: *authority,
+            vault_config: derive_vault_config(mint).0,
+        },
+        instruction::SetWithdrawalTimelock { withdrawal_delay, guardian: *guardian },
+    )
+}
+
+pub fn request_withdrawal(user: &Pubkey, mint: &Pubkey, amount: u64) -> Instruction {
+    let vault_config = derive_vault_config(mint).0;
+    build(
+        accounts::RequestWithdrawal {
+            user: *user,
+            vault_config,
+            whitelist_entry: derive_whitelist_entry(&vault_config, user).0,
+            withdrawal_ticket: derive_withdrawal_ticket(&vault_config, user).0,
+            system_program: system_program::ID,
+        },
+        instruction::RequestWithdrawal { amount },
+    )
+}
+
+pub fn claim_withdrawal(
+    withdrawer: &Pubkey,
+    mint: &Pubkey,
+    withdrawer_token_account: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    let vault_config = derive_vault_config(mint).0;
+    build(
+        accounts::ClaimWithdrawal {
+            withdrawer: *withdrawer,
+            mint: *mint,
+            vault_config,
+            whitelist_entry: derive_whitelist_entry(&vault_config, withdrawer).0,
+            withdrawal_ticket: derive_withdrawal_ticket(&vault_config, withdrawer).0,
+            withdrawer_token_account: *withdrawer_token_account,
+            vault: derive_vault(mint).0,
+            token_program: *token_program,
+        },
+        instruction::ClaimWithdrawal {},
+    )
+}
+
+pub fn cancel_withdrawal(user: &Pubkey, mint: &Pubkey) -> Instruction {
+    let vault_config = derive_vault_config(mint).0;
+    build(
+        accounts::CancelWithdrawal {
+            user: *user,
+            vault_config,
+            withdrawal_ticket: derive_withdrawal_ticket(&vault_config, user).0,
+        },
+        instruction::CancelWithdrawal {},
+    )
+}
+
+pub fn veto_withdrawal(guardian: &Pubkey, mint: &Pubkey, user: &Pubkey) -> Instruction {
+    let vault_config = derive_vault_config(mint).0;
+    build(
+        accounts::VetoWithdrawal {
+            guardian: *guardian,
+            vault_config,
+            user: *user,
+            withdrawal_ticket: derive_withdrawal_ticket(&vault_config, user).0,
+        },
+        instruction::VetoWithdrawal {},
+    )
+}
+
+// Vesting schedules, paid out of a single-mint vault
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_vesting(
+    authority: &Pubkey,
+    mint: &Pubkey,
+    beneficiary: &Pubkey,
+    schedule_id: u64,
+    total: u64,
+    start: i64,
+    cliff: i64,
+    end: i64,
+) -> Instruction {
+    let vault_config = derive_vault_config(mint).0;
+    build(
+        accounts::CreateVesting {
+            authority: *authority,
+            vault_config,
+            vault: derive_vault(mint).0,
+            vesting_schedule: derive_vesting_schedule(&vault_config, beneficiary, schedule_id).0,
+            system_program: system_program::ID,
+        },
+        instruction::CreateVesting { beneficiary: *beneficiary, schedule_id, total, start, cliff, end },
+    )
+}
+
+pub fn withdraw_vested(
+    beneficiary: &Pubkey,
+    mint: &Pubkey,
+    schedule_id: u64,
+    beneficiary_token_account: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    let vault_config = derive_vault_config(mint).0;
+    build(
+        accounts::WithdrawVested {
+            beneficiary: *beneficiary,
+            mint: *mint,
+            vault_config,
+            vesting_schedule: derive_vesting_schedule(&vault_config, beneficiary, schedule_id).0,
+            beneficiary_token_account: *beneficiary_token_account,
+            vault: derive_vault(mint).0,
+            token_program: *token_program,
+        },
+        instruction::WithdrawVested { schedule_id },
+    )
+}
+
+pub fn close_vesting(authority: &Pubkey, mint: &Pubkey, beneficiary: &Pubkey, schedule_id: u64) -> Instruction {
+    let vault_config = derive_vault_config(mint).0;
+    build(
+        accounts::CloseVesting {
+            authority: *authority,
+            vault_config,
+            vesting_schedule: derive_vesting_schedule(&vault_config, beneficiary, schedule_id).0,
+        },
+        instruction::CloseVesting {},
+    )
+}
+
+// Multisig approval of withdrawals above a vault's threshold
+
+pub fn set_approvers(authority: &Pubkey, mint: &Pubkey, approvers: &[Pubkey], quorum: u8, threshold: u64) -> Instruction {
+    build(
+        accounts::SetApprovers {
+            authority: *authority,
+            vault_config: derive_vault_config(mint).0,
+        },
+        instruction::SetApprovers { approvers: approvers.to_vec(), quorum, threshold },
+    )
+}
+
+pub fn propose_withdrawal(withdrawer: &Pubkey, mint: &Pubkey, withdrawer_token_account: &Pubkey, amount: u64) -> Instruction {
+    let vault_config = derive_vault_config(mint).0;
+    build(
+        accounts::ProposeWithdrawal {
+            withdrawer: *withdrawer,
+            vault_config,
+            whitelist_entry: derive_whitelist_entry(&vault_config, withdrawer).0,
+            withdrawer_token_account: *withdrawer_token_account,
+            proposal: derive_proposal(&vault_config, withdrawer).0,
+            system_program: system_program::ID,
+        },
+        instruction::ProposeWithdrawal { amount },
+    )
+}
+
+/// `destination` must be the token account named in the proposal
+pub fn approve_withdrawal(
+    approver: &Pubkey,
+    mint: &Pubkey,
+    withdrawer: &Pubkey,
+    destination: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    let vault_config = derive_vault_config(mint).0;
+    build(
+        accounts::ApproveWithdrawal {
+            approver: *approver,
+            mint: *mint,
+            vault_config,
+            withdrawer: *withdrawer,
+            whitelist_entry: derive_whitelist_entry(&vault_config, withdrawer).0,
+            proposal: derive_proposal(&vault_config, withdrawer).0,
+            destination: *destination,
+            vault: derive_vault(mint).0,
+            token_program: *token_program,
+        },
+        instruction::ApproveWithdrawal {},
+    )
+}
+
+pub fn cancel_proposal(withdrawer: &Pubkey, mint: &Pubkey) -> Instruction {
+    let vault_config = derive_vault_config(mint).0;
+    build(
+        accounts::CancelProposal {
+            withdrawer: *withdrawer,
+            vault_config,
+            proposal: derive_proposal(&vault_config, withdrawer).0,
+        },
+        instruction::CancelProposal {},
+    )
+}
+
+/// `destination` must be the token account named in the proposal
+pub fn execute_proposal(withdrawer: &Pubkey, mint: &Pubkey, destination: &Pubkey, token_program: &Pubkey) -> Instruction {
+    let vault_config = derive_vault_config(mint).0;
+    build(
+        accounts::ExecuteProposal {
+            withdrawer: *withdrawer,
+            mint: *mint,
+            vault_config,
+            whitelist_entry: derive_whitelist_entry(&vault_config, withdrawer).0,
+            proposal: derive_proposal(&vault_config, withdrawer).0,
+            destination: *destination,
+            vault: derive_vault(mint).0,
+            token_program: *token_program,
+        },
+        instruction::ExecuteProposal {},
+    )
+}
+
+pub fn veto_proposal(guardian: &Pubkey, mint: &Pubkey, withdrawer: &Pubkey) -> Instruction {
+    let vault_config = derive_vault_config(mint).0;
+    build(
+        accounts::VetoProposal {
+            guardian: *guardian,
+            vault_config,
+            withdrawer: *withdrawer,
+            proposal: derive_proposal(&vault_config, withdrawer).0,
+        },
+        instruction::VetoProposal {},
+    )
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+pub fn derive_vault_config(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_CONFIG_SEED, mint.as_ref()], &crate::ID)
+}
+
+pub fn derive_vault(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_SEED, mint.as_ref()], &crate::ID)
+}
+
+pub fn derive_whitelist_entry(vault_config: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[WHITELIST_ENTRY_SEED, vault_config.as_ref(), user.as_ref()],
+        &crate::ID,
+    )
+}
+
+/// The soulbound mint whitelisted users hold, once `create_credential_mint`
+/// has run
+pub fn derive_credential_mint(vault_config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CREDENTIAL_MINT_SEED, vault_config.as_ref()], &crate::ID)
+}
+
+/// Lists the member mints of a group mint's family
+pub fn derive_vault_registry(group_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_REGISTRY_SEED, group_mint.as_ref()], &crate::ID)
+}
+
+pub fn derive_multi_vault(authority: &Pubkey, vault_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MULTI_VAULT_SEED, authority.as_ref(), &vault_id.to_le_bytes()], &crate::ID)
+}
+
+pub fn derive_vault_asset(multi_vault: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_ASSET_SEED, multi_vault.as_ref(), mint.as_ref()], &crate::ID)
+}
+
+/// The token account holding a multi-mint vault's balance of `mint`
+pub fn derive_multi_vault_account(multi_vault: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_SEED, multi_vault.as_ref(), mint.as_ref()], &crate::ID)
+}
+
+/// A user's pending withdrawal from a timelocked vault
+pub fn derive_withdrawal_ticket(vault_config: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[WITHDRAWAL_TICKET_SEED, vault_config.as_ref(), user.as_ref()], &crate::ID)
+}
+
+pub fn derive_vesting_schedule(vault_config: &Pubkey, beneficiary: &Pubkey, schedule_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[VESTING_SCHEDULE_SEED, vault_config.as_ref(), beneficiary.as_ref(), &schedule_id.to_le_bytes()],
+        &crate::ID,
+    )
+}
+
+/// A user's open withdrawal proposal, awaiting the vault's approvers
+pub fn derive_proposal(vault_config: &Pubkey, withdrawer: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PROPOSAL_SEED, vault_config.as_ref(), withdrawer.as_ref()], &crate::ID)
+}
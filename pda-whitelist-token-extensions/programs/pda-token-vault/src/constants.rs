@@ -1,3 +1,19 @@
+use anchor_lang::solana_program::{pubkey, pubkey::Pubkey};
+
 pub const VAULT_CONFIG_SEED: &[u8] = b"vault_config";
 pub const VAULT_SEED: &[u8] = b"vault";
 pub const WHITELIST_ENTRY_SEED: &[u8] = b"whitelist_entry";
+pub const CREDENTIAL_MINT_SEED: &[u8] = b"credential_mint";
+pub const VAULT_REGISTRY_SEED: &[u8] = b"vault_registry";
+pub const MULTI_VAULT_SEED: &[u8] = b"multi_vault";
+pub const VAULT_ASSET_SEED: &[u8] = b"vault_asset";
+pub const WITHDRAWAL_TICKET_SEED: &[u8] = b"withdrawal_ticket";
+pub const VESTING_SCHEDULE_SEED: &[u8] = b"vesting_schedule";
+pub const PROPOSAL_SEED: &[u8] = b"proposal";
+
+/// Size of a vault's withdrawal approver set
+pub const MAX_APPROVERS: usize = 5;
+
+/// Credentials are only issued to and burned from the holder's associated
+/// token account
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
@@ -22,4 +22,112 @@ pub enum VaultError {
     
     #[msg("Invalid extension configuration")]
     InvalidExtensionConfig,
+    
+    #[msg("Mint's permanent delegate is not the vault config")]
+    PermanentDelegateNotVault,
+    
+    #[msg("Clawback requires a non-zero reason code")]
+    MissingReasonCode,
+    
+    #[msg("Clawback source and destination must differ")]
+    InvalidClawbackDestination,
+    
+    #[msg("Mint, holder token account and token program must be passed together")]
+    IncompleteHolderAccounts,
+    
+    #[msg("Token account is not owned by the user")]
+    HolderMismatch,
+    
+    #[msg("Mint has no token metadata")]
+    MetadataNotInitialized,
+    
+    #[msg("Mint is not interest-bearing")]
+    InterestBearingNotEnabled,
+    
+    #[msg("Vault already has a credential mint")]
+    CredentialMintExists,
+    
+    #[msg("Credential account is not for the vault's credential mint")]
+    InvalidCredentialMint,
+    
+    #[msg("Vault issues credentials; pass the credential mint, the user's credential account and the token program")]
+    MissingCredentialAccounts,
+    
+    #[msg("Vault still holds tokens")]
+    VaultNotEmpty,
+    
+    #[msg("Account does not belong to this vault")]
+    ForeignAccount,
+    
+    #[msg("Credentials are still held; remove those users from the whitelist first")]
+    CredentialsOutstanding,
+    
+    #[msg("Mint does not allow confidential transfers")]
+    ConfidentialTransferNotEnabled,
+    
+    #[msg("Confidential amounts are hidden, so only entries without a limit can receive them")]
+    ConfidentialLimitUnenforceable,
+    
+    #[msg("Mint is not a token group")]
+    NotATokenGroup,
+    
+    #[msg("Joining a group needs the group mint, its vault config and its registry")]
+    IncompleteGroupAccounts,
+    
+    #[msg("Vault has a withdrawal delay; request the withdrawal and claim it once unlocked")]
+    WithdrawalTimelocked,
+    
+    #[msg("Withdrawal ticket is still locked")]
+    WithdrawalLocked,
+    
+    #[msg("Withdrawal delay cannot be negative")]
+    InvalidWithdrawalDelay,
+    
+    #[msg("Vesting needs a non-zero total and start <= cliff <= end")]
+    InvalidVestingSchedule,
+    
+    #[msg("Vault balance is reserved for vesting schedules")]
+    VestingReserved,
+    
+    #[msg("Nothing has vested since the last withdrawal")]
+    NothingVested,
+    
+    #[msg("Approvers must be distinct, within the maximum, and at least as many as the quorum")]
+    InvalidApproverSet,
+    
+    #[msg("Amount is above the approval threshold; propose the withdrawal instead")]
+    ApprovalRequired,
+    
+    #[msg("Amount is within the approval threshold; withdraw directly")]
+    ApprovalNotRequired,
+    
+    #[msg("Signer is not a withdrawal approver")]
+    NotAnApprover,
+    
+    #[msg("Approver has already approved this proposal")]
+    AlreadyApproved,
+    
+    #[msg("Confidential withdrawals are disabled while a timelock, vesting or approval quorum is configured")]
+    ConfidentialWithdrawRestricted,
+    
+    #[msg("This feature needs a Token-2022 mint; legacy Token mints don't support it")]
+    RequiresToken2022,
+    
+    #[msg("Withdrawal tickets, vesting schedules or proposals are still open; close them with the vault")]
+    OpenClaims,
+    
+    #[msg("Rent must go back to the account that paid it")]
+    RentRecipientMismatch,
+    
+    #[msg("Vesting schedule still has unreleased tokens")]
+    VestingNotFullyReleased,
+    
+    #[msg("Proposal doesn't have enough approvals yet")]
+    QuorumNotReached,
+    
+    #[msg("Only the mint authority can initialize a vault for this mint")]
+    NotMintAuthority,
+    
+    #[msg("Credentials must be held in the user's associated token account")]
+    CredentialNotAssociated,
 }
@@ -0,0 +1,350 @@
+use anchor_lang::prelude::*;
+use crate::instructions::MetadataField;
+
+#[event]
+pub struct MintCreated {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub decimals: u8,
+    pub transfer_fee_basis_points: Option<u16>,
+    pub permanent_delegate: bool,
+    pub default_frozen: bool,
+    pub interest_rate: Option<i16>,
+    pub mint_close_authority: bool,
+    pub confidential_transfer: bool,
+    /// Set if the mint is a token group
+    pub group_max_size: Option<u32>,
+    /// Group mint this mint joined as a member
+    pub group: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultInitialized {
+    pub vault_config: Pubkey,
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WhitelistAdded {
+    /// Vault config, or the multi-mint vault
+    pub vault_config: Pubkey,
+    pub user: Pubkey,
+    pub max_amount: u64,
+    pub timestamp: i64,
+}
+
+/// A limit change that keeps the entry and its credential
+#[event]
+pub struct WhitelistUpdated {
+    pub vault_config: Pubkey,
+    pub user: Pubkey,
+    pub max_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WhitelistRemoved {
+    /// Vault config, or the multi-mint vault
+    pub vault_config: Pubkey,
+    pub user: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct Deposited {
+    /// Vault config, or the multi-mint vault
+    pub vault_config: Pubkey,
+    pub mint: Pubkey,
+    pub depositor: Pubkey,
+    pub amount: u64,
+    /// `amount` as the token program displays it, including accrued interest
+    pub ui_amount: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct Withdrew {
+    /// Vault config, or the multi-mint vault
+    pub vault_config: Pubkey,
+    pub mint: Pubkey,
+    pub withdrawer: Pubkey,
+    pub amount: u64,
+    /// `amount` as the token program displays it, including accrued interest
+    pub ui_amount: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountFrozen {
+    pub vault_config: Pubkey,
+    pub token_account: Pubkey,
+    pub owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountThawed {
+    pub vault_config: Pubkey,
+    pub token_account: Pubkey,
+    pub owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ClawedBack {
+    pub vault_config: Pubkey,
+    pub source: Pubkey,
+    pub owner: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub reason_code: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ClawbackAuthorityChanged {
+    pub vault_config: Pubkey,
+    pub previous: Pubkey,
+    pub new: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokenMetadataUpdated {
+    pub vault_config: Pubkey,
+    pub mint: Pubkey,
+    pub field: MetadataField,
+    pub value: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokenMetadataKeyRemoved {
+    pub vault_config: Pubkey,
+    pub mint: Pubkey,
+    pub key: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InterestRateUpdated {
+    pub vault_config: Pubkey,
+    pub mint: Pubkey,
+    pub previous_rate: i16,
+    pub new_rate: i16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CredentialMintCreated {
+    pub vault_config: Pubkey,
+    pub credential_mint: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CredentialIssued {
+    pub vault_config: Pubkey,
+    pub user: Pubkey,
+    pub credential_account: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CredentialRevoked {
+    pub vault_config: Pubkey,
+    pub user: Pubkey,
+    pub credential_account: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultClosed {
+    pub vault_config: Pubkey,
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    /// Whitelist entries, withdrawal tickets and vesting schedules closed
+    /// alongside the vault
+    pub accounts_closed: u32,
+    pub mint_closed: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfidentialVaultConfigured {
+    pub vault_config: Pubkey,
+    pub vault: Pubkey,
+    /// Key the vault's confidential balance is encrypted under
+    pub elgamal_pubkey: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfidentialAccountApproved {
+    pub vault_config: Pubkey,
+    pub owner: Pubkey,
+    pub token_account: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfidentialVaultDeposited {
+    pub vault_config: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultPendingBalanceApplied {
+    pub vault_config: Pubkey,
+    /// Pending credits folded into the available balance
+    pub credits: u64,
+    pub timestamp: i64,
+}
+
+/// The amount is only visible to the vault, the recipient and the auditor
+#[event]
+pub struct ConfidentialWithdrawal {
+    pub vault_config: Pubkey,
+    pub recipient: Pubkey,
+    pub recipient_token_account: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultRegistryCreated {
+    pub registry: Pubkey,
+    pub group_mint: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GroupMemberAdded {
+    pub registry: Pubkey,
+    pub group_mint: Pubkey,
+    pub member_mint: Pubkey,
+    /// Token-2022's member number, starting at 1
+    pub member_number: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultiVaultCreated {
+    pub multi_vault: Pubkey,
+    pub authority: Pubkey,
+    pub vault_id: u64,
+    pub timestamp: i64,
+}
+
+/// A mint was added to a multi-mint vault or its limit changed
+#[event]
+pub struct VaultMintConfigured {
+    pub multi_vault: Pubkey,
+    pub mint: Pubkey,
+    pub token_program: Pubkey,
+    pub max_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WithdrawalTimelockSet {
+    pub vault_config: Pubkey,
+    pub withdrawal_delay: i64,
+    pub guardian: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WithdrawalRequested {
+    pub vault_config: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub unlock_time: i64,
+    pub timestamp: i64,
+}
+
+/// A ticket closed without paying out, by its user or vetoed by the guardian
+#[event]
+pub struct WithdrawalCancelled {
+    pub vault_config: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub cancelled_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VestingCreated {
+    pub vault_config: Pubkey,
+    pub beneficiary: Pubkey,
+    pub schedule_id: u64,
+    pub total: u64,
+    pub start: i64,
+    pub cliff: i64,
+    pub end: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VestedWithdrawn {
+    pub vault_config: Pubkey,
+    pub beneficiary: Pubkey,
+    pub schedule_id: u64,
+    pub amount: u64,
+    /// Released so far, including this withdrawal
+    pub released: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VestingClosed {
+    pub vault_config: Pubkey,
+    pub beneficiary: Pubkey,
+    pub schedule_id: u64,
+    pub total: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ApproversSet {
+    pub vault_config: Pubkey,
+    pub approvers: Vec<Pubkey>,
+    pub quorum: u8,
+    pub threshold: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WithdrawalProposed {
+    pub vault_config: Pubkey,
+    pub proposal: Pubkey,
+    pub withdrawer: Pubkey,
+    pub amount: u64,
+    pub unlock_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WithdrawalApproved {
+    pub vault_config: Pubkey,
+    pub proposal: Pubkey,
+    pub approver: Pubkey,
+    /// Approvals counted towards the quorum, including this one
+    pub approvals: u8,
+    pub executed: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalCancelled {
+    pub vault_config: Pubkey,
+    pub withdrawer: Pubkey,
+    pub amount: u64,
+    /// The withdrawer, or the guardian on a veto
+    pub cancelled_by: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
+
+/// Let a user withdraw from a multi-mint vault. Entries carry no amount
+/// limit of their own, since the vault's mints differ in decimals and value;
+/// each mint's limit lives on its `VaultAsset`.
+#[derive(Accounts)]
+#[instruction(user: Pubkey)]
+pub struct AddMultiVaultMember<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [MULTI_VAULT_SEED, authority.key().as_ref(), &multi_vault.vault_id.to_le_bytes()],
+        bump = multi_vault.bump,
+        has_one = authority @ VaultError::UnauthorizedAuthority,
+    )]
+    pub multi_vault: Account<'info, MultiVault>,
+    
+    /// Seeds: ["whitelist_entry", multi_vault, user]
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + WhitelistEntry::INIT_SPACE,
+        seeds = [WHITELIST_ENTRY_SEED, multi_vault.key().as_ref(), user.as_ref()],
+        bump,
+    )]
+    pub whitelist_entry: Account<'info, WhitelistEntry>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<AddMultiVaultMember>, user: Pubkey) -> Result<()> {
+    let whitelist_entry = &mut ctx.accounts.whitelist_entry;
+    
+    whitelist_entry.user = user;
+    whitelist_entry.max_amount = 0;
+    whitelist_entry.vault_config = ctx.accounts.multi_vault.key();
+    whitelist_entry.bump = ctx.bumps.whitelist_entry;
+    
+    emit!(WhitelistAdded {
+        vault_config: whitelist_entry.vault_config,
+        user,
+        max_amount: 0,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -1,6 +1,10 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use crate::constants::*;
 use crate::error::VaultError;
+use crate::events::*;
+use crate::instructions::credential::sync_credential;
+use crate::instructions::holder::sync_holder_frozen;
 use crate::state::*;
 
 /// Add a user to the whitelist by creating their PDA account
@@ -29,9 +33,35 @@ pub struct AddToWhitelist<'info> {
     pub whitelist_entry: Account<'info, WhitelistEntry>,
     
     pub system_program: Program<'info, System>,
+    
+    /// Optional with `holder_token_account` and `token_program`: the user's
+    /// token account is thawed via the vault config's freeze authority
+    #[account(address = vault_config.mint @ VaultError::InvalidMint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        mut,
+        constraint = holder_token_account.mint == vault_config.mint @ VaultError::InvalidMint,
+        constraint = holder_token_account.owner == user @ VaultError::HolderMismatch,
+    )]
+    pub holder_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    
+    /// Required with `credential_account` and `token_program` once the vault
+    /// has a credential mint: a credential is minted to the user
+    #[account(mut, address = vault_config.credential_mint @ VaultError::InvalidCredentialMint)]
+    pub credential_mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        mut,
+        constraint = credential_account.mint == vault_config.credential_mint @ VaultError::InvalidCredentialMint,
+        constraint = credential_account.owner == user @ VaultError::HolderMismatch,
+    )]
+    pub credential_account: Option<InterfaceAccount<'info, TokenAccount>>,
 }
 
-pub fn handler(ctx: Context<AddToWhitelist>, user: Pubkey, max_amount: u64) -> Result<()> {
+pub(crate) fn handler(ctx: Context<AddToWhitelist>, user: Pubkey, max_amount: u64) -> Result<()> {
     let whitelist_entry = &mut ctx.accounts.whitelist_entry;
     
     whitelist_entry.user = user;
@@ -39,6 +69,26 @@ pub fn handler(ctx: Context<AddToWhitelist>, user: Pubkey, max_amount: u64) -> R
     whitelist_entry.vault_config = ctx.accounts.vault_config.key();
     whitelist_entry.bump = ctx.bumps.whitelist_entry;
     
-    msg!("Added {} to whitelist (max: {})", user, max_amount);
+    sync_holder_frozen(
+        false,
+        &ctx.accounts.vault_config,
+        ctx.accounts.mint.as_ref(),
+        ctx.accounts.holder_token_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    )?;
+    sync_credential(
+        true,
+        &ctx.accounts.vault_config,
+        ctx.accounts.credential_mint.as_ref(),
+        ctx.accounts.credential_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    )?;
+    
+    emit!(WhitelistAdded {
+        vault_config: whitelist_entry.vault_config,
+        user,
+        max_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
     Ok(())
 }
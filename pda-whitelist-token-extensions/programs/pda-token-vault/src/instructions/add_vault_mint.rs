@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::instructions::vault_account::create_vault_account;
+use crate::state::*;
+
+/// Accept a mint in a multi-mint vault, creating its token account. Works
+/// with Token-2022 and legacy Token mints alike.
+#[derive(Accounts)]
+pub struct AddVaultMint<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [MULTI_VAULT_SEED, authority.key().as_ref(), &multi_vault.vault_id.to_le_bytes()],
+        bump = multi_vault.bump,
+        has_one = authority @ VaultError::UnauthorizedAuthority,
+    )]
+    pub multi_vault: Account<'info, MultiVault>,
+    
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + VaultAsset::INIT_SPACE,
+        seeds = [VAULT_ASSET_SEED, multi_vault.key().as_ref(), mint.key().as_ref()],
+        bump,
+    )]
+    pub asset: Account<'info, VaultAsset>,
+    
+    /// CHECK: Created in the handler as a token account that is its own authority
+    #[account(mut, seeds = [VAULT_SEED, multi_vault.key().as_ref(), mint.key().as_ref()], bump)]
+    pub vault: UncheckedAccount<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<AddVaultMint>, max_amount: u64) -> Result<()> {
+    let multi_vault_key = ctx.accounts.multi_vault.key();
+    let mint_key = ctx.accounts.mint.key();
+    let seeds: &[&[u8]] = &[VAULT_SEED, multi_vault_key.as_ref(), mint_key.as_ref(), &[ctx.bumps.vault]];
+    create_vault_account(
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        seeds,
+    )?;
+    
+    let asset = &mut ctx.accounts.asset;
+    asset.multi_vault = multi_vault_key;
+    asset.mint = mint_key;
+    asset.token_program = ctx.accounts.token_program.key();
+    asset.max_amount = max_amount;
+    asset.vault_bump = ctx.bumps.vault;
+    asset.bump = ctx.bumps.asset;
+    
+    let multi_vault = &mut ctx.accounts.multi_vault;
+    multi_vault.mint_count = multi_vault.mint_count.checked_add(1).ok_or(ProgramError::ArithmeticOverflow)?;
+    
+    emit!(VaultMintConfigured {
+        multi_vault: multi_vault_key,
+        mint: mint_key,
+        token_program: asset.token_program,
+        max_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::{
+    token_2022::{
+        spl_token_2022::{
+            self,
+            extension::confidential_transfer::instruction::inner_apply_pending_balance,
+            solana_zk_token_sdk::zk_token_elgamal::pod::AeCiphertext,
+        },
+        Token2022,
+    },
+    token_interface::{Mint, TokenAccount},
+};
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
+
+/// Credit confidential transfers and deposits received by the vault to its
+/// available balance. The authority decrypts the pending balance off-chain
+/// and supplies the new balance encrypted under the vault's AE key.
+#[derive(Accounts)]
+pub struct ApplyVaultPendingBalance<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [VAULT_CONFIG_SEED, mint.key().as_ref()],
+        bump = vault_config.config_bump,
+        has_one = authority @ VaultError::UnauthorizedAuthority,
+        has_one = mint @ VaultError::InvalidMint,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    #[account(owner = Token2022::id() @ VaultError::RequiresToken2022)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, mint.key().as_ref()],
+        bump = vault_config.vault_bump,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<ApplyVaultPendingBalance>,
+    expected_pending_balance_credit_counter: u64,
+    new_decryptable_available_balance: [u8; 36],
+) -> Result<()> {
+    let mint_key = ctx.accounts.mint.key();
+    let vault = ctx.accounts.vault.to_account_info();
+    let vault_seeds = &[VAULT_SEED, mint_key.as_ref(), &[ctx.accounts.vault_config.vault_bump]];
+    
+    invoke_signed(
+        &inner_apply_pending_balance(
+            &spl_token_2022::ID,
+            vault.key,
+            expected_pending_balance_credit_counter,
+            AeCiphertext(new_decryptable_available_balance),
+            vault.key,
+            &[],
+        )?,
+        std::slice::from_ref(&vault),
+        &[&vault_seeds[..]],
+    )?;
+    
+    emit!(VaultPendingBalanceApplied {
+        vault_config: ctx.accounts.vault_config.key(),
+        credits: expected_pending_balance_credit_counter,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
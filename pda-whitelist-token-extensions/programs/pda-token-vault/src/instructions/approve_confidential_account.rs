@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::{
+    token_2022::{
+        spl_token_2022::{self, extension::confidential_transfer::instruction::approve_account},
+        Token2022,
+    },
+    token_interface::{Mint, TokenAccount},
+};
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::instructions::confidential::auto_approves;
+use crate::state::*;
+
+/// Approve a whitelisted holder's configured account for confidential
+/// transfers, for mints that don't auto-approve new accounts
+#[derive(Accounts)]
+pub struct ApproveConfidentialAccount<'info> {
+    pub authority: Signer<'info>,
+    
+    /// Confidential transfer authority of the mint
+    #[account(
+        seeds = [VAULT_CONFIG_SEED, mint.key().as_ref()],
+        bump = vault_config.config_bump,
+        has_one = authority @ VaultError::UnauthorizedAuthority,
+        has_one = mint @ VaultError::InvalidMint,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    #[account(owner = Token2022::id() @ VaultError::RequiresToken2022)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(mut, token::mint = mint)]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// The token account's owner must be whitelisted
+    #[account(
+        seeds = [WHITELIST_ENTRY_SEED, vault_config.key().as_ref(), token_account.owner.as_ref()],
+        bump = whitelist_entry.bump,
+    )]
+    pub whitelist_entry: Account<'info, WhitelistEntry>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub(crate) fn handler(ctx: Context<ApproveConfidentialAccount>) -> Result<()> {
+    let mint = ctx.accounts.mint.to_account_info();
+    auto_approves(&mint)?;
+    
+    let mint_key = mint.key();
+    let vault_config = ctx.accounts.vault_config.to_account_info();
+    let config_seeds = &[VAULT_CONFIG_SEED, mint_key.as_ref(), &[ctx.accounts.vault_config.config_bump]];
+    
+    invoke_signed(
+        &approve_account(&spl_token_2022::ID, &ctx.accounts.token_account.key(), &mint_key, vault_config.key, &[])?,
+        &[ctx.accounts.token_account.to_account_info(), mint, vault_config.clone()],
+        &[&config_seeds[..]],
+    )?;
+    
+    emit!(ConfidentialAccountApproved {
+        vault_config: vault_config.key(),
+        owner: ctx.accounts.token_account.owner,
+        token_account: ctx.accounts.token_account.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
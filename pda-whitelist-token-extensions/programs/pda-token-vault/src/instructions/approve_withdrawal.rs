@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked, transfer_checked};
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::instructions::ui_amount::ui_amount;
+use crate::state::*;
+
+/// Record an approver's sign-off. The approval that reaches the quorum pays
+/// the withdrawal out and closes the proposal if it has unlocked, so every
+/// approval carries the accounts the transfer needs; otherwise the
+/// withdrawer runs `execute_proposal` once it unlocks.
+#[derive(Accounts)]
+pub struct ApproveWithdrawal<'info> {
+    pub approver: Signer<'info>,
+    
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED, mint.key().as_ref()],
+        bump = vault_config.config_bump,
+        constraint = vault_config.approvers.contains(&approver.key()) @ VaultError::NotAnApprover,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    /// CHECK: Receives the proposal's rent; must be its withdrawer
+    #[account(mut, address = proposal.withdrawer)]
+    pub withdrawer: UncheckedAccount<'info>,
+    
+    /// The withdrawer must still be whitelisted when the quorum is reached
+    #[account(
+        seeds = [WHITELIST_ENTRY_SEED, vault_config.key().as_ref(), withdrawer.key().as_ref()],
+        bump = whitelist_entry.bump,
+    )]
+    pub whitelist_entry: Account<'info, WhitelistEntry>,
+    
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, vault_config.key().as_ref(), withdrawer.key().as_ref()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+    
+    #[account(mut, address = proposal.destination)]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, mint.key().as_ref()],
+        bump = vault_config.vault_bump,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Token-2022 or the legacy Token program, whichever owns the mint
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub(crate) fn handler(ctx: Context<ApproveWithdrawal>) -> Result<()> {
+    let approver = ctx.accounts.approver.key();
+    let proposal = &mut ctx.accounts.proposal;
+    require!(!proposal.approvals.contains(&approver), VaultError::AlreadyApproved);
+    // Approvals from keys since removed don't count, so make room for this one
+    let vault_config = &ctx.accounts.vault_config;
+    proposal.approvals.retain(|key| vault_config.approvers.contains(key));
+    proposal.approvals.push(approver);
+    
+    let approvals = vault_config.counted_approvals(&proposal.approvals);
+    let now = Clock::get()?.unix_timestamp;
+    // As in `execute_proposal`, a quorum of 0 means approvals were turned
+    // off, not that the proposal needs none
+    let executed = vault_config.approval_quorum > 0
+        && approvals >= vault_config.approval_quorum as usize
+        && proposal.is_unlocked(now);
+    
+    emit!(WithdrawalApproved {
+        vault_config: vault_config.key(),
+        proposal: proposal.key(),
+        approver,
+        approvals: approvals as u8,
+        executed,
+        timestamp: now,
+    });
+    if executed {
+        execute(ctx, now)?;
+    }
+    Ok(())
+}
+
+/// Pay out with the vault's signature, as `withdraw` does, and close the proposal
+fn execute(ctx: Context<ApproveWithdrawal>, now: i64) -> Result<()> {
+    let amount = ctx.accounts.proposal.amount;
+    require!(
+        ctx.accounts.whitelist_entry.is_within_limit(amount),
+        VaultError::AmountExceedsLimit
+    );
+    require!(
+        ctx.accounts.vault_config.unreserved(ctx.accounts.vault.amount) >= amount,
+        VaultError::VestingReserved
+    );
+    
+    let vault_config = &mut ctx.accounts.vault_config;
+    vault_config.open_proposals = vault_config.open_proposals.checked_sub(1).ok_or(ProgramError::ArithmeticOverflow)?;
+    
+    let mint_key = ctx.accounts.mint.key();
+    let vault_bump = ctx.accounts.vault_config.vault_bump;
+    let signer_seeds = &[VAULT_SEED, mint_key.as_ref(), &[vault_bump]];
+    
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+            &[signer_seeds],
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+    ctx.accounts.proposal.close(ctx.accounts.withdrawer.to_account_info())?;
+    
+    let ui_amount = ui_amount(&ctx.accounts.token_program, &ctx.accounts.mint, amount)?;
+    msg!("Approved withdrawal of {} ({} tokens)", amount, ui_amount);
+    
+    emit!(Withdrew {
+        vault_config: ctx.accounts.vault_config.key(),
+        mint: mint_key,
+        withdrawer: ctx.accounts.withdrawer.key(),
+        amount,
+        ui_amount,
+        timestamp: now,
+    });
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::events::*;
+use crate::state::*;
+
+/// Withdraw an open proposal and reclaim its rent
+#[derive(Accounts)]
+pub struct CancelProposal<'info> {
+    #[account(mut)]
+    pub withdrawer: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED, vault_config.mint.as_ref()],
+        bump = vault_config.config_bump,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    #[account(
+        mut,
+        close = withdrawer,
+        seeds = [PROPOSAL_SEED, vault_config.key().as_ref(), withdrawer.key().as_ref()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
+
+pub(crate) fn handler(ctx: Context<CancelProposal>) -> Result<()> {
+    let vault_config = &mut ctx.accounts.vault_config;
+    vault_config.open_proposals = vault_config.open_proposals.checked_sub(1).ok_or(ProgramError::ArithmeticOverflow)?;
+    
+    emit!(ProposalCancelled {
+        vault_config: ctx.accounts.vault_config.key(),
+        withdrawer: ctx.accounts.withdrawer.key(),
+        amount: ctx.accounts.proposal.amount,
+        cancelled_by: ctx.accounts.withdrawer.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::events::*;
+use crate::state::*;
+
+/// Withdraw a pending request and reclaim the ticket's rent
+#[derive(Accounts)]
+pub struct CancelWithdrawal<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED, vault_config.mint.as_ref()],
+        bump = vault_config.config_bump,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    #[account(
+        mut,
+        close = user,
+        seeds = [WITHDRAWAL_TICKET_SEED, vault_config.key().as_ref(), user.key().as_ref()],
+        bump = withdrawal_ticket.bump,
+    )]
+    pub withdrawal_ticket: Account<'info, WithdrawalTicket>,
+}
+
+pub(crate) fn handler(ctx: Context<CancelWithdrawal>) -> Result<()> {
+    let vault_config = &mut ctx.accounts.vault_config;
+    vault_config.open_tickets = vault_config.open_tickets.checked_sub(1).ok_or(ProgramError::ArithmeticOverflow)?;
+    
+    emit!(WithdrawalCancelled {
+        vault_config: ctx.accounts.vault_config.key(),
+        user: ctx.accounts.user.key(),
+        amount: ctx.accounts.withdrawal_ticket.amount,
+        cancelled_by: ctx.accounts.user.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked, transfer_checked};
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::instructions::ui_amount::ui_amount;
+use crate::state::*;
+
+/// Pay out an unlocked ticket and return its rent. The user must still be
+/// whitelisted, and within their current limit.
+#[derive(Accounts)]
+pub struct ClaimWithdrawal<'info> {
+    #[account(mut)]
+    pub withdrawer: Signer<'info>,
+    
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED, mint.key().as_ref()],
+        bump = vault_config.config_bump,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    #[account(
+        seeds = [WHITELIST_ENTRY_SEED, vault_config.key().as_ref(), withdrawer.key().as_ref()],
+        bump = whitelist_entry.bump,
+        constraint = whitelist_entry.user == withdrawer.key() @ VaultError::NotWhitelisted,
+    )]
+    pub whitelist_entry: Account<'info, WhitelistEntry>,
+    
+    #[account(
+        mut,
+        close = withdrawer,
+        seeds = [WITHDRAWAL_TICKET_SEED, vault_config.key().as_ref(), withdrawer.key().as_ref()],
+        bump = withdrawal_ticket.bump,
+    )]
+    pub withdrawal_ticket: Account<'info, WithdrawalTicket>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = withdrawer,
+    )]
+    pub withdrawer_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, mint.key().as_ref()],
+        bump = vault_config.vault_bump,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Token-2022 or the legacy Token program, whichever owns the mint
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub(crate) fn handler(ctx: Context<ClaimWithdrawal>) -> Result<()> {
+    let ticket = &ctx.accounts.withdrawal_ticket;
+    let amount = ticket.amount;
+    let now = Clock::get()?.unix_timestamp;
+    
+    require!(ticket.is_unlocked(now), VaultError::WithdrawalLocked);
+    // Approvals may have been turned on since the request; such a ticket has
+    // to be cancelled and proposed instead
+    require!(
+        !ctx.accounts.vault_config.requires_approval(amount),
+        VaultError::ApprovalRequired
+    );
+    require!(
+        ctx.accounts.whitelist_entry.is_within_limit(amount),
+        VaultError::AmountExceedsLimit
+    );
+    require!(
+        ctx.accounts.vault_config.unreserved(ctx.accounts.vault.amount) >= amount,
+        VaultError::VestingReserved
+    );
+    
+    let vault_config = &mut ctx.accounts.vault_config;
+    vault_config.open_tickets = vault_config.open_tickets.checked_sub(1).ok_or(ProgramError::ArithmeticOverflow)?;
+    
+    let mint_key = ctx.accounts.mint.key();
+    let vault_bump = ctx.accounts.vault_config.vault_bump;
+    let signer_seeds = &[VAULT_SEED, mint_key.as_ref(), &[vault_bump]];
+    
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.withdrawer_token_account.to_account_info(),
+                authority: ctx.accounts.vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+            &[signer_seeds],
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+    
+    let ui_amount = ui_amount(&ctx.accounts.token_program, &ctx.accounts.mint, amount)?;
+    msg!("Claimed withdrawal of {} ({} tokens)", amount, ui_amount);
+    
+    emit!(Withdrew {
+        vault_config: ctx.accounts.vault_config.key(),
+        mint: mint_key,
+        withdrawer: ctx.accounts.withdrawer.key(),
+        amount,
+        ui_amount,
+        timestamp: now,
+    });
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::{
+        spl_token_2022::{
+            extension::{permanent_delegate::PermanentDelegate, BaseStateWithExtensions, StateWithExtensions},
+            state::Mint as MintState,
+        },
+        Token2022,
+    },
+    token_interface::{self, Mint, TokenAccount, TokenInterface},
+};
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
+
+/// Force a transfer out of any holder's account using the vault config as
+/// the mint's permanent delegate
+#[derive(Accounts)]
+pub struct Clawback<'info> {
+    pub clawback_authority: Signer<'info>,
+    
+    #[account(
+        seeds = [VAULT_CONFIG_SEED, mint.key().as_ref()],
+        bump = vault_config.config_bump,
+        has_one = clawback_authority @ VaultError::UnauthorizedAuthority,
+        has_one = mint @ VaultError::InvalidMint,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    /// Permanent delegates are a Token-2022 extension
+    #[account(owner = Token2022::id() @ VaultError::RequiresToken2022)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(mut, token::mint = mint)]
+    pub source: InterfaceAccount<'info, TokenAccount>,
+    
+    /// The vault or a recovery account
+    #[account(mut, token::mint = mint)]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub(crate) fn handler(ctx: Context<Clawback>, amount: u64, reason_code: u16) -> Result<()> {
+    require!(reason_code != 0, VaultError::MissingReasonCode);
+    require_keys_neq!(ctx.accounts.source.key(), ctx.accounts.destination.key(), VaultError::InvalidClawbackDestination);
+    
+    // Fail with our own error rather than Token-2022's owner mismatch
+    {
+        let mint_info = ctx.accounts.mint.to_account_info();
+        let data = mint_info.try_borrow_data()?;
+        let mint = StateWithExtensions::<MintState>::unpack(&data)?;
+        let delegate = mint
+            .get_extension::<PermanentDelegate>()
+            .ok()
+            .and_then(|ext| Option::<Pubkey>::from(ext.delegate));
+        require!(delegate == Some(ctx.accounts.vault_config.key()), VaultError::PermanentDelegateNotVault);
+    }
+    
+    let mint_key = ctx.accounts.mint.key();
+    let config_bump = ctx.accounts.vault_config.config_bump;
+    let signer_seeds: &[&[u8]] = &[VAULT_CONFIG_SEED, mint_key.as_ref(), &[config_bump]];
+    
+    // Frozen accounts can't send even through the permanent delegate, so a
+    // frozen holder is thawed for the transfer and frozen again afterwards
+    let was_frozen = ctx.accounts.source.is_frozen();
+    if was_frozen {
+        token_interface::thaw_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::ThawAccount {
+                account: ctx.accounts.source.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                authority: ctx.accounts.vault_config.to_account_info(),
+            },
+            &[signer_seeds],
+        ))?;
+    }
+    
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: ctx.accounts.source.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.vault_config.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+            &[signer_seeds],
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+    
+    if was_frozen {
+        token_interface::freeze_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::FreezeAccount {
+                account: ctx.accounts.source.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                authority: ctx.accounts.vault_config.to_account_info(),
+            },
+            &[signer_seeds],
+        ))?;
+    }
+    
+    emit!(ClawedBack {
+        vault_config: ctx.accounts.vault_config.key(),
+        source: ctx.accounts.source.key(),
+        owner: ctx.accounts.source.owner,
+        destination: ctx.accounts.destination.key(),
+        amount,
+        reason_code,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,329 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{program::invoke_signed, program_option::COption};
+use anchor_lang::system_program;
+use anchor_spl::{
+    token_2022::{
+        spl_token_2022::{
+            extension::{
+                confidential_transfer::ConfidentialTransferMint,
+                confidential_transfer_fee::ConfidentialTransferFeeConfig,
+                group_member_pointer::GroupMemberPointer,
+                group_pointer::GroupPointer,
+                interest_bearing_mint::InterestBearingConfig,
+                metadata_pointer::MetadataPointer,
+                mint_close_authority::MintCloseAuthority,
+                permanent_delegate::PermanentDelegate,
+                transfer_fee::{TransferFeeAmount, TransferFeeConfig},
+                transfer_hook::TransferHook,
+                BaseStateWithExtensions,
+                StateWithExtensions,
+            },
+            instruction::AuthorityType,
+            state::{Account as TokenAccountState, Mint as MintState},
+        },
+        Token2022,
+    },
+    token_interface::{self, CloseAccount, HarvestWithheldTokensToMint, Mint, SetAuthority, TokenAccount, TokenInterface},
+};
+use spl_pod::optional_keys::OptionalNonZeroPubkey;
+use spl_token_group_interface::{instruction::update_group_authority, state::TokenGroup};
+use spl_token_metadata_interface::{instruction::update_authority, state::TokenMetadata};
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
+
+/// Decommission an empty vault, returning the rent of the vault config, the
+/// vault token account and any `WhitelistEntry` accounts passed in
+/// `remaining_accounts` to the authority. Withdrawal tickets and proposals
+/// are closed too when passed, each followed by its user, who gets the rent
+/// back, and so are fully released vesting schedules; the vault can't close
+/// while any stay open or tokens stay reserved. The mint and credential mint are closed
+/// too when the vault config is their close authority and their supply is
+/// zero. A mint left open gets every authority the vault config holds over
+/// it handed to the authority, so whoever initializes a vault for the mint
+/// again doesn't inherit them.
+#[derive(Accounts)]
+pub struct CloseVault<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        close = authority,
+        seeds = [VAULT_CONFIG_SEED, mint.key().as_ref()],
+        bump = vault_config.config_bump,
+        has_one = authority @ VaultError::UnauthorizedAuthority,
+        has_one = mint @ VaultError::InvalidMint,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, mint.key().as_ref()],
+        bump = vault_config.vault_bump,
+        constraint = vault.amount == 0 @ VaultError::VaultNotEmpty,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Required when the vault issues credentials
+    /// CHECK: Address checked against the vault config
+    #[account(
+        mut,
+        address = vault_config.credential_mint @ VaultError::InvalidCredentialMint,
+    )]
+    pub credential_mint: Option<UncheckedAccount<'info>>,
+    
+    /// Token-2022 or the legacy Token program, whichever owns the mint
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, '_, 'info, CloseVault<'info>>) -> Result<()> {
+    let authority = ctx.accounts.authority.to_account_info();
+    let vault_config_key = ctx.accounts.vault_config.key();
+    let mint_key = ctx.accounts.mint.key();
+    let config_seeds = &[VAULT_CONFIG_SEED, mint_key.as_ref(), &[ctx.accounts.vault_config.config_bump]];
+    let vault_seeds = &[VAULT_SEED, mint_key.as_ref(), &[ctx.accounts.vault_config.vault_bump]];
+    
+    // Token-2022 won't close an account holding withheld fees
+    let withheld = {
+        let vault_info = ctx.accounts.vault.to_account_info();
+        let data = vault_info.try_borrow_data()?;
+        let vault = StateWithExtensions::<TokenAccountState>::unpack(&data)?;
+        vault.get_extension::<TransferFeeAmount>().map_or(0, |fees| u64::from(fees.withheld_amount))
+    };
+    if withheld > 0 {
+        token_interface::harvest_withheld_tokens_to_mint(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                HarvestWithheldTokensToMint {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                },
+            ),
+            vec![ctx.accounts.vault.to_account_info()],
+        )?;
+    }
+    
+    token_interface::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.vault.to_account_info(),
+            destination: authority.clone(),
+            authority: ctx.accounts.vault.to_account_info(),
+        },
+        &[&vault_seeds[..]],
+    ))?;
+    
+    // Outstanding credentials could never be burned once the vault config is
+    // gone, so every credential holder must be removed first
+    if ctx.accounts.vault_config.credential_mint != Pubkey::default() {
+        require_keys_eq!(ctx.accounts.token_program.key(), Token2022::id(), VaultError::RequiresToken2022);
+        let credential_mint = ctx
+            .accounts
+            .credential_mint
+            .as_ref()
+            .ok_or(VaultError::MissingCredentialAccounts)?
+            .to_account_info();
+        let (supply, closable) = mint_closability(&credential_mint, &vault_config_key)?;
+        require!(supply == 0, VaultError::CredentialsOutstanding);
+        if closable {
+            close_mint(&ctx, credential_mint, &config_seeds[..])?;
+        }
+    }
+    
+    let mut accounts = ctx.remaining_accounts.iter();
+    let mut accounts_closed = 0u32;
+    while let Some(info) = accounts.next() {
+        let destination = match VaultAccount::load(info, &vault_config_key)? {
+            VaultAccount::Entry => &authority,
+            VaultAccount::Ticket { user } => {
+                let vault_config = &mut ctx.accounts.vault_config;
+                vault_config.open_tickets = vault_config.open_tickets.checked_sub(1).ok_or(ProgramError::ArithmeticOverflow)?;
+                let user_info = accounts.next().ok_or(VaultError::RentRecipientMismatch)?;
+                require_keys_eq!(user_info.key(), user, VaultError::RentRecipientMismatch);
+                user_info
+            }
+            VaultAccount::Proposal { withdrawer } => {
+                let vault_config = &mut ctx.accounts.vault_config;
+                vault_config.open_proposals =
+                    vault_config.open_proposals.checked_sub(1).ok_or(ProgramError::ArithmeticOverflow)?;
+                let withdrawer_info = accounts.next().ok_or(VaultError::RentRecipientMismatch)?;
+                require_keys_eq!(withdrawer_info.key(), withdrawer, VaultError::RentRecipientMismatch);
+                withdrawer_info
+            }
+            VaultAccount::Schedule { fully_released } => {
+                require!(fully_released, VaultError::VestingNotFullyReleased);
+                let vault_config = &mut ctx.accounts.vault_config;
+                vault_config.vesting_schedules =
+                    vault_config.vesting_schedules.checked_sub(1).ok_or(ProgramError::ArithmeticOverflow)?;
+                &authority
+            }
+        };
+        close_program_account(info, destination)?;
+        accounts_closed += 1;
+    }
+    require!(!ctx.accounts.vault_config.has_open_claims(), VaultError::OpenClaims);
+    
+    let mint_info = ctx.accounts.mint.to_account_info();
+    let (supply, closable) = mint_closability(&mint_info, &vault_config_key)?;
+    let mint_closed = closable && supply == 0;
+    if mint_closed {
+        close_mint(&ctx, mint_info, &config_seeds[..])?;
+    } else {
+        release_mint_authorities(&ctx, &config_seeds[..])?;
+    }
+    
+    emit!(VaultClosed {
+        vault_config: vault_config_key,
+        mint: mint_key,
+        authority: authority.key(),
+        accounts_closed,
+        mint_closed,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+/// A program account `close_vault` may close alongside the vault
+enum VaultAccount {
+    Entry,
+    /// Rent goes back to `user`, who paid it
+    Ticket { user: Pubkey },
+    /// Rent goes back to `withdrawer`, who paid it
+    Proposal { withdrawer: Pubkey },
+    Schedule { fully_released: bool },
+}
+
+impl VaultAccount {
+    fn load(info: &AccountInfo, vault_config: &Pubkey) -> Result<Self> {
+        require!(*info.owner == crate::ID, VaultError::ForeignAccount);
+        let data = info.try_borrow_data()?;
+        let (owner, account) = if let Ok(entry) = WhitelistEntry::try_deserialize(&mut &data[..]) {
+            (entry.vault_config, VaultAccount::Entry)
+        } else if let Ok(ticket) = WithdrawalTicket::try_deserialize(&mut &data[..]) {
+            (ticket.vault_config, VaultAccount::Ticket { user: ticket.user })
+        } else if let Ok(proposal) = Proposal::try_deserialize(&mut &data[..]) {
+            (proposal.vault_config, VaultAccount::Proposal { withdrawer: proposal.withdrawer })
+        } else if let Ok(schedule) = VestingSchedule::try_deserialize(&mut &data[..]) {
+            (schedule.vault_config, VaultAccount::Schedule { fully_released: schedule.is_fully_released() })
+        } else {
+            return err!(VaultError::ForeignAccount);
+        };
+        require_keys_eq!(owner, *vault_config, VaultError::ForeignAccount);
+        Ok(account)
+    }
+}
+
+/// The mint's supply, and whether `vault_config` is its close authority
+fn mint_closability(mint: &AccountInfo, vault_config: &Pubkey) -> Result<(u64, bool)> {
+    let data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<MintState>::unpack(&data)?;
+    let closable = mint
+        .get_extension::<MintCloseAuthority>()
+        .is_ok_and(|extension| Option::<Pubkey>::from(extension.close_authority) == Some(*vault_config));
+    Ok((mint.base.supply, closable))
+}
+
+fn close_mint<'info>(ctx: &Context<'_, '_, '_, 'info, CloseVault<'info>>, mint: AccountInfo<'info>, config_seeds: &[&[u8]]) -> Result<()> {
+    token_interface::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: mint,
+            destination: ctx.accounts.authority.to_account_info(),
+            authority: ctx.accounts.vault_config.to_account_info(),
+        },
+        &[config_seeds],
+    ))
+}
+
+/// The authorities over `mint` that `vault_config` holds: those moved by
+/// `set_authority`, and whether it updates the token metadata and group
+fn held_authorities(mint: &AccountInfo, vault_config: &Pubkey) -> Result<(Vec<AuthorityType>, bool, bool)> {
+    let data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<MintState>::unpack(&data)?;
+    let held = |authority: OptionalNonZeroPubkey| Option::<Pubkey>::from(authority) == Some(*vault_config);
+    
+    let mut authority_types = Vec::new();
+    if mint.base.mint_authority == COption::Some(*vault_config) {
+        authority_types.push(AuthorityType::MintTokens);
+    }
+    if mint.base.freeze_authority == COption::Some(*vault_config) {
+        authority_types.push(AuthorityType::FreezeAccount);
+    }
+    let extensions = [
+        (AuthorityType::CloseMint, mint.get_extension::<MintCloseAuthority>().map(|e| e.close_authority)),
+        (AuthorityType::PermanentDelegate, mint.get_extension::<PermanentDelegate>().map(|e| e.delegate)),
+        (AuthorityType::TransferFeeConfig, mint.get_extension::<TransferFeeConfig>().map(|e| e.transfer_fee_config_authority)),
+        (AuthorityType::WithheldWithdraw, mint.get_extension::<TransferFeeConfig>().map(|e| e.withdraw_withheld_authority)),
+        (AuthorityType::InterestRate, mint.get_extension::<InterestBearingConfig>().map(|e| e.rate_authority)),
+        (AuthorityType::ConfidentialTransferMint, mint.get_extension::<ConfidentialTransferMint>().map(|e| e.authority)),
+        (
+            AuthorityType::ConfidentialTransferFeeConfig,
+            mint.get_extension::<ConfidentialTransferFeeConfig>().map(|e| e.authority),
+        ),
+        (AuthorityType::TransferHookProgramId, mint.get_extension::<TransferHook>().map(|e| e.authority)),
+        (AuthorityType::MetadataPointer, mint.get_extension::<MetadataPointer>().map(|e| e.authority)),
+        (AuthorityType::GroupPointer, mint.get_extension::<GroupPointer>().map(|e| e.authority)),
+        (AuthorityType::GroupMemberPointer, mint.get_extension::<GroupMemberPointer>().map(|e| e.authority)),
+    ];
+    authority_types.extend(
+        extensions
+            .into_iter()
+            .filter(|(_, authority)| authority.as_ref().is_ok_and(|authority| held(*authority)))
+            .map(|(authority_type, _)| authority_type),
+    );
+    
+    let metadata = mint.get_variable_len_extension::<TokenMetadata>().is_ok_and(|m| held(m.update_authority));
+    let group = mint.get_extension::<TokenGroup>().is_ok_and(|group| held(group.update_authority));
+    Ok((authority_types, metadata, group))
+}
+
+/// Hand every authority the vault config holds over the mint to the vault
+/// authority
+fn release_mint_authorities<'info>(ctx: &Context<'_, '_, '_, 'info, CloseVault<'info>>, config_seeds: &[&[u8]]) -> Result<()> {
+    let mint = ctx.accounts.mint.to_account_info();
+    let vault_config = ctx.accounts.vault_config.to_account_info();
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let authority = ctx.accounts.authority.key();
+    let (authority_types, metadata, group) = held_authorities(&mint, vault_config.key)?;
+    
+    for authority_type in authority_types {
+        token_interface::set_authority(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                SetAuthority { current_authority: vault_config.clone(), account_or_mint: mint.clone() },
+                &[config_seeds],
+            ),
+            authority_type,
+            Some(authority),
+        )?;
+    }
+    if metadata {
+        invoke_signed(
+            &update_authority(token_program.key, mint.key, vault_config.key, Some(authority).try_into()?),
+            &[mint.clone(), vault_config.clone()],
+            &[config_seeds],
+        )?;
+    }
+    if group {
+        invoke_signed(
+            &update_group_authority(token_program.key, mint.key, vault_config.key, Some(authority)),
+            &[mint, vault_config],
+            &[config_seeds],
+        )?;
+    }
+    Ok(())
+}
+
+/// What Anchor's `close` constraint does, for accounts not in the struct
+fn close_program_account<'info>(info: &AccountInfo<'info>, destination: &AccountInfo<'info>) -> Result<()> {
+    **destination.try_borrow_mut_lamports()? += info.lamports();
+    **info.try_borrow_mut_lamports()? = 0;
+    info.assign(&system_program::ID);
+    info.realloc(0, false)?;
+    Ok(())
+}
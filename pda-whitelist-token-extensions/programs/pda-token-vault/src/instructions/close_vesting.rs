@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
+
+/// Close a schedule that has released everything, returning its rent to
+/// the authority that paid for it
+#[derive(Accounts)]
+pub struct CloseVesting<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED, vault_config.mint.as_ref()],
+        bump = vault_config.config_bump,
+        has_one = authority @ VaultError::UnauthorizedAuthority,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    #[account(
+        mut,
+        close = authority,
+        has_one = vault_config @ VaultError::ForeignAccount,
+        constraint = vesting_schedule.is_fully_released() @ VaultError::VestingNotFullyReleased,
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+}
+
+pub(crate) fn handler(ctx: Context<CloseVesting>) -> Result<()> {
+    let vault_config = &mut ctx.accounts.vault_config;
+    vault_config.vesting_schedules = vault_config
+        .vesting_schedules
+        .checked_sub(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    
+    let schedule = &ctx.accounts.vesting_schedule;
+    emit!(VestingClosed {
+        vault_config: vault_config.key(),
+        beneficiary: schedule.beneficiary,
+        schedule_id: schedule.schedule_id,
+        total: schedule.total,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
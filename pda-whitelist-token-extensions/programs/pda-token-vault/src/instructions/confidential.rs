@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{
+        confidential_transfer::{instruction::ConfidentialTransferInstruction, ConfidentialTransferMint},
+        BaseStateWithExtensions,
+        StateWithExtensions,
+    },
+    instruction::TokenInstruction,
+    state::Mint as MintState,
+};
+use crate::error::VaultError;
+
+/// A Token-2022 confidential transfer instruction. Its `inner_*` builders
+/// need client-only ciphertext types or the proof data itself, so CPIs that
+/// only forward a proof location encode their own.
+pub fn confidential_instruction(
+    accounts: Vec<AccountMeta>,
+    instruction: ConfidentialTransferInstruction,
+    data: &[u8],
+) -> Instruction {
+    let mut bytes = TokenInstruction::ConfidentialTransferExtension.pack();
+    bytes.push(instruction.into());
+    bytes.extend_from_slice(data);
+    Instruction { program_id: spl_token_2022::ID, accounts, data: bytes }
+}
+
+/// Whether new accounts are approved automatically, failing if the mint
+/// doesn't allow confidential transfers at all
+pub fn auto_approves(mint: &AccountInfo) -> Result<bool> {
+    let data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<MintState>::unpack(&data)?;
+    let config = mint
+        .get_extension::<ConfidentialTransferMint>()
+        .map_err(|_| error!(VaultError::ConfidentialTransferNotEnabled))?;
+    Ok(bool::from(config.auto_approve_new_accounts))
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::{
+    token_2022::{
+        spl_token_2022::{self, extension::confidential_transfer::instruction::deposit},
+        Token2022,
+    },
+    token_interface::{Mint, TokenAccount},
+};
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
+
+/// Move `amount` of the vault's public balance into its pending confidential
+/// balance, e.g. after public deposits. The amount is visible in this
+/// transaction; later transfers out of the confidential balance aren't.
+/// Balance reserved for vesting stays public.
+#[derive(Accounts)]
+pub struct ConfidentialVaultDeposit<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [VAULT_CONFIG_SEED, mint.key().as_ref()],
+        bump = vault_config.config_bump,
+        has_one = authority @ VaultError::UnauthorizedAuthority,
+        has_one = mint @ VaultError::InvalidMint,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    #[account(owner = Token2022::id() @ VaultError::RequiresToken2022)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, mint.key().as_ref()],
+        bump = vault_config.vault_bump,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub(crate) fn handler(ctx: Context<ConfidentialVaultDeposit>, amount: u64) -> Result<()> {
+    require!(
+        amount <= ctx.accounts.vault_config.unreserved(ctx.accounts.vault.amount),
+        VaultError::VestingReserved
+    );
+    
+    let mint_key = ctx.accounts.mint.key();
+    let vault = ctx.accounts.vault.to_account_info();
+    let vault_seeds = &[VAULT_SEED, mint_key.as_ref(), &[ctx.accounts.vault_config.vault_bump]];
+    
+    invoke_signed(
+        &deposit(&spl_token_2022::ID, vault.key, &mint_key, amount, ctx.accounts.mint.decimals, vault.key, &[])?,
+        &[vault.clone(), ctx.accounts.mint.to_account_info()],
+        &[&vault_seeds[..]],
+    )?;
+    
+    emit!(ConfidentialVaultDeposited {
+        vault_config: ctx.accounts.vault_config.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
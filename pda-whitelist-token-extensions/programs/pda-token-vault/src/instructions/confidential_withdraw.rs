@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::{
+    token_2022::{
+        spl_token_2022::{
+            extension::confidential_transfer::instruction::{ConfidentialTransferInstruction, TransferInstructionData},
+            solana_zk_token_sdk::zk_token_elgamal::pod::AeCiphertext,
+        },
+        Token2022,
+    },
+    token_interface::{Mint, TokenAccount},
+};
+use spl_pod::bytemuck::pod_bytes_of;
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::instructions::confidential::confidential_instruction;
+use crate::state::*;
+
+/// Confidentially transfer from the vault to a whitelisted holder. Only the
+/// authority holds the vault's ElGamal key, so it builds the `Transfer`
+/// proof (usually in a context state account, as it doesn't fit alongside
+/// this instruction) and signs instead of the recipient. The hidden amount
+/// can't be checked against a timelock, vesting reservation or approval
+/// threshold, so the instruction is refused while any of them is set.
+#[derive(Accounts)]
+pub struct ConfidentialWithdraw<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [VAULT_CONFIG_SEED, mint.key().as_ref()],
+        bump = vault_config.config_bump,
+        has_one = authority @ VaultError::UnauthorizedAuthority,
+        has_one = mint @ VaultError::InvalidMint,
+        constraint = !vault_config.has_withdrawal_controls() @ VaultError::ConfidentialWithdrawRestricted,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    #[account(owner = Token2022::id() @ VaultError::RequiresToken2022)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, mint.key().as_ref()],
+        bump = vault_config.vault_bump,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(mut, token::mint = mint)]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// The amount is hidden, so a per-transfer limit can't be enforced;
+    /// only entries without one may receive confidential withdrawals
+    #[account(
+        seeds = [WHITELIST_ENTRY_SEED, vault_config.key().as_ref(), recipient_token_account.owner.as_ref()],
+        bump = whitelist_entry.bump,
+        constraint = whitelist_entry.max_amount == 0 @ VaultError::ConfidentialLimitUnenforceable,
+    )]
+    pub whitelist_entry: Account<'info, WhitelistEntry>,
+    
+    /// CHECK: Instructions sysvar or proof context state account; Token-2022 verifies it
+    pub proof: UncheckedAccount<'info>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<ConfidentialWithdraw>,
+    new_source_decryptable_available_balance: [u8; 36],
+    proof_instruction_offset: i8,
+) -> Result<()> {
+    let mint_key = ctx.accounts.mint.key();
+    let vault = ctx.accounts.vault.to_account_info();
+    let recipient = ctx.accounts.recipient_token_account.to_account_info();
+    let vault_seeds = &[VAULT_SEED, mint_key.as_ref(), &[ctx.accounts.vault_config.vault_bump]];
+    
+    let data = TransferInstructionData {
+        new_source_decryptable_available_balance: AeCiphertext(new_source_decryptable_available_balance),
+        proof_instruction_offset,
+    };
+    invoke_signed(
+        &confidential_instruction(
+            vec![
+                AccountMeta::new(vault.key(), false),
+                AccountMeta::new_readonly(mint_key, false),
+                AccountMeta::new(recipient.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.proof.key(), false),
+                AccountMeta::new_readonly(vault.key(), true),
+            ],
+            ConfidentialTransferInstruction::Transfer,
+            pod_bytes_of(&data),
+        ),
+        &[
+            vault.clone(),
+            ctx.accounts.mint.to_account_info(),
+            recipient.clone(),
+            ctx.accounts.proof.to_account_info(),
+        ],
+        &[&vault_seeds[..]],
+    )?;
+    
+    emit!(ConfidentialWithdrawal {
+        vault_config: ctx.accounts.vault_config.key(),
+        recipient: ctx.accounts.recipient_token_account.owner,
+        recipient_token_account: recipient.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::{
+    token_2022::{
+        spl_token_2022::{
+            self,
+            extension::{
+                confidential_transfer::{
+                    instruction::{approve_account, ConfigureAccountInstructionData, ConfidentialTransferInstruction},
+                    ConfidentialTransferAccount,
+                },
+                BaseStateWithExtensions,
+                ExtensionType,
+                StateWithExtensions,
+            },
+            instruction::reallocate,
+            solana_zk_token_sdk::zk_token_elgamal::pod::AeCiphertext,
+            state::Account as TokenAccountState,
+        },
+        Token2022,
+    },
+    token_interface::{Mint, TokenAccount},
+};
+use spl_pod::bytemuck::pod_bytes_of;
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::instructions::confidential::{auto_approves, confidential_instruction};
+use crate::state::*;
+
+/// Give the vault's token account a confidential balance under an ElGamal
+/// key the authority holds off-chain. The key is proven valid by a
+/// `PubkeyValidity` proof, either in the instruction at
+/// `proof_instruction_offset` or, with an offset of 0, in a context state
+/// account passed as `proof`.
+#[derive(Accounts)]
+pub struct ConfigureConfidentialVault<'info> {
+    /// Pays for the vault account's extra space
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [VAULT_CONFIG_SEED, mint.key().as_ref()],
+        bump = vault_config.config_bump,
+        has_one = authority @ VaultError::UnauthorizedAuthority,
+        has_one = mint @ VaultError::InvalidMint,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    #[account(owner = Token2022::id() @ VaultError::RequiresToken2022)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, mint.key().as_ref()],
+        bump = vault_config.vault_bump,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    
+    /// CHECK: Instructions sysvar or proof context state account; Token-2022 verifies it
+    pub proof: UncheckedAccount<'info>,
+    
+    pub token_program: Program<'info, Token2022>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<ConfigureConfidentialVault>,
+    decryptable_zero_balance: [u8; 36],
+    maximum_pending_balance_credit_counter: u64,
+    proof_instruction_offset: i8,
+) -> Result<()> {
+    let mint = ctx.accounts.mint.to_account_info();
+    let vault = ctx.accounts.vault.to_account_info();
+    let auto_approve = auto_approves(&mint)?;
+    
+    let mint_key = mint.key();
+    let vault_seeds = &[VAULT_SEED, mint_key.as_ref(), &[ctx.accounts.vault_config.vault_bump]];
+    let config_seeds = &[VAULT_CONFIG_SEED, mint_key.as_ref(), &[ctx.accounts.vault_config.config_bump]];
+    
+    // The vault was sized for the mint's required extensions only
+    invoke_signed(
+        &reallocate(
+            &spl_token_2022::ID,
+            vault.key,
+            ctx.accounts.authority.key,
+            vault.key,
+            &[],
+            &[ExtensionType::ConfidentialTransferAccount],
+        )?,
+        &[
+            vault.clone(),
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+        &[&vault_seeds[..]],
+    )?;
+    
+    let data = ConfigureAccountInstructionData {
+        decryptable_zero_balance: AeCiphertext(decryptable_zero_balance),
+        maximum_pending_balance_credit_counter: maximum_pending_balance_credit_counter.into(),
+        proof_instruction_offset,
+    };
+    invoke_signed(
+        &confidential_instruction(
+            vec![
+                AccountMeta::new(vault.key(), false),
+                AccountMeta::new_readonly(mint_key, false),
+                AccountMeta::new_readonly(ctx.accounts.proof.key(), false),
+                AccountMeta::new_readonly(vault.key(), true),
+            ],
+            ConfidentialTransferInstruction::ConfigureAccount,
+            pod_bytes_of(&data),
+        ),
+        &[vault.clone(), mint.clone(), ctx.accounts.proof.to_account_info()],
+        &[&vault_seeds[..]],
+    )?;
+    
+    // The vault config is the mint's confidential transfer authority
+    if !auto_approve {
+        invoke_signed(
+            &approve_account(&spl_token_2022::ID, vault.key, &mint_key, &ctx.accounts.vault_config.key(), &[])?,
+            &[vault.clone(), mint, ctx.accounts.vault_config.to_account_info()],
+            &[&config_seeds[..]],
+        )?;
+    }
+    
+    let elgamal_pubkey = {
+        let data = vault.try_borrow_data()?;
+        let state = StateWithExtensions::<TokenAccountState>::unpack(&data)?;
+        state.get_extension::<ConfidentialTransferAccount>()?.elgamal_pubkey.0
+    };
+    
+    emit!(ConfidentialVaultConfigured {
+        vault_config: ctx.accounts.vault_config.key(),
+        vault: vault.key(),
+        elgamal_pubkey,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
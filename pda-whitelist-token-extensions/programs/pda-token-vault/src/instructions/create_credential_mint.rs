@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+use anchor_spl::token_2022::{
+    spl_token_2022::{
+        self,
+        extension::ExtensionType,
+        instruction::{
+            initialize_mint2,
+            initialize_mint_close_authority,
+            initialize_non_transferable_mint,
+            initialize_permanent_delegate,
+        },
+        state::Mint,
+    },
+    Token2022,
+};
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::instructions::vault_account::create_pda_account;
+use crate::state::*;
+
+/// Create the vault's soulbound credential mint. Whitelisted users hold one
+/// token of it, so wallets and other programs can check membership by
+/// reading a token account instead of this program's state.
+#[derive(Accounts)]
+pub struct CreateCredentialMint<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED, vault_config.mint.as_ref()],
+        bump = vault_config.config_bump,
+        has_one = authority @ VaultError::UnauthorizedAuthority,
+        constraint = vault_config.credential_mint == Pubkey::default() @ VaultError::CredentialMintExists,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    /// The vault's mint; `add_to_whitelist` passes one token program for
+    /// holder and credential accounts, so a legacy vault can't issue credentials
+    /// CHECK: Address and owner checked; the data isn't read
+    #[account(
+        address = vault_config.mint @ VaultError::InvalidMint,
+        owner = Token2022::id() @ VaultError::RequiresToken2022,
+    )]
+    pub mint: UncheckedAccount<'info>,
+    
+    /// CHECK: Created and initialized manually with extensions
+    #[account(
+        mut,
+        seeds = [CREDENTIAL_MINT_SEED, vault_config.key().as_ref()],
+        bump,
+    )]
+    pub credential_mint: UncheckedAccount<'info>,
+    
+    pub token_program: Program<'info, Token2022>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<CreateCredentialMint>) -> Result<()> {
+    let credential_mint = &ctx.accounts.credential_mint;
+    let vault_config = &ctx.accounts.vault_config;
+    
+    let space = ExtensionType::try_calculate_account_len::<Mint>(&[
+        ExtensionType::NonTransferable,
+        ExtensionType::PermanentDelegate,
+        ExtensionType::MintCloseAuthority,
+    ])
+    .map_err(|_| error!(VaultError::InvalidExtension))?;
+    
+    let vault_config_key = vault_config.key();
+    let seeds: &[&[u8]] = &[CREDENTIAL_MINT_SEED, vault_config_key.as_ref(), &[ctx.bumps.credential_mint]];
+    create_pda_account(
+        &ctx.accounts.authority.to_account_info(),
+        &credential_mint.to_account_info(),
+        space,
+        &spl_token_2022::ID,
+        &ctx.accounts.system_program.to_account_info(),
+        seeds,
+    )?;
+    
+    invoke(
+        &initialize_non_transferable_mint(&spl_token_2022::ID, credential_mint.key)?,
+        &[credential_mint.to_account_info()],
+    )?;
+    
+    // Removal burns the credential through the permanent delegate, so the
+    // holder can't keep it once they leave the whitelist
+    invoke(
+        &initialize_permanent_delegate(&spl_token_2022::ID, credential_mint.key, &vault_config_key)?,
+        &[credential_mint.to_account_info()],
+    )?;
+    
+    // Lets `close_vault` reclaim the mint once every credential is burned
+    invoke(
+        &initialize_mint_close_authority(&spl_token_2022::ID, credential_mint.key, Some(&vault_config_key))?,
+        &[credential_mint.to_account_info()],
+    )?;
+    
+    // The vault config issues credentials; with no freeze authority a
+    // credential can only be revoked by burning
+    invoke(
+        &initialize_mint2(&spl_token_2022::ID, credential_mint.key, &vault_config_key, None, 0)?,
+        &[credential_mint.to_account_info()],
+    )?;
+    
+    let vault_config = &mut ctx.accounts.vault_config;
+    vault_config.credential_mint = credential_mint.key();
+    
+    emit!(CredentialMintCreated {
+        vault_config: vault_config_key,
+        credential_mint: credential_mint.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
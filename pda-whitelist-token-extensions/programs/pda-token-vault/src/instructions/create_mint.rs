@@ -1,15 +1,32 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::{program::invoke, system_instruction};
+use anchor_lang::solana_program::{program::{invoke, invoke_signed}, system_instruction};
 use anchor_spl::token_2022::spl_token_2022::{
     self,
     extension::{
+        confidential_transfer::instruction::initialize_mint as initialize_confidential_transfer_mint,
+        default_account_state::instruction::initialize_default_account_state,
+        group_member_pointer::instruction::initialize as initialize_group_member_pointer,
+        group_pointer::instruction::initialize as initialize_group_pointer,
+        interest_bearing_mint::instruction::initialize as initialize_interest_bearing_mint,
+        metadata_pointer::instruction::initialize as initialize_metadata_pointer,
         transfer_fee::instruction::initialize_transfer_fee_config,
+        BaseStateWithExtensions,
         ExtensionType,
+        StateWithExtensions,
     },
-    instruction::initialize_mint2,
-    state::Mint,
+    instruction::{initialize_mint2, initialize_mint_close_authority},
+    solana_zk_token_sdk::zk_token_elgamal::pod::ElGamalPubkey,
+    state::{AccountState, Mint},
 };
+use spl_token_group_interface::{
+    instruction::{initialize_group, initialize_member},
+    state::TokenGroupMember,
+};
+use spl_token_metadata_interface::state::TokenMetadata;
+use crate::constants::*;
 use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
 
 /// Extension configuration passed as instruction arguments
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
@@ -20,8 +37,42 @@ pub struct ExtensionArgs {
     pub transfer_fee_basis_points: u16,
     /// Maximum fee amount
     pub max_fee: u64,
-    /// Enable permanent delegate (authority can transfer any tokens)
+    /// Enable permanent delegate (the vault config, for `clawback`)
     pub enable_permanent_delegate: bool,
+    /// New token accounts start frozen until whitelisting thaws them
+    pub enable_default_frozen: bool,
+    /// Enable interest-bearing extension (the vault config sets the rate)
+    pub enable_interest_bearing: bool,
+    /// Annual interest rate in basis points; may be negative
+    pub interest_rate: i16,
+    /// Let `close_vault` close the mint once its supply is zero (the vault
+    /// config is the close authority)
+    pub enable_mint_close_authority: bool,
+    /// Allow confidential balances and transfers (not with transfer fees)
+    pub confidential_transfer: Option<ConfidentialTransferArgs>,
+    /// Make the mint a token group of up to this many members (the vault
+    /// config is the group update authority)
+    pub group_max_size: Option<u32>,
+    /// Embed name, symbol and URI in the mint (MetadataPointer + TokenMetadata)
+    pub metadata: Option<TokenMetadataArgs>,
+}
+
+/// Confidential transfer settings; the vault config is the mint's
+/// confidential transfer authority
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ConfidentialTransferArgs {
+    /// Let new token accounts use confidential balances without approval
+    pub auto_approve_new_accounts: bool,
+    /// ElGamal public key that can decrypt every confidential amount
+    pub auditor_elgamal_pubkey: Option<[u8; 32]>,
+}
+
+/// Token metadata written at mint creation
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct TokenMetadataArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
 }
 
 #[derive(Accounts)]
@@ -33,6 +84,35 @@ pub struct CreateMint<'info> {
     #[account(mut)]
     pub mint: Signer<'info>,
     
+    /// CHECK: Not created yet; its address becomes the freeze authority,
+    /// permanent delegate, rate authority, close authority, confidential
+    /// transfer authority, metadata and group update authority
+    #[account(seeds = [VAULT_CONFIG_SEED, mint.key().as_ref()], bump)]
+    pub vault_config: UncheckedAccount<'info>,
+    
+    /// CHECK: Group to join; Token-2022 checks it is a group whose update
+    /// authority is `group_vault_config`
+    #[account(mut)]
+    pub group_mint: Option<UncheckedAccount<'info>>,
+    
+    /// Signs for the group, so only its vault authority can add members
+    #[account(
+        seeds = [VAULT_CONFIG_SEED, group_vault_config.mint.as_ref()],
+        bump = group_vault_config.config_bump,
+        has_one = authority @ VaultError::UnauthorizedAuthority,
+    )]
+    pub group_vault_config: Option<Account<'info, VaultConfig>>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_REGISTRY_SEED, registry.group_mint.as_ref()],
+        bump = registry.bump,
+        realloc = VaultRegistry::space(registry.members.len() + 1),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    pub registry: Option<Account<'info, VaultRegistry>>,
+    
     /// CHECK: Token 2022 program
     #[account(address = spl_token_2022::ID)]
     pub token_program: AccountInfo<'info>,
@@ -40,11 +120,29 @@ pub struct CreateMint<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<CreateMint>, decimals: u8, extension_args: ExtensionArgs) -> Result<()> {
+pub(crate) fn handler(ctx: Context<CreateMint>, decimals: u8, extension_args: ExtensionArgs) -> Result<()> {
     let mint = &ctx.accounts.mint;
     let authority = &ctx.accounts.authority;
+    let vault_config = &ctx.accounts.vault_config;
     let system_program = &ctx.accounts.system_program;
     
+    let group = match (&ctx.accounts.group_mint, &ctx.accounts.group_vault_config, ctx.accounts.registry.as_mut()) {
+        (Some(group_mint), Some(group_vault_config), Some(registry)) => {
+            require_keys_eq!(group_mint.key(), group_vault_config.mint, VaultError::InvalidMint);
+            require_keys_eq!(registry.group_mint, group_vault_config.mint, VaultError::InvalidMint);
+            Some((group_mint, group_vault_config, registry))
+        }
+        (None, None, None) => None,
+        _ => return err!(VaultError::IncompleteGroupAccounts),
+    };
+    
+    // Confidential transfers on a fee mint need ConfidentialTransferFeeConfig,
+    // which isn't supported
+    require!(
+        !(extension_args.enable_transfer_fee && extension_args.confidential_transfer.is_some()),
+        VaultError::InvalidExtensionConfig
+    );
+    
     // Build list of extensions based on args
     let mut extensions: Vec<ExtensionType> = Vec::new();
     
@@ -54,13 +152,61 @@ pub fn handler(ctx: Context<CreateMint>, decimals: u8, extension_args: Extension
     if extension_args.enable_permanent_delegate {
         extensions.push(ExtensionType::PermanentDelegate);
     }
+    if extension_args.enable_default_frozen {
+        extensions.push(ExtensionType::DefaultAccountState);
+    }
+    if extension_args.enable_interest_bearing {
+        extensions.push(ExtensionType::InterestBearingConfig);
+    }
+    if extension_args.enable_mint_close_authority {
+        extensions.push(ExtensionType::MintCloseAuthority);
+    }
+    if extension_args.confidential_transfer.is_some() {
+        extensions.push(ExtensionType::ConfidentialTransferMint);
+    }
+    if extension_args.metadata.is_some() {
+        extensions.push(ExtensionType::MetadataPointer);
+    }
+    if extension_args.group_max_size.is_some() {
+        extensions.push(ExtensionType::GroupPointer);
+    }
+    if group.is_some() {
+        extensions.push(ExtensionType::GroupMemberPointer);
+    }
     
     // Calculate space for all extensions
     let space = ExtensionType::try_calculate_account_len::<Mint>(&extensions)
         .map_err(|_| error!(VaultError::InvalidExtension))?;
     
+    // Like metadata, group and member state are realloc'd in after the mint
+    // is initialized, so fund them now too
+    let mut funded = extensions.clone();
+    if extension_args.group_max_size.is_some() {
+        funded.push(ExtensionType::TokenGroup);
+    }
+    if group.is_some() {
+        funded.push(ExtensionType::TokenGroupMember);
+    }
+    let funded_space = ExtensionType::try_calculate_account_len::<Mint>(&funded)
+        .map_err(|_| error!(VaultError::InvalidExtension))?;
+    
+    // Token-2022 reallocs the mint when writing metadata but doesn't fund it,
+    // so pay rent for the metadata up front
+    let metadata_space = match &extension_args.metadata {
+        Some(args) => TokenMetadata {
+            update_authority: Some(vault_config.key()).try_into()?,
+            mint: mint.key(),
+            name: args.name.clone(),
+            symbol: args.symbol.clone(),
+            uri: args.uri.clone(),
+            additional_metadata: vec![],
+        }
+        .tlv_size_of()?,
+        None => 0,
+    };
+    
     let rent = Rent::get()?;
-    let lamports = rent.minimum_balance(space);
+    let lamports = rent.minimum_balance(funded_space + metadata_space);
     
     // Create the mint account
     invoke(
@@ -97,25 +243,199 @@ pub fn handler(ctx: Context<CreateMint>, decimals: u8, extension_args: Extension
             &spl_token_2022::instruction::initialize_permanent_delegate(
                 &spl_token_2022::ID,
                 mint.key,
-                &authority.key(),
+                vault_config.key,
             )?,
             &[mint.to_account_info()],
         )?;
         msg!("Initialized PermanentDelegate extension");
     }
     
-    // Initialize the mint itself
+    // Initialize Default Account State extension
+    if extension_args.enable_default_frozen {
+        invoke(
+            &initialize_default_account_state(&spl_token_2022::ID, mint.key, &AccountState::Frozen)?,
+            &[mint.to_account_info()],
+        )?;
+        msg!("Initialized DefaultAccountState extension: frozen");
+    }
+    
+    // Initialize Interest Bearing extension
+    if extension_args.enable_interest_bearing {
+        invoke(
+            &initialize_interest_bearing_mint(
+                &spl_token_2022::ID,
+                mint.key,
+                Some(vault_config.key()),
+                extension_args.interest_rate,
+            )?,
+            &[mint.to_account_info()],
+        )?;
+        msg!("Initialized InterestBearingConfig extension: {}bp", extension_args.interest_rate);
+    }
+    
+    // Initialize Mint Close Authority extension
+    if extension_args.enable_mint_close_authority {
+        invoke(
+            &initialize_mint_close_authority(&spl_token_2022::ID, mint.key, Some(vault_config.key))?,
+            &[mint.to_account_info()],
+        )?;
+        msg!("Initialized MintCloseAuthority extension");
+    }
+    
+    // Initialize Confidential Transfer extension
+    if let Some(args) = &extension_args.confidential_transfer {
+        invoke(
+            &initialize_confidential_transfer_mint(
+                &spl_token_2022::ID,
+                mint.key,
+                Some(vault_config.key()),
+                args.auto_approve_new_accounts,
+                args.auditor_elgamal_pubkey.map(ElGamalPubkey),
+            )?,
+            &[mint.to_account_info()],
+        )?;
+        msg!("Initialized ConfidentialTransferMint extension");
+    }
+    
+    // Initialize Metadata Pointer extension, pointing at the mint itself
+    if extension_args.metadata.is_some() {
+        invoke(
+            &initialize_metadata_pointer(
+                &spl_token_2022::ID,
+                mint.key,
+                Some(vault_config.key()),
+                Some(mint.key()),
+            )?,
+            &[mint.to_account_info()],
+        )?;
+        msg!("Initialized MetadataPointer extension");
+    }
+    
+    // Initialize Group Pointer extension, pointing at the mint itself
+    if extension_args.group_max_size.is_some() {
+        invoke(
+            &initialize_group_pointer(&spl_token_2022::ID, mint.key, Some(vault_config.key()), Some(mint.key()))?,
+            &[mint.to_account_info()],
+        )?;
+        msg!("Initialized GroupPointer extension");
+    }
+    
+    // Initialize Group Member Pointer extension, pointing at the mint itself
+    if group.is_some() {
+        invoke(
+            &initialize_group_member_pointer(&spl_token_2022::ID, mint.key, Some(vault_config.key()), Some(mint.key()))?,
+            &[mint.to_account_info()],
+        )?;
+        msg!("Initialized GroupMemberPointer extension");
+    }
+    
+    // Initialize the mint itself; the vault config PDA holds every authority
+    // except minting (freeze, permanent delegate, rate, close, confidential
+    // transfer, metadata and group update) so those powers go through the
+    // program's authority checks
     invoke(
         &initialize_mint2(
             &spl_token_2022::ID,
             mint.key,
             &authority.key(),
-            Some(&authority.key()),
+            Some(vault_config.key),
             decimals,
         )?,
         &[mint.to_account_info()],
     )?;
     
-    msg!("Mint created with {} extensions", extensions.len());
+    // Write the metadata now that the mint is initialized
+    if let Some(args) = &extension_args.metadata {
+        invoke(
+            &spl_token_metadata_interface::instruction::initialize(
+                &spl_token_2022::ID,
+                mint.key,
+                vault_config.key,
+                mint.key,
+                authority.key,
+                args.name.clone(),
+                args.symbol.clone(),
+                args.uri.clone(),
+            ),
+            &[mint.to_account_info(), vault_config.to_account_info(), authority.to_account_info()],
+        )?;
+        msg!("Initialized TokenMetadata: {} ({})", args.name, args.symbol);
+    }
+    
+    // Write the group now that the mint is initialized
+    if let Some(max_size) = extension_args.group_max_size {
+        invoke(
+            &initialize_group(
+                &spl_token_2022::ID,
+                mint.key,
+                mint.key,
+                authority.key,
+                Some(vault_config.key()),
+                max_size,
+            ),
+            &[mint.to_account_info(), authority.to_account_info()],
+        )?;
+        msg!("Initialized TokenGroup: max size {}", max_size);
+    }
+    
+    // Join the group, signed by the group's vault config, and list the mint
+    // in the group's registry
+    if let Some((group_mint, group_vault_config, registry)) = group {
+        let group_key = group_mint.key();
+        let group_seeds = &[VAULT_CONFIG_SEED, group_key.as_ref(), &[group_vault_config.config_bump]];
+        invoke_signed(
+            &initialize_member(
+                &spl_token_2022::ID,
+                mint.key,
+                mint.key,
+                authority.key,
+                &group_key,
+                &group_vault_config.key(),
+            ),
+            &[
+                mint.to_account_info(),
+                authority.to_account_info(),
+                group_mint.to_account_info(),
+                group_vault_config.to_account_info(),
+            ],
+            &[&group_seeds[..]],
+        )?;
+        
+        let member_number = {
+            let data = mint.try_borrow_data()?;
+            let state = StateWithExtensions::<Mint>::unpack(&data)?;
+            u32::from(state.get_extension::<TokenGroupMember>()?.member_number)
+        };
+        msg!("Joined group {} as member {}", group_key, member_number);
+        
+        registry.members.push(mint.key());
+        
+        emit!(GroupMemberAdded {
+            registry: registry.key(),
+            group_mint: group_key,
+            member_mint: mint.key(),
+            member_number,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+    }
+    
+    emit!(MintCreated {
+        mint: mint.key(),
+        authority: authority.key(),
+        decimals,
+        transfer_fee_basis_points: extension_args
+            .enable_transfer_fee
+            .then_some(extension_args.transfer_fee_basis_points),
+        permanent_delegate: extension_args.enable_permanent_delegate,
+        default_frozen: extension_args.enable_default_frozen,
+        interest_rate: extension_args
+            .enable_interest_bearing
+            .then_some(extension_args.interest_rate),
+        mint_close_authority: extension_args.enable_mint_close_authority,
+        confidential_transfer: extension_args.confidential_transfer.is_some(),
+        group_max_size: extension_args.group_max_size,
+        group: ctx.accounts.group_mint.as_ref().map(|group_mint| group_mint.key()),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
     Ok(())
 }
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::events::*;
+use crate::state::*;
+
+/// Create a vault that holds several mints under one authority; add mints
+/// with `add_vault_mint`
+#[derive(Accounts)]
+#[instruction(vault_id: u64)]
+pub struct CreateMultiVault<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + MultiVault::INIT_SPACE,
+        seeds = [MULTI_VAULT_SEED, authority.key().as_ref(), &vault_id.to_le_bytes()],
+        bump,
+    )]
+    pub multi_vault: Account<'info, MultiVault>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<CreateMultiVault>, vault_id: u64) -> Result<()> {
+    let multi_vault = &mut ctx.accounts.multi_vault;
+    
+    multi_vault.authority = ctx.accounts.authority.key();
+    multi_vault.vault_id = vault_id;
+    multi_vault.mint_count = 0;
+    multi_vault.bump = ctx.bumps.multi_vault;
+    
+    emit!(MultiVaultCreated {
+        multi_vault: multi_vault.key(),
+        authority: multi_vault.authority,
+        vault_id,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::{BaseStateWithExtensions, StateWithExtensions},
+    state::Mint as MintState,
+};
+use anchor_spl::token_interface::Mint;
+use spl_token_group_interface::state::TokenGroup;
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
+
+/// Start the registry of a group mint's member vaults. Mints created with
+/// this group afterwards are appended to it.
+#[derive(Accounts)]
+pub struct CreateVaultRegistry<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [VAULT_CONFIG_SEED, mint.key().as_ref()],
+        bump = vault_config.config_bump,
+        has_one = authority @ VaultError::UnauthorizedAuthority,
+        has_one = mint @ VaultError::InvalidMint,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    /// The group mint
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = VaultRegistry::space(0),
+        seeds = [VAULT_REGISTRY_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub registry: Account<'info, VaultRegistry>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<CreateVaultRegistry>) -> Result<()> {
+    {
+        let mint = ctx.accounts.mint.to_account_info();
+        let data = mint.try_borrow_data()?;
+        let state = StateWithExtensions::<MintState>::unpack(&data)?;
+        state
+            .get_extension::<TokenGroup>()
+            .map_err(|_| error!(VaultError::NotATokenGroup))?;
+    }
+    
+    let registry = &mut ctx.accounts.registry;
+    registry.group_mint = ctx.accounts.mint.key();
+    registry.members = Vec::new();
+    registry.bump = ctx.bumps.registry;
+    
+    emit!(VaultRegistryCreated {
+        registry: registry.key(),
+        group_mint: registry.group_mint,
+        authority: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
+
+/// Reserve `total` of the vault's unreserved balance for a beneficiary.
+/// Deposit the allocation first; reserved tokens can only leave through
+/// `withdraw_vested`. `schedule_id` is chosen by the authority and tells a
+/// beneficiary's schedules apart.
+#[derive(Accounts)]
+#[instruction(beneficiary: Pubkey, schedule_id: u64)]
+pub struct CreateVesting<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED, vault_config.mint.as_ref()],
+        bump = vault_config.config_bump,
+        has_one = authority @ VaultError::UnauthorizedAuthority,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    #[account(
+        seeds = [VAULT_SEED, vault_config.mint.as_ref()],
+        bump = vault_config.vault_bump,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + VestingSchedule::INIT_SPACE,
+        seeds = [VESTING_SCHEDULE_SEED, vault_config.key().as_ref(), beneficiary.as_ref(), &schedule_id.to_le_bytes()],
+        bump,
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<CreateVesting>,
+    beneficiary: Pubkey,
+    schedule_id: u64,
+    total: u64,
+    start: i64,
+    cliff: i64,
+    end: i64,
+) -> Result<()> {
+    require!(
+        total > 0 && start <= cliff && cliff <= end,
+        VaultError::InvalidVestingSchedule
+    );
+    
+    let vault_config = &mut ctx.accounts.vault_config;
+    require!(
+        vault_config.unreserved(ctx.accounts.vault.amount) >= total,
+        VaultError::VestingReserved
+    );
+    vault_config.vesting_reserved = vault_config
+        .vesting_reserved
+        .checked_add(total)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    vault_config.vesting_schedules = vault_config
+        .vesting_schedules
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    
+    let schedule = &mut ctx.accounts.vesting_schedule;
+    schedule.vault_config = vault_config.key();
+    schedule.beneficiary = beneficiary;
+    schedule.schedule_id = schedule_id;
+    schedule.start = start;
+    schedule.cliff = cliff;
+    schedule.end = end;
+    schedule.total = total;
+    schedule.released = 0;
+    schedule.bump = ctx.bumps.vesting_schedule;
+    
+    emit!(VestingCreated {
+        vault_config: schedule.vault_config,
+        beneficiary,
+        schedule_id,
+        total,
+        start,
+        cliff,
+        end,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
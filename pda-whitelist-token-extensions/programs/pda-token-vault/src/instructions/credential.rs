@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::Token2022,
+    token_interface::{self, Mint, TokenAccount, TokenInterface},
+};
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
+
+/// Issues (one token) or revokes (burns the balance) a user's whitelist
+/// credential. A no-op for vaults without a credential mint; once one
+/// exists, `add_to_whitelist` and `remove_from_whitelist` must pass the
+/// user's associated credential account so membership and credentials
+/// can't drift.
+pub fn sync_credential<'info>(
+    issue: bool,
+    vault_config: &Account<'info, VaultConfig>,
+    credential_mint: Option<&InterfaceAccount<'info, Mint>>,
+    credential_account: Option<&InterfaceAccount<'info, TokenAccount>>,
+    token_program: Option<&Interface<'info, TokenInterface>>,
+) -> Result<()> {
+    if vault_config.credential_mint == Pubkey::default() {
+        return Ok(());
+    }
+    let (Some(credential_mint), Some(credential_account), Some(token_program)) =
+        (credential_mint, credential_account, token_program)
+    else {
+        return err!(VaultError::MissingCredentialAccounts);
+    };
+    // Credential mints are always Token-2022, so only legacy vaults, which
+    // can't create one, ever pass the legacy Token program here
+    require_keys_eq!(token_program.key(), Token2022::id(), VaultError::RequiresToken2022);
+    
+    // A second account of the user's would let removal burn an empty
+    // decoy while the credential in their associated account survives
+    let (associated, _) = Pubkey::find_program_address(
+        &[credential_account.owner.as_ref(), token_program.key().as_ref(), credential_mint.key().as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    );
+    require_keys_eq!(credential_account.key(), associated, VaultError::CredentialNotAssociated);
+    
+    let mint_key = vault_config.mint;
+    let seeds = &[VAULT_CONFIG_SEED, mint_key.as_ref(), &[vault_config.config_bump]];
+    let timestamp = Clock::get()?.unix_timestamp;
+    if issue {
+        // Re-adding a user whose credential survived must not mint a second
+        if credential_account.amount > 0 {
+            return Ok(());
+        }
+        token_interface::mint_to(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token_interface::MintTo {
+                    mint: credential_mint.to_account_info(),
+                    to: credential_account.to_account_info(),
+                    authority: vault_config.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            1,
+        )?;
+        emit!(CredentialIssued {
+            vault_config: vault_config.key(),
+            user: credential_account.owner,
+            credential_account: credential_account.key(),
+            timestamp,
+        });
+    } else {
+        if credential_account.amount == 0 {
+            return Ok(());
+        }
+        // The vault config is the permanent delegate, so no holder signature
+        token_interface::burn(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token_interface::Burn {
+                    mint: credential_mint.to_account_info(),
+                    from: credential_account.to_account_info(),
+                    authority: vault_config.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            credential_account.amount,
+        )?;
+        emit!(CredentialRevoked {
+            vault_config: vault_config.key(),
+            user: credential_account.owner,
+            credential_account: credential_account.key(),
+            timestamp,
+        });
+    }
+    Ok(())
+}
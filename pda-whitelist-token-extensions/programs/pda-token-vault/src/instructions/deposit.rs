@@ -1,9 +1,8 @@
 use anchor_lang::prelude::*;
-use anchor_spl::{
-    token_2022::Token2022,
-    token_interface::{Mint, TokenAccount, TransferChecked, transfer_checked},
-};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked, transfer_checked};
 use crate::constants::*;
+use crate::events::*;
+use crate::instructions::ui_amount::ui_amount;
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -11,6 +10,7 @@ pub struct Deposit<'info> {
     #[account(mut)]
     pub depositor: Signer<'info>,
     
+    #[account(mint::token_program = token_program)]
     pub mint: InterfaceAccount<'info, Mint>,
     
     #[account(
@@ -33,10 +33,11 @@ pub struct Deposit<'info> {
     )]
     pub vault: InterfaceAccount<'info, TokenAccount>,
     
-    pub token_program: Program<'info, Token2022>,
+    /// Token-2022 or the legacy Token program, whichever owns the mint
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
-pub fn handler(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+pub(crate) fn handler(ctx: Context<Deposit>, amount: u64) -> Result<()> {
     let mint_decimals = ctx.accounts.mint.decimals;
     
     transfer_checked(
@@ -53,6 +54,16 @@ pub fn handler(ctx: Context<Deposit>, amount: u64) -> Result<()> {
         mint_decimals,
     )?;
     
-    msg!("Deposited {} tokens", amount);
+    let ui_amount = ui_amount(&ctx.accounts.token_program, &ctx.accounts.mint, amount)?;
+    msg!("Deposited {} ({} tokens)", amount, ui_amount);
+    
+    emit!(Deposited {
+        vault_config: ctx.accounts.vault_config.key(),
+        mint: ctx.accounts.mint.key(),
+        depositor: ctx.accounts.depositor.key(),
+        amount,
+        ui_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
     Ok(())
 }
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked, transfer_checked};
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::instructions::ui_amount::ui_amount;
+use crate::state::*;
+
+/// Pay out a proposal that reached its quorum before it unlocked, and return
+/// its rent. As with `approve_withdrawal`, the withdrawer must still be
+/// whitelisted and within their current limit.
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(mut)]
+    pub withdrawer: Signer<'info>,
+    
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED, mint.key().as_ref()],
+        bump = vault_config.config_bump,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    #[account(
+        seeds = [WHITELIST_ENTRY_SEED, vault_config.key().as_ref(), withdrawer.key().as_ref()],
+        bump = whitelist_entry.bump,
+        constraint = whitelist_entry.user == withdrawer.key() @ VaultError::NotWhitelisted,
+    )]
+    pub whitelist_entry: Account<'info, WhitelistEntry>,
+    
+    #[account(
+        mut,
+        close = withdrawer,
+        seeds = [PROPOSAL_SEED, vault_config.key().as_ref(), withdrawer.key().as_ref()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+    
+    #[account(mut, address = proposal.destination)]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, mint.key().as_ref()],
+        bump = vault_config.vault_bump,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Token-2022 or the legacy Token program, whichever owns the mint
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub(crate) fn handler(ctx: Context<ExecuteProposal>) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+    let vault_config = &ctx.accounts.vault_config;
+    let amount = proposal.amount;
+    let now = Clock::get()?.unix_timestamp;
+    
+    // Approvers removed since approving no longer count, and a quorum of 0
+    // means approvals were turned off, not that none are needed
+    require!(
+        vault_config.approval_quorum > 0
+            && vault_config.counted_approvals(&proposal.approvals) >= vault_config.approval_quorum as usize,
+        VaultError::QuorumNotReached
+    );
+    require!(proposal.is_unlocked(now), VaultError::WithdrawalLocked);
+    require!(
+        ctx.accounts.whitelist_entry.is_within_limit(amount),
+        VaultError::AmountExceedsLimit
+    );
+    require!(
+        vault_config.unreserved(ctx.accounts.vault.amount) >= amount,
+        VaultError::VestingReserved
+    );
+    
+    let vault_config = &mut ctx.accounts.vault_config;
+    vault_config.open_proposals = vault_config.open_proposals.checked_sub(1).ok_or(ProgramError::ArithmeticOverflow)?;
+    
+    let mint_key = ctx.accounts.mint.key();
+    let vault_bump = ctx.accounts.vault_config.vault_bump;
+    let signer_seeds = &[VAULT_SEED, mint_key.as_ref(), &[vault_bump]];
+    
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+            &[signer_seeds],
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+    
+    let ui_amount = ui_amount(&ctx.accounts.token_program, &ctx.accounts.mint, amount)?;
+    msg!("Executed approved withdrawal of {} ({} tokens)", amount, ui_amount);
+    
+    emit!(Withdrew {
+        vault_config: ctx.accounts.vault_config.key(),
+        mint: mint_key,
+        withdrawer: ctx.accounts.withdrawer.key(),
+        amount,
+        ui_amount,
+        timestamp: now,
+    });
+    Ok(())
+}
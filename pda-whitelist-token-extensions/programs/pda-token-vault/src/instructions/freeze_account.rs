@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct FreezeAccount<'info> {
+    pub authority: Signer<'info>,
+    
+    /// Freeze authority of the mint
+    #[account(
+        seeds = [VAULT_CONFIG_SEED, mint.key().as_ref()],
+        bump = vault_config.config_bump,
+        has_one = authority @ VaultError::UnauthorizedAuthority,
+        has_one = mint @ VaultError::InvalidMint,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(mut, token::mint = mint)]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Token-2022 or the legacy Token program, whichever owns the mint
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub(crate) fn handler(ctx: Context<FreezeAccount>) -> Result<()> {
+    let mint_key = ctx.accounts.mint.key();
+    let config_bump = ctx.accounts.vault_config.config_bump;
+    let signer_seeds = &[VAULT_CONFIG_SEED, mint_key.as_ref(), &[config_bump]];
+    
+    token_interface::freeze_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token_interface::FreezeAccount {
+            account: ctx.accounts.token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            authority: ctx.accounts.vault_config.to_account_info(),
+        },
+        &[signer_seeds],
+    ))?;
+    
+    emit!(AccountFrozen {
+        vault_config: ctx.accounts.vault_config.key(),
+        token_account: ctx.accounts.token_account.key(),
+        owner: ctx.accounts.token_account.owner,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
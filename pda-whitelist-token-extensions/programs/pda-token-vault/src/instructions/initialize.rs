@@ -1,5 +1,11 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token_2022::spl_token_2022::{extension::StateWithExtensions, state::Account as TokenAccountState};
+use anchor_spl::token_interface::{thaw_account, Mint, ThawAccount, TokenInterface};
 use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::instructions::vault_account::create_vault_account;
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -7,8 +13,13 @@ pub struct Initialize<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
     
-    /// CHECK: Mint account (already created via create_mint)
-    pub mint: AccountInfo<'info>,
+    /// Only the mint authority may claim the mint's vault, which inherits
+    /// the freeze authority and every other power the mint grants the PDA
+    #[account(
+        mint::token_program = token_program,
+        constraint = mint.mint_authority == COption::Some(authority.key()) @ VaultError::NotMintAuthority,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
     
     #[account(
         init,
@@ -19,23 +30,73 @@ pub struct Initialize<'info> {
     )]
     pub vault_config: Account<'info, VaultConfig>,
     
+    /// CHECK: Created in the handler as a token account that is its own authority
+    #[account(mut, seeds = [VAULT_SEED, mint.key().as_ref()], bump)]
+    pub vault: UncheckedAccount<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+    
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<Initialize>) -> Result<()> {
-    let vault_config = &mut ctx.accounts.vault_config;
+pub(crate) fn handler(ctx: Context<Initialize>) -> Result<()> {
+    create_vault(&ctx)?;
     
-    // Derive vault bump for future use
-    let (_, vault_bump) = Pubkey::find_program_address(
-        &[VAULT_SEED, ctx.accounts.mint.key().as_ref()],
-        ctx.program_id,
-    );
+    let vault_config = &mut ctx.accounts.vault_config;
     
     vault_config.authority = ctx.accounts.authority.key();
     vault_config.mint = ctx.accounts.mint.key();
+    vault_config.clawback_authority = ctx.accounts.authority.key();
+    vault_config.credential_mint = Pubkey::default();
+    vault_config.guardian = ctx.accounts.authority.key();
+    vault_config.withdrawal_delay = 0;
+    vault_config.vesting_reserved = 0;
+    vault_config.approvers = Vec::new();
+    vault_config.approval_quorum = 0;
+    vault_config.approval_threshold = 0;
     vault_config.config_bump = ctx.bumps.vault_config;
-    vault_config.vault_bump = vault_bump;
+    vault_config.vault_bump = ctx.bumps.vault;
+    
+    emit!(VaultInitialized {
+        vault_config: vault_config.key(),
+        authority: vault_config.authority,
+        mint: vault_config.mint,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+/// Create the vault token account, then thaw it if the mint makes new
+/// accounts frozen
+fn create_vault(ctx: &Context<Initialize>) -> Result<()> {
+    let mint = ctx.accounts.mint.to_account_info();
+    let mint_key = mint.key();
+    let seeds: &[&[u8]] = &[VAULT_SEED, mint_key.as_ref(), &[ctx.bumps.vault]];
+    create_vault_account(
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.vault.to_account_info(),
+        &mint,
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        seeds,
+    )?;
     
-    msg!("Vault initialized for mint: {}", ctx.accounts.mint.key());
+    // Mints whose accounts default to frozen would leave the vault unusable
+    let vault_frozen = {
+        let data = ctx.accounts.vault.try_borrow_data()?;
+        StateWithExtensions::<TokenAccountState>::unpack(&data)?.base.is_frozen()
+    };
+    if vault_frozen {
+        let config_seeds: &[&[u8]] = &[VAULT_CONFIG_SEED, mint_key.as_ref(), &[ctx.bumps.vault_config]];
+        thaw_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            ThawAccount {
+                account: ctx.accounts.vault.to_account_info(),
+                mint,
+                authority: ctx.accounts.vault_config.to_account_info(),
+            },
+            &[config_seeds],
+        ))?;
+    }
     Ok(())
 }
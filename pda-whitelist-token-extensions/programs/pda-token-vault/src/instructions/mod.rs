@@ -2,12 +2,91 @@ pub mod create_mint;
 pub mod initialize;
 pub mod add_to_whitelist;
 pub mod remove_from_whitelist;
+pub mod update_whitelist_entry;
 pub mod deposit;
 pub mod withdraw;
+pub mod freeze_account;
+pub mod thaw_account;
+pub mod update_token_metadata_field;
+pub mod remove_metadata_key;
+pub mod update_interest_rate;
+pub mod clawback;
+pub mod set_clawback_authority;
+pub mod create_credential_mint;
+pub mod close_vault;
+pub mod configure_confidential_vault;
+pub mod approve_confidential_account;
+pub mod confidential_vault_deposit;
+pub mod apply_vault_pending_balance;
+pub mod confidential_withdraw;
+pub mod create_vault_registry;
+pub mod create_multi_vault;
+pub mod add_vault_mint;
+pub mod set_vault_mint_limit;
+pub mod add_multi_vault_member;
+pub mod remove_multi_vault_member;
+pub mod multi_vault_deposit;
+pub mod multi_vault_withdraw;
+pub mod set_withdrawal_timelock;
+pub mod request_withdrawal;
+pub mod claim_withdrawal;
+pub mod cancel_withdrawal;
+pub mod veto_withdrawal;
+pub mod create_vesting;
+pub mod withdraw_vested;
+pub mod close_vesting;
+pub mod set_approvers;
+pub mod propose_withdrawal;
+pub mod approve_withdrawal;
+pub mod cancel_proposal;
+pub mod execute_proposal;
+pub mod veto_proposal;
+pub(crate) mod confidential;
+pub(crate) mod credential;
+pub(crate) mod holder;
+pub(crate) mod ui_amount;
+pub(crate) mod vault_account;
 
 pub use create_mint::*;
 pub use initialize::*;
 pub use add_to_whitelist::*;
 pub use remove_from_whitelist::*;
+pub use update_whitelist_entry::*;
 pub use deposit::*;
 pub use withdraw::*;
+pub use freeze_account::*;
+pub use thaw_account::*;
+pub use update_token_metadata_field::*;
+pub use remove_metadata_key::*;
+pub use update_interest_rate::*;
+pub use clawback::*;
+pub use set_clawback_authority::*;
+pub use create_credential_mint::*;
+pub use close_vault::*;
+pub use configure_confidential_vault::*;
+pub use approve_confidential_account::*;
+pub use confidential_vault_deposit::*;
+pub use apply_vault_pending_balance::*;
+pub use confidential_withdraw::*;
+pub use create_vault_registry::*;
+pub use create_multi_vault::*;
+pub use add_vault_mint::*;
+pub use set_vault_mint_limit::*;
+pub use add_multi_vault_member::*;
+pub use remove_multi_vault_member::*;
+pub use multi_vault_deposit::*;
+pub use multi_vault_withdraw::*;
+pub use set_withdrawal_timelock::*;
+pub use request_withdrawal::*;
+pub use claim_withdrawal::*;
+pub use cancel_withdrawal::*;
+pub use veto_withdrawal::*;
+pub use create_vesting::*;
+pub use withdraw_vested::*;
+pub use close_vesting::*;
+pub use set_approvers::*;
+pub use propose_withdrawal::*;
+pub use approve_withdrawal::*;
+pub use cancel_proposal::*;
+pub use execute_proposal::*;
+pub use veto_proposal::*;
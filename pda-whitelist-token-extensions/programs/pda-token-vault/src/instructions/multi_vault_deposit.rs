@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked, transfer_checked};
+use crate::constants::*;
+use crate::events::*;
+use crate::instructions::ui_amount::ui_amount;
+use crate::state::*;
+
+/// Deposit one of a multi-mint vault's mints into its token account
+#[derive(Accounts)]
+pub struct MultiVaultDeposit<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+    
+    pub multi_vault: Account<'info, MultiVault>,
+    
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    /// Only mints added with `add_vault_mint` have one
+    #[account(
+        seeds = [VAULT_ASSET_SEED, multi_vault.key().as_ref(), mint.key().as_ref()],
+        bump = asset.bump,
+    )]
+    pub asset: Account<'info, VaultAsset>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = depositor,
+    )]
+    pub depositor_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, multi_vault.key().as_ref(), mint.key().as_ref()],
+        bump = asset.vault_bump,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub(crate) fn handler(ctx: Context<MultiVaultDeposit>, amount: u64) -> Result<()> {
+    transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.depositor_token_account.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+                authority: ctx.accounts.depositor.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+    
+    let ui_amount = ui_amount(&ctx.accounts.token_program, &ctx.accounts.mint, amount)?;
+    msg!("Deposited {} ({} tokens)", amount, ui_amount);
+    
+    emit!(Deposited {
+        vault_config: ctx.accounts.multi_vault.key(),
+        mint: ctx.accounts.mint.key(),
+        depositor: ctx.accounts.depositor.key(),
+        amount,
+        ui_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
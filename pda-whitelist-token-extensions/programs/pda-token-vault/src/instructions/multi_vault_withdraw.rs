@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked, transfer_checked};
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::instructions::ui_amount::ui_amount;
+use crate::state::*;
+
+/// Withdraw one of a multi-mint vault's mints, within that mint's limit
+#[derive(Accounts)]
+pub struct MultiVaultWithdraw<'info> {
+    #[account(mut)]
+    pub withdrawer: Signer<'info>,
+    
+    pub multi_vault: Account<'info, MultiVault>,
+    
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        seeds = [VAULT_ASSET_SEED, multi_vault.key().as_ref(), mint.key().as_ref()],
+        bump = asset.bump,
+    )]
+    pub asset: Account<'info, VaultAsset>,
+    
+    /// Whitelist entry for withdrawer - must exist
+    #[account(
+        seeds = [WHITELIST_ENTRY_SEED, multi_vault.key().as_ref(), withdrawer.key().as_ref()],
+        bump = whitelist_entry.bump,
+        constraint = whitelist_entry.user == withdrawer.key() @ VaultError::NotWhitelisted,
+    )]
+    pub whitelist_entry: Account<'info, WhitelistEntry>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = withdrawer,
+    )]
+    pub withdrawer_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, multi_vault.key().as_ref(), mint.key().as_ref()],
+        bump = asset.vault_bump,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub(crate) fn handler(ctx: Context<MultiVaultWithdraw>, amount: u64) -> Result<()> {
+    require!(ctx.accounts.asset.is_within_limit(amount), VaultError::AmountExceedsLimit);
+    
+    let multi_vault_key = ctx.accounts.multi_vault.key();
+    let mint_key = ctx.accounts.mint.key();
+    let signer_seeds = &[VAULT_SEED, multi_vault_key.as_ref(), mint_key.as_ref(), &[ctx.accounts.asset.vault_bump]];
+    
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.withdrawer_token_account.to_account_info(),
+                authority: ctx.accounts.vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+            &[signer_seeds],
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+    
+    let ui_amount = ui_amount(&ctx.accounts.token_program, &ctx.accounts.mint, amount)?;
+    msg!("Withdrew {} ({} tokens)", amount, ui_amount);
+    
+    emit!(Withdrew {
+        vault_config: multi_vault_key,
+        mint: mint_key,
+        withdrawer: ctx.accounts.withdrawer.key(),
+        amount,
+        ui_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
+
+/// Ask the approvers to release a withdrawal above the vault's threshold.
+/// A user has at most one open proposal per vault, and it can't execute
+/// before the vault's withdrawal delay has passed.
+#[derive(Accounts)]
+pub struct ProposeWithdrawal<'info> {
+    #[account(mut)]
+    pub withdrawer: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED, vault_config.mint.as_ref()],
+        bump = vault_config.config_bump,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    #[account(
+        seeds = [WHITELIST_ENTRY_SEED, vault_config.key().as_ref(), withdrawer.key().as_ref()],
+        bump = whitelist_entry.bump,
+        constraint = whitelist_entry.user == withdrawer.key() @ VaultError::NotWhitelisted,
+    )]
+    pub whitelist_entry: Account<'info, WhitelistEntry>,
+    
+    #[account(
+        token::mint = vault_config.mint,
+        token::authority = withdrawer,
+    )]
+    pub withdrawer_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        init,
+        payer = withdrawer,
+        space = 8 + Proposal::INIT_SPACE,
+        seeds = [PROPOSAL_SEED, vault_config.key().as_ref(), withdrawer.key().as_ref()],
+        bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<ProposeWithdrawal>, amount: u64) -> Result<()> {
+    require!(
+        ctx.accounts.whitelist_entry.is_within_limit(amount),
+        VaultError::AmountExceedsLimit
+    );
+    require!(
+        ctx.accounts.vault_config.requires_approval(amount),
+        VaultError::ApprovalNotRequired
+    );
+    
+    let now = Clock::get()?.unix_timestamp;
+    let vault_config = &mut ctx.accounts.vault_config;
+    let unlock_at = now.checked_add(vault_config.withdrawal_delay).ok_or(ProgramError::ArithmeticOverflow)?;
+    vault_config.open_proposals = vault_config.open_proposals.checked_add(1).ok_or(ProgramError::ArithmeticOverflow)?;
+    
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.vault_config = ctx.accounts.vault_config.key();
+    proposal.withdrawer = ctx.accounts.withdrawer.key();
+    proposal.destination = ctx.accounts.withdrawer_token_account.key();
+    proposal.amount = amount;
+    proposal.approvals = Vec::new();
+    proposal.unlock_at = unlock_at;
+    proposal.bump = ctx.bumps.proposal;
+    
+    emit!(WithdrawalProposed {
+        vault_config: proposal.vault_config,
+        proposal: proposal.key(),
+        withdrawer: proposal.withdrawer,
+        amount,
+        unlock_at,
+        timestamp: now,
+    });
+    Ok(())
+}
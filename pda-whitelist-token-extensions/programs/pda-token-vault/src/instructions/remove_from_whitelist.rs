@@ -1,6 +1,10 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use crate::constants::*;
 use crate::error::VaultError;
+use crate::events::*;
+use crate::instructions::credential::sync_credential;
+use crate::instructions::holder::sync_holder_frozen;
 use crate::state::*;
 
 /// Remove a user from the whitelist by closing their PDA account
@@ -26,9 +30,54 @@ pub struct RemoveFromWhitelist<'info> {
         constraint = whitelist_entry.user == user @ VaultError::NotWhitelisted,
     )]
     pub whitelist_entry: Account<'info, WhitelistEntry>,
+    
+    /// Optional with `holder_token_account` and `token_program`: the user's
+    /// token account is frozen via the vault config's freeze authority
+    #[account(address = vault_config.mint @ VaultError::InvalidMint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        mut,
+        constraint = holder_token_account.mint == vault_config.mint @ VaultError::InvalidMint,
+        constraint = holder_token_account.owner == user @ VaultError::HolderMismatch,
+    )]
+    pub holder_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    
+    /// Required with `credential_account` and `token_program` once the vault
+    /// has a credential mint: a credential is burned from the user
+    #[account(mut, address = vault_config.credential_mint @ VaultError::InvalidCredentialMint)]
+    pub credential_mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        mut,
+        constraint = credential_account.mint == vault_config.credential_mint @ VaultError::InvalidCredentialMint,
+        constraint = credential_account.owner == user @ VaultError::HolderMismatch,
+    )]
+    pub credential_account: Option<InterfaceAccount<'info, TokenAccount>>,
 }
 
-pub fn handler(_ctx: Context<RemoveFromWhitelist>, user: Pubkey) -> Result<()> {
-    msg!("Removed {} from whitelist", user);
+pub(crate) fn handler(ctx: Context<RemoveFromWhitelist>, user: Pubkey) -> Result<()> {
+    sync_holder_frozen(
+        true,
+        &ctx.accounts.vault_config,
+        ctx.accounts.mint.as_ref(),
+        ctx.accounts.holder_token_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    )?;
+    sync_credential(
+        false,
+        &ctx.accounts.vault_config,
+        ctx.accounts.credential_mint.as_ref(),
+        ctx.accounts.credential_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    )?;
+    
+    emit!(WhitelistRemoved {
+        vault_config: ctx.accounts.vault_config.key(),
+        user,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
     Ok(())
 }
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
+
+/// Revoke a user's access to a multi-mint vault, returning the entry's rent
+#[derive(Accounts)]
+#[instruction(user: Pubkey)]
+pub struct RemoveMultiVaultMember<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [MULTI_VAULT_SEED, authority.key().as_ref(), &multi_vault.vault_id.to_le_bytes()],
+        bump = multi_vault.bump,
+        has_one = authority @ VaultError::UnauthorizedAuthority,
+    )]
+    pub multi_vault: Account<'info, MultiVault>,
+    
+    #[account(
+        mut,
+        close = authority,
+        seeds = [WHITELIST_ENTRY_SEED, multi_vault.key().as_ref(), user.as_ref()],
+        bump = whitelist_entry.bump,
+        constraint = whitelist_entry.user == user @ VaultError::NotWhitelisted,
+    )]
+    pub whitelist_entry: Account<'info, WhitelistEntry>,
+}
+
+pub(crate) fn handler(ctx: Context<RemoveMultiVaultMember>, user: Pubkey) -> Result<()> {
+    emit!(WhitelistRemoved {
+        vault_config: ctx.accounts.multi_vault.key(),
+        user,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
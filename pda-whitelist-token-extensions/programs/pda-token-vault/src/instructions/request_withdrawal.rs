@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
+
+/// Open a withdrawal ticket that unlocks after the vault's delay. A user
+/// has at most one ticket per vault; cancel it to request a different amount.
+#[derive(Accounts)]
+pub struct RequestWithdrawal<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED, vault_config.mint.as_ref()],
+        bump = vault_config.config_bump,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    #[account(
+        seeds = [WHITELIST_ENTRY_SEED, vault_config.key().as_ref(), user.key().as_ref()],
+        bump = whitelist_entry.bump,
+        constraint = whitelist_entry.user == user.key() @ VaultError::NotWhitelisted,
+    )]
+    pub whitelist_entry: Account<'info, WhitelistEntry>,
+    
+    #[account(
+        init,
+        payer = user,
+        space = 8 + WithdrawalTicket::INIT_SPACE,
+        seeds = [WITHDRAWAL_TICKET_SEED, vault_config.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub withdrawal_ticket: Account<'info, WithdrawalTicket>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<RequestWithdrawal>, amount: u64) -> Result<()> {
+    require!(
+        ctx.accounts.whitelist_entry.is_within_limit(amount),
+        VaultError::AmountExceedsLimit
+    );
+    require!(
+        !ctx.accounts.vault_config.requires_approval(amount),
+        VaultError::ApprovalRequired
+    );
+    
+    let now = Clock::get()?.unix_timestamp;
+    let unlock_time = now
+        .checked_add(ctx.accounts.vault_config.withdrawal_delay)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    
+    let vault_config = &mut ctx.accounts.vault_config;
+    vault_config.open_tickets = vault_config.open_tickets.checked_add(1).ok_or(ProgramError::ArithmeticOverflow)?;
+    
+    let ticket = &mut ctx.accounts.withdrawal_ticket;
+    ticket.vault_config = ctx.accounts.vault_config.key();
+    ticket.user = ctx.accounts.user.key();
+    ticket.amount = amount;
+    ticket.unlock_time = unlock_time;
+    ticket.bump = ctx.bumps.withdrawal_ticket;
+    
+    emit!(WithdrawalRequested {
+        vault_config: ticket.vault_config,
+        user: ticket.user,
+        amount,
+        unlock_time,
+        timestamp: now,
+    });
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
+
+/// Replace the withdrawal approver set. Pending proposals keep their
+/// approvals, but only those from keys still in the set count.
+#[derive(Accounts)]
+pub struct SetApprovers<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED, vault_config.mint.as_ref()],
+        bump = vault_config.config_bump,
+        has_one = authority @ VaultError::UnauthorizedAuthority,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+}
+
+pub(crate) fn handler(ctx: Context<SetApprovers>, approvers: Vec<Pubkey>, quorum: u8, threshold: u64) -> Result<()> {
+    let distinct = approvers.iter().enumerate().all(|(i, key)| !approvers[..i].contains(key));
+    require!(
+        approvers.len() <= MAX_APPROVERS && distinct && quorum as usize <= approvers.len(),
+        VaultError::InvalidApproverSet
+    );
+    
+    let vault_config = &mut ctx.accounts.vault_config;
+    vault_config.approvers = approvers.clone();
+    vault_config.approval_quorum = quorum;
+    vault_config.approval_threshold = threshold;
+    
+    emit!(ApproversSet {
+        vault_config: vault_config.key(),
+        approvers,
+        quorum,
+        threshold,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
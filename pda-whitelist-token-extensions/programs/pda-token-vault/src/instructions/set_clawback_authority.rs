@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
+
+/// Hand the clawback role to another key (e.g. a compliance officer)
+#[derive(Accounts)]
+pub struct SetClawbackAuthority<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED, vault_config.mint.as_ref()],
+        bump = vault_config.config_bump,
+        has_one = authority @ VaultError::UnauthorizedAuthority,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+}
+
+pub(crate) fn handler(ctx: Context<SetClawbackAuthority>, new_clawback_authority: Pubkey) -> Result<()> {
+    let vault_config = &mut ctx.accounts.vault_config;
+    let previous = vault_config.clawback_authority;
+    vault_config.clawback_authority = new_clawback_authority;
+    
+    emit!(ClawbackAuthorityChanged {
+        vault_config: vault_config.key(),
+        previous,
+        new: new_clawback_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
+
+/// Change the per-withdrawal limit for one mint of a multi-mint vault
+#[derive(Accounts)]
+pub struct SetVaultMintLimit<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [MULTI_VAULT_SEED, authority.key().as_ref(), &multi_vault.vault_id.to_le_bytes()],
+        bump = multi_vault.bump,
+        has_one = authority @ VaultError::UnauthorizedAuthority,
+    )]
+    pub multi_vault: Account<'info, MultiVault>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_ASSET_SEED, multi_vault.key().as_ref(), asset.mint.as_ref()],
+        bump = asset.bump,
+    )]
+    pub asset: Account<'info, VaultAsset>,
+}
+
+pub(crate) fn handler(ctx: Context<SetVaultMintLimit>, max_amount: u64) -> Result<()> {
+    let asset = &mut ctx.accounts.asset;
+    asset.max_amount = max_amount;
+    
+    emit!(VaultMintConfigured {
+        multi_vault: asset.multi_vault,
+        mint: asset.mint,
+        token_program: asset.token_program,
+        max_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
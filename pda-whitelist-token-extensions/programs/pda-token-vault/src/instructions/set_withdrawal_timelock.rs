@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
+
+/// Set the cooling-off window for withdrawals and who may veto them
+#[derive(Accounts)]
+pub struct SetWithdrawalTimelock<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED, vault_config.mint.as_ref()],
+        bump = vault_config.config_bump,
+        has_one = authority @ VaultError::UnauthorizedAuthority,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+}
+
+pub(crate) fn handler(ctx: Context<SetWithdrawalTimelock>, withdrawal_delay: i64, guardian: Pubkey) -> Result<()> {
+    require!(withdrawal_delay >= 0, VaultError::InvalidWithdrawalDelay);
+    
+    let vault_config = &mut ctx.accounts.vault_config;
+    vault_config.withdrawal_delay = withdrawal_delay;
+    vault_config.guardian = guardian;
+    
+    emit!(WithdrawalTimelockSet {
+        vault_config: vault_config.key(),
+        withdrawal_delay,
+        guardian,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022,
+    token_interface::{Mint, TokenInterface},
+};
+
+/// `amount` as the mint's token program displays it. Asks the token program
+/// rather than dividing by decimals so interest-bearing mints include the
+/// interest accrued so far; legacy Token mints answer the same request.
+pub fn ui_amount<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    mint: &InterfaceAccount<'info, Mint>,
+    amount: u64,
+) -> Result<String> {
+    token_2022::amount_to_ui_amount(
+        CpiContext::new(
+            token_program.to_account_info(),
+            token_2022::AmountToUiAmount { account: mint.to_account_info() },
+        ),
+        amount,
+    )
+}
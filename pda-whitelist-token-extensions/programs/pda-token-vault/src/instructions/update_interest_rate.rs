@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::{
+        spl_token_2022::{
+            extension::{interest_bearing_mint::InterestBearingConfig, BaseStateWithExtensions, StateWithExtensions},
+            state::Mint as MintState,
+        },
+        Token2022,
+    },
+    token_interface::{self, Mint},
+};
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct UpdateInterestRate<'info> {
+    pub authority: Signer<'info>,
+    
+    /// Rate authority of the mint
+    #[account(
+        seeds = [VAULT_CONFIG_SEED, mint.key().as_ref()],
+        bump = vault_config.config_bump,
+        has_one = authority @ VaultError::UnauthorizedAuthority,
+        has_one = mint @ VaultError::InvalidMint,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    #[account(mut, owner = Token2022::id() @ VaultError::RequiresToken2022)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub(crate) fn handler(ctx: Context<UpdateInterestRate>, rate: i16) -> Result<()> {
+    let previous_rate = {
+        let mint_info = ctx.accounts.mint.to_account_info();
+        let data = mint_info.try_borrow_data()?;
+        let mint = StateWithExtensions::<MintState>::unpack(&data)?;
+        let config = mint
+            .get_extension::<InterestBearingConfig>()
+            .map_err(|_| error!(VaultError::InterestBearingNotEnabled))?;
+        i16::from(config.current_rate)
+    };
+    
+    let mint_key = ctx.accounts.mint.key();
+    let config_bump = ctx.accounts.vault_config.config_bump;
+    let signer_seeds = &[VAULT_CONFIG_SEED, mint_key.as_ref(), &[config_bump]];
+    
+    token_interface::interest_bearing_mint_update_rate(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::InterestBearingMintUpdateRate {
+                token_program_id: ctx.accounts.token_program.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                rate_authority: ctx.accounts.vault_config.to_account_info(),
+            },
+            &[signer_seeds],
+        ),
+        rate,
+    )?;
+    
+    emit!(InterestRateUpdated {
+        vault_config: ctx.accounts.vault_config.key(),
+        mint: mint_key,
+        previous_rate,
+        new_rate: rate,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
+
+/// Change a user's limit in place; unlike remove-then-add this leaves the
+/// entry, and any credential token issued for it, untouched
+#[derive(Accounts)]
+#[instruction(user: Pubkey)]
+pub struct UpdateWhitelistEntry<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [VAULT_CONFIG_SEED, vault_config.mint.as_ref()],
+        bump = vault_config.config_bump,
+        has_one = authority @ VaultError::UnauthorizedAuthority,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    #[account(
+        mut,
+        seeds = [WHITELIST_ENTRY_SEED, vault_config.key().as_ref(), user.as_ref()],
+        bump = whitelist_entry.bump,
+        constraint = whitelist_entry.user == user @ VaultError::NotWhitelisted,
+    )]
+    pub whitelist_entry: Account<'info, WhitelistEntry>,
+}
+
+pub(crate) fn handler(ctx: Context<UpdateWhitelistEntry>, user: Pubkey, max_amount: u64) -> Result<()> {
+    ctx.accounts.whitelist_entry.max_amount = max_amount;
+    
+    emit!(WhitelistUpdated {
+        vault_config: ctx.accounts.vault_config.key(),
+        user,
+        max_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer};
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+    state::{Account as TokenAccountState, Mint as MintState},
+};
+use anchor_spl::token_interface::{initialize_account3, InitializeAccount3};
+
+/// `init` can't make a token account its own authority, so create it by hand
+/// with room for the account extensions the mint requires (e.g. withheld fees)
+pub fn create_vault_account<'info>(
+    payer: &AccountInfo<'info>,
+    vault: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    vault_seeds: &[&[u8]],
+) -> Result<()> {
+    let space = if *mint.owner == anchor_spl::token_2022::ID {
+        let data = mint.try_borrow_data()?;
+        let state = StateWithExtensions::<MintState>::unpack(&data)?;
+        let extensions = ExtensionType::get_required_init_account_extensions(&state.get_extension_types()?);
+        ExtensionType::try_calculate_account_len::<TokenAccountState>(&extensions)?
+    } else {
+        anchor_spl::token::TokenAccount::LEN
+    };
+    
+    create_pda_account(payer, vault, space, token_program.key, system_program, vault_seeds)?;
+    
+    initialize_account3(CpiContext::new(
+        token_program.clone(),
+        InitializeAccount3 {
+            account: vault.clone(),
+            mint: mint.clone(),
+            authority: vault.clone(),
+        },
+    ))
+}
+
+/// Like Anchor's `init`: `create_account` fails on an address that already
+/// holds lamports, and anyone can fund a predictable PDA, so a pre-funded
+/// account is topped up to rent exemption, then allocated and assigned
+pub fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    space: usize,
+    owner: &Pubkey,
+    system_program: &AccountInfo<'info>,
+    seeds: &[&[u8]],
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let lamports = account.lamports();
+    if lamports == 0 {
+        return create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                CreateAccount { from: payer.clone(), to: account.clone() },
+                &[seeds],
+            ),
+            rent,
+            space as u64,
+            owner,
+        );
+    }
+    
+    let top_up = rent.saturating_sub(lamports);
+    if top_up > 0 {
+        transfer(
+            CpiContext::new(system_program.clone(), Transfer { from: payer.clone(), to: account.clone() }),
+            top_up,
+        )?;
+    }
+    allocate(
+        CpiContext::new_with_signer(system_program.clone(), Allocate { account_to_allocate: account.clone() }, &[seeds]),
+        space as u64,
+    )?;
+    assign(
+        CpiContext::new_with_signer(system_program.clone(), Assign { account_to_assign: account.clone() }, &[seeds]),
+        owner,
+    )
+}
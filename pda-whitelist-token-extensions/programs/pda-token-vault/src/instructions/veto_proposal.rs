@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
+
+/// Cancel a user's open proposal, approved or not, before it executes. The
+/// proposal's rent goes back to the withdrawer.
+#[derive(Accounts)]
+pub struct VetoProposal<'info> {
+    pub guardian: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED, vault_config.mint.as_ref()],
+        bump = vault_config.config_bump,
+        has_one = guardian @ VaultError::UnauthorizedAuthority,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    /// CHECK: Rent destination, which must be the proposal's withdrawer
+    #[account(mut, address = proposal.withdrawer)]
+    pub withdrawer: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        close = withdrawer,
+        seeds = [PROPOSAL_SEED, vault_config.key().as_ref(), withdrawer.key().as_ref()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
+
+pub(crate) fn handler(ctx: Context<VetoProposal>) -> Result<()> {
+    let vault_config = &mut ctx.accounts.vault_config;
+    vault_config.open_proposals = vault_config.open_proposals.checked_sub(1).ok_or(ProgramError::ArithmeticOverflow)?;
+    
+    emit!(ProposalCancelled {
+        vault_config: ctx.accounts.vault_config.key(),
+        withdrawer: ctx.accounts.withdrawer.key(),
+        amount: ctx.accounts.proposal.amount,
+        cancelled_by: ctx.accounts.guardian.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
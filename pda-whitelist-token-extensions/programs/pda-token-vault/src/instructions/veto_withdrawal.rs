@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
+
+/// Cancel a user's pending withdrawal during its cooling-off window, e.g.
+/// while investigating fraud. The ticket's rent goes back to the user.
+#[derive(Accounts)]
+pub struct VetoWithdrawal<'info> {
+    pub guardian: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED, vault_config.mint.as_ref()],
+        bump = vault_config.config_bump,
+        has_one = guardian @ VaultError::UnauthorizedAuthority,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    /// CHECK: Rent destination, which must be the ticket's user
+    #[account(mut, address = withdrawal_ticket.user)]
+    pub user: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        close = user,
+        seeds = [WITHDRAWAL_TICKET_SEED, vault_config.key().as_ref(), user.key().as_ref()],
+        bump = withdrawal_ticket.bump,
+    )]
+    pub withdrawal_ticket: Account<'info, WithdrawalTicket>,
+}
+
+pub(crate) fn handler(ctx: Context<VetoWithdrawal>) -> Result<()> {
+    let vault_config = &mut ctx.accounts.vault_config;
+    vault_config.open_tickets = vault_config.open_tickets.checked_sub(1).ok_or(ProgramError::ArithmeticOverflow)?;
+    
+    emit!(WithdrawalCancelled {
+        vault_config: ctx.accounts.vault_config.key(),
+        user: ctx.accounts.user.key(),
+        amount: ctx.accounts.withdrawal_ticket.amount,
+        cancelled_by: ctx.accounts.guardian.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
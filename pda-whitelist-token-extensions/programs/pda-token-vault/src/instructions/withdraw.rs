@@ -1,10 +1,9 @@
 use anchor_lang::prelude::*;
-use anchor_spl::{
-    token_2022::Token2022,
-    token_interface::{Mint, TokenAccount, TransferChecked, transfer_checked},
-};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked, transfer_checked};
 use crate::constants::*;
 use crate::error::VaultError;
+use crate::events::*;
+use crate::instructions::ui_amount::ui_amount;
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -12,11 +11,14 @@ pub struct Withdraw<'info> {
     #[account(mut)]
     pub withdrawer: Signer<'info>,
     
+    #[account(mint::token_program = token_program)]
     pub mint: InterfaceAccount<'info, Mint>,
     
+    /// Vaults with a withdrawal delay only pay out through claimed tickets
     #[account(
         seeds = [VAULT_CONFIG_SEED, mint.key().as_ref()],
         bump = vault_config.config_bump,
+        constraint = vault_config.withdrawal_delay == 0 @ VaultError::WithdrawalTimelocked,
     )]
     pub vault_config: Account<'info, VaultConfig>,
     
@@ -42,10 +44,11 @@ pub struct Withdraw<'info> {
     )]
     pub vault: InterfaceAccount<'info, TokenAccount>,
     
-    pub token_program: Program<'info, Token2022>,
+    /// Token-2022 or the legacy Token program, whichever owns the mint
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
-pub fn handler(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
+pub(crate) fn handler(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
     let whitelist_entry = &ctx.accounts.whitelist_entry;
     
     // Check amount limit
@@ -53,6 +56,14 @@ pub fn handler(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
         whitelist_entry.is_within_limit(amount),
         VaultError::AmountExceedsLimit
     );
+    require!(
+        !ctx.accounts.vault_config.requires_approval(amount),
+        VaultError::ApprovalRequired
+    );
+    require!(
+        ctx.accounts.vault_config.unreserved(ctx.accounts.vault.amount) >= amount,
+        VaultError::VestingReserved
+    );
     
     let mint_key = ctx.accounts.mint.key();
     let vault_bump = ctx.accounts.vault_config.vault_bump;
@@ -73,6 +84,16 @@ pub fn handler(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
         ctx.accounts.mint.decimals,
     )?;
     
-    msg!("Withdrew {} tokens for {}", amount, ctx.accounts.withdrawer.key());
+    let ui_amount = ui_amount(&ctx.accounts.token_program, &ctx.accounts.mint, amount)?;
+    msg!("Withdrew {} ({} tokens)", amount, ui_amount);
+    
+    emit!(Withdrew {
+        vault_config: ctx.accounts.vault_config.key(),
+        mint: ctx.accounts.mint.key(),
+        withdrawer: ctx.accounts.withdrawer.key(),
+        amount,
+        ui_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
     Ok(())
 }
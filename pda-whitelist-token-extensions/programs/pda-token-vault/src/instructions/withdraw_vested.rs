@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked, transfer_checked};
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
+
+/// Release everything vested so far to the beneficiary. The schedule is the
+/// authorization, so no whitelist entry or timelock applies.
+#[derive(Accounts)]
+#[instruction(schedule_id: u64)]
+pub struct WithdrawVested<'info> {
+    pub beneficiary: Signer<'info>,
+    
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED, mint.key().as_ref()],
+        bump = vault_config.config_bump,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    #[account(
+        mut,
+        seeds = [
+            VESTING_SCHEDULE_SEED,
+            vault_config.key().as_ref(),
+            beneficiary.key().as_ref(),
+            &schedule_id.to_le_bytes(),
+        ],
+        bump = vesting_schedule.bump,
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = beneficiary,
+    )]
+    pub beneficiary_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, mint.key().as_ref()],
+        bump = vault_config.vault_bump,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Token-2022 or the legacy Token program, whichever owns the mint
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub(crate) fn handler(ctx: Context<WithdrawVested>, schedule_id: u64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let amount = ctx.accounts.vesting_schedule.releasable(now);
+    require!(amount > 0, VaultError::NothingVested);
+    
+    let mint_key = ctx.accounts.mint.key();
+    let vault_bump = ctx.accounts.vault_config.vault_bump;
+    let signer_seeds = &[VAULT_SEED, mint_key.as_ref(), &[vault_bump]];
+    
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.beneficiary_token_account.to_account_info(),
+                authority: ctx.accounts.vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+            &[signer_seeds],
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+    
+    let schedule = &mut ctx.accounts.vesting_schedule;
+    schedule.released = schedule.released.checked_add(amount).ok_or(ProgramError::ArithmeticOverflow)?;
+    let vault_config = &mut ctx.accounts.vault_config;
+    vault_config.vesting_reserved = vault_config
+        .vesting_reserved
+        .checked_sub(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    
+    emit!(VestedWithdrawn {
+        vault_config: vault_config.key(),
+        beneficiary: schedule.beneficiary,
+        schedule_id,
+        amount,
+        released: schedule.released,
+        timestamp: now,
+    });
+    Ok(())
+}
@@ -2,8 +2,11 @@ use anchor_lang::prelude::*;
 
 declare_id!("6K7gP2L8j9hq1B3m4kC5n2D4f5E6a7b8c9d1e2F3G4H");
 
+#[cfg(not(target_os = "solana"))]
+pub mod client;
 pub mod constants;
 pub mod error;
+pub mod events;
 pub mod instructions;
 pub mod state;
 
@@ -12,38 +15,273 @@ use instructions::*;
 #[program]
 pub mod pda_token_vault {
     use super::*;
-
+    
     /// Create a mint with configurable token extensions
     /// Part 2: Token creation using extension args
     pub fn create_mint(ctx: Context<CreateMint>, decimals: u8, extension_args: ExtensionArgs) -> Result<()> {
         instructions::create_mint::handler(ctx, decimals, extension_args)
     }
-
+    
     /// Initialize the vault config for a mint
     pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
         instructions::initialize::handler(ctx)
     }
-
+    
     /// Add user to whitelist by creating their PDA account
     /// Part 1: PDA account per whitelisted address
     pub fn add_to_whitelist(ctx: Context<AddToWhitelist>, user: Pubkey, max_amount: u64) -> Result<()> {
         instructions::add_to_whitelist::handler(ctx, user, max_amount)
     }
-
+    
     /// Remove user from whitelist by closing their PDA account
     pub fn remove_from_whitelist(ctx: Context<RemoveFromWhitelist>, user: Pubkey) -> Result<()> {
         instructions::remove_from_whitelist::handler(ctx, user)
     }
-
+    
+    /// Change a whitelisted user's limit without recreating their PDA
+    pub fn update_whitelist_entry(ctx: Context<UpdateWhitelistEntry>, user: Pubkey, max_amount: u64) -> Result<()> {
+        instructions::update_whitelist_entry::handler(ctx, user, max_amount)
+    }
+    
     /// Deposit tokens to vault
     pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
         instructions::deposit::handler(ctx, amount)
     }
-
+    
     /// Withdraw tokens from vault (requires whitelist PDA)
     pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
         instructions::withdraw::handler(ctx, amount)
     }
+    
+    /// Freeze a holder's token account (vault config is the freeze authority)
+    pub fn freeze_account(ctx: Context<FreezeAccount>) -> Result<()> {
+        instructions::freeze_account::handler(ctx)
+    }
+    
+    /// Thaw a previously frozen token account
+    pub fn thaw_account(ctx: Context<ThawAccount>) -> Result<()> {
+        instructions::thaw_account::handler(ctx)
+    }
+    
+    /// Set a metadata field on the mint (vault config is the update authority)
+    pub fn update_token_metadata_field(
+        ctx: Context<UpdateTokenMetadataField>,
+        field: MetadataField,
+        value: String,
+    ) -> Result<()> {
+        instructions::update_token_metadata_field::handler(ctx, field, value)
+    }
+    
+    /// Remove an additional metadata key from the mint
+    pub fn remove_metadata_key(ctx: Context<RemoveMetadataKey>, key: String, idempotent: bool) -> Result<()> {
+        instructions::remove_metadata_key::handler(ctx, key, idempotent)
+    }
+    
+    /// Set the mint's annual interest rate in basis points (vault config is
+    /// the rate authority)
+    pub fn update_interest_rate(ctx: Context<UpdateInterestRate>, rate: i16) -> Result<()> {
+        instructions::update_interest_rate::handler(ctx, rate)
+    }
+    
+    /// Move tokens out of any holder's account via the permanent delegate,
+    /// recording why (reason codes are defined off-chain)
+    pub fn clawback(ctx: Context<Clawback>, amount: u64, reason_code: u16) -> Result<()> {
+        instructions::clawback::handler(ctx, amount, reason_code)
+    }
+    
+    /// Assign the role allowed to call `clawback`
+    pub fn set_clawback_authority(ctx: Context<SetClawbackAuthority>, new_clawback_authority: Pubkey) -> Result<()> {
+        instructions::set_clawback_authority::handler(ctx, new_clawback_authority)
+    }
+    
+    /// Create the soulbound credential mint issued to whitelisted users
+    pub fn create_credential_mint(ctx: Context<CreateCredentialMint>) -> Result<()> {
+        instructions::create_credential_mint::handler(ctx)
+    }
+    
+    /// Close an empty vault and its accounts, returning their rent to the
+    /// authority; also closes the mint if it allows it and has no supply
+    pub fn close_vault<'info>(ctx: Context<'_, '_, '_, 'info, CloseVault<'info>>) -> Result<()> {
+        instructions::close_vault::handler(ctx)
+    }
+    
+    /// Give the vault's token account a confidential balance under an
+    /// ElGamal key held by the authority
+    pub fn configure_confidential_vault(
+        ctx: Context<ConfigureConfidentialVault>,
+        decryptable_zero_balance: [u8; 36],
+        maximum_pending_balance_credit_counter: u64,
+        proof_instruction_offset: i8,
+    ) -> Result<()> {
+        instructions::configure_confidential_vault::handler(
+            ctx,
+            decryptable_zero_balance,
+            maximum_pending_balance_credit_counter,
+            proof_instruction_offset,
+        )
+    }
+    
+    /// Approve a whitelisted holder's account for confidential transfers
+    /// (vault config is the confidential transfer authority)
+    pub fn approve_confidential_account(ctx: Context<ApproveConfidentialAccount>) -> Result<()> {
+        instructions::approve_confidential_account::handler(ctx)
+    }
+    
+    /// Move part of the vault's public balance into its confidential balance
+    pub fn confidential_vault_deposit(ctx: Context<ConfidentialVaultDeposit>, amount: u64) -> Result<()> {
+        instructions::confidential_vault_deposit::handler(ctx, amount)
+    }
+    
+    /// Credit the vault's pending confidential balance to its available balance
+    pub fn apply_vault_pending_balance(
+        ctx: Context<ApplyVaultPendingBalance>,
+        expected_pending_balance_credit_counter: u64,
+        new_decryptable_available_balance: [u8; 36],
+    ) -> Result<()> {
+        instructions::apply_vault_pending_balance::handler(
+            ctx,
+            expected_pending_balance_credit_counter,
+            new_decryptable_available_balance,
+        )
+    }
+    
+    /// Confidentially transfer from the vault to a whitelisted holder
+    pub fn confidential_withdraw(
+        ctx: Context<ConfidentialWithdraw>,
+        new_source_decryptable_available_balance: [u8; 36],
+        proof_instruction_offset: i8,
+    ) -> Result<()> {
+        instructions::confidential_withdraw::handler(ctx, new_source_decryptable_available_balance, proof_instruction_offset)
+    }
+    
+    /// Start listing the member vaults of a group mint; later `create_mint`
+    /// calls that join the group append to it
+    pub fn create_vault_registry(ctx: Context<CreateVaultRegistry>) -> Result<()> {
+        instructions::create_vault_registry::handler(ctx)
+    }
+    
+    /// Create a vault holding several mints under one custody policy
+    pub fn create_multi_vault(ctx: Context<CreateMultiVault>, vault_id: u64) -> Result<()> {
+        instructions::create_multi_vault::handler(ctx, vault_id)
+    }
+    
+    /// Accept a Token-2022 or legacy Token mint in a multi-mint vault, with
+    /// its own per-withdrawal limit
+    pub fn add_vault_mint(ctx: Context<AddVaultMint>, max_amount: u64) -> Result<()> {
+        instructions::add_vault_mint::handler(ctx, max_amount)
+    }
+    
+    /// Change one mint's per-withdrawal limit in a multi-mint vault
+    pub fn set_vault_mint_limit(ctx: Context<SetVaultMintLimit>, max_amount: u64) -> Result<()> {
+        instructions::set_vault_mint_limit::handler(ctx, max_amount)
+    }
+    
+    /// Whitelist a user for withdrawals from a multi-mint vault
+    pub fn add_multi_vault_member(ctx: Context<AddMultiVaultMember>, user: Pubkey) -> Result<()> {
+        instructions::add_multi_vault_member::handler(ctx, user)
+    }
+    
+    /// Remove a user from a multi-mint vault's whitelist
+    pub fn remove_multi_vault_member(ctx: Context<RemoveMultiVaultMember>, user: Pubkey) -> Result<()> {
+        instructions::remove_multi_vault_member::handler(ctx, user)
+    }
+    
+    /// Deposit one of a multi-mint vault's mints
+    pub fn multi_vault_deposit(ctx: Context<MultiVaultDeposit>, amount: u64) -> Result<()> {
+        instructions::multi_vault_deposit::handler(ctx, amount)
+    }
+    
+    /// Withdraw one of a multi-mint vault's mints (requires whitelist PDA)
+    pub fn multi_vault_withdraw(ctx: Context<MultiVaultWithdraw>, amount: u64) -> Result<()> {
+        instructions::multi_vault_withdraw::handler(ctx, amount)
+    }
+    
+    /// Set the delay between requesting and claiming a withdrawal, and the
+    /// guardian who may veto requests in between; a delay of 0 re-enables
+    /// instant withdrawals
+    pub fn set_withdrawal_timelock(
+        ctx: Context<SetWithdrawalTimelock>,
+        withdrawal_delay: i64,
+        guardian: Pubkey,
+    ) -> Result<()> {
+        instructions::set_withdrawal_timelock::handler(ctx, withdrawal_delay, guardian)
+    }
+    
+    /// Open a withdrawal ticket that unlocks after the vault's delay
+    pub fn request_withdrawal(ctx: Context<RequestWithdrawal>, amount: u64) -> Result<()> {
+        instructions::request_withdrawal::handler(ctx, amount)
+    }
+    
+    /// Pay out an unlocked withdrawal ticket (requires whitelist PDA)
+    pub fn claim_withdrawal(ctx: Context<ClaimWithdrawal>) -> Result<()> {
+        instructions::claim_withdrawal::handler(ctx)
+    }
+    
+    /// Cancel the signer's pending withdrawal
+    pub fn cancel_withdrawal(ctx: Context<CancelWithdrawal>) -> Result<()> {
+        instructions::cancel_withdrawal::handler(ctx)
+    }
+    
+    /// Guardian only: cancel a user's pending withdrawal
+    pub fn veto_withdrawal(ctx: Context<VetoWithdrawal>) -> Result<()> {
+        instructions::veto_withdrawal::handler(ctx)
+    }
+    
+    /// Reserve part of the vault for a beneficiary, vesting linearly from
+    /// `start` to `end` after a cliff
+    pub fn create_vesting(
+        ctx: Context<CreateVesting>,
+        beneficiary: Pubkey,
+        schedule_id: u64,
+        total: u64,
+        start: i64,
+        cliff: i64,
+        end: i64,
+    ) -> Result<()> {
+        instructions::create_vesting::handler(ctx, beneficiary, schedule_id, total, start, cliff, end)
+    }
+    
+    /// Release the signer's vested tokens from one of their schedules
+    pub fn withdraw_vested(ctx: Context<WithdrawVested>, schedule_id: u64) -> Result<()> {
+        instructions::withdraw_vested::handler(ctx, schedule_id)
+    }
+    
+    /// Close a fully released vesting schedule, returning its rent
+    pub fn close_vesting(ctx: Context<CloseVesting>) -> Result<()> {
+        instructions::close_vesting::handler(ctx)
+    }
+    
+    /// Set the M-of-N approvers for withdrawals above `threshold`; a quorum
+    /// of 0 turns approvals off
+    pub fn set_approvers(ctx: Context<SetApprovers>, approvers: Vec<Pubkey>, quorum: u8, threshold: u64) -> Result<()> {
+        instructions::set_approvers::handler(ctx, approvers, quorum, threshold)
+    }
+    
+    /// Propose a withdrawal above the approval threshold (requires whitelist PDA)
+    pub fn propose_withdrawal(ctx: Context<ProposeWithdrawal>, amount: u64) -> Result<()> {
+        instructions::propose_withdrawal::handler(ctx, amount)
+    }
+    
+    /// Approver only: approve a proposal, executing it once the quorum is met
+    /// if it has unlocked
+    pub fn approve_withdrawal(ctx: Context<ApproveWithdrawal>) -> Result<()> {
+        instructions::approve_withdrawal::handler(ctx)
+    }
+    
+    /// Cancel the signer's open proposal
+    pub fn cancel_proposal(ctx: Context<CancelProposal>) -> Result<()> {
+        instructions::cancel_proposal::handler(ctx)
+    }
+    
+    /// Pay out the signer's approved proposal once its delay has passed
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        instructions::execute_proposal::handler(ctx)
+    }
+    
+    /// Guardian only: cancel a user's open proposal
+    pub fn veto_proposal(ctx: Context<VetoProposal>) -> Result<()> {
+        instructions::veto_proposal::handler(ctx)
+    }
 }
 
 #[cfg(test)]
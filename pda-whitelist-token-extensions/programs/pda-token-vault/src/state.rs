@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::constants::MAX_APPROVERS;
 
 /// Vault configuration - stores authority and PDA bumps
 #[account]
@@ -6,10 +7,66 @@ use anchor_lang::prelude::*;
 pub struct VaultConfig {
     pub authority: Pubkey,
     pub mint: Pubkey,
+    /// May force transfers via the permanent delegate; starts as `authority`
+    pub clawback_authority: Pubkey,
+    /// Soulbound mint held by whitelisted users; default until
+    /// `create_credential_mint`
+    pub credential_mint: Pubkey,
+    /// May veto pending withdrawal tickets; starts as `authority`
+    pub guardian: Pubkey,
+    /// Seconds between `request_withdrawal` and `claim_withdrawal`; while
+    /// non-zero, instant withdrawals are refused
+    pub withdrawal_delay: i64,
+    /// Vault tokens promised to vesting schedules and not yet released
+    pub vesting_reserved: u64,
+    /// Keys that sign off on withdrawals above `approval_threshold`
+    #[max_len(MAX_APPROVERS)]
+    pub approvers: Vec<Pubkey>,
+    /// Approvals a proposal needs; 0 disables the approval path
+    pub approval_quorum: u8,
+    /// Largest withdrawal that skips approval
+    pub approval_threshold: u64,
+    /// Withdrawal tickets not yet claimed, cancelled or vetoed
+    pub open_tickets: u32,
+    /// Vesting schedules not yet closed, released in full or not
+    pub vesting_schedules: u32,
+    /// Proposals not yet executed, cancelled or vetoed
+    pub open_proposals: u32,
     pub config_bump: u8,
     pub vault_bump: u8,
 }
 
+impl VaultConfig {
+    /// Part of the vault's `balance` not reserved for vesting, and so
+    /// available to whitelisted withdrawals and new schedules
+    pub fn unreserved(&self, balance: u64) -> u64 {
+        balance.saturating_sub(self.vesting_reserved)
+    }
+    
+    /// Whether withdrawing `amount` needs a proposal approved by the quorum
+    pub fn requires_approval(&self, amount: u64) -> bool {
+        self.approval_quorum > 0 && amount > self.approval_threshold
+    }
+    
+    /// Whether a timelock, vesting reservation or approval quorum governs
+    /// payouts; confidential withdrawals hide the amount and can't honour them
+    pub fn has_withdrawal_controls(&self) -> bool {
+        self.withdrawal_delay > 0 || self.vesting_reserved > 0 || self.approval_quorum > 0
+    }
+    
+    /// Whether accounts holding a claim on the vault are still open;
+    /// `close_vault` refuses until they are
+    pub fn has_open_claims(&self) -> bool {
+        self.open_tickets > 0 || self.vesting_schedules > 0 || self.vesting_reserved > 0 || self.open_proposals > 0
+    }
+    
+    /// Approvals from keys still in the approver set; removed approvers
+    /// stop counting
+    pub fn counted_approvals(&self, approvals: &[Pubkey]) -> usize {
+        approvals.iter().filter(|key| self.approvers.contains(key)).count()
+    }
+}
+
 /// Each whitelisted user has their own PDA account
 /// Seeds: ["whitelist_entry", vault_config, user]
 #[account]
@@ -27,3 +84,139 @@ impl WhitelistEntry {
         self.max_amount == 0 || amount <= self.max_amount
     }
 }
+
+/// A pending withdrawal, claimable once `unlock_time` has passed
+/// Seeds: ["withdrawal_ticket", vault_config, user]
+#[account]
+#[derive(InitSpace)]
+pub struct WithdrawalTicket {
+    pub vault_config: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub unlock_time: i64,
+    pub bump: u8,
+}
+
+impl WithdrawalTicket {
+    pub fn is_unlocked(&self, now: i64) -> bool {
+        now >= self.unlock_time
+    }
+}
+
+/// Tokens reserved in the vault for one beneficiary, unlocking linearly from
+/// `start` to `end` with nothing before `cliff`. `cliff == end` is a pure
+/// cliff. A beneficiary may hold several, told apart by `schedule_id`.
+/// Seeds: ["vesting_schedule", vault_config, beneficiary, schedule_id (LE)]
+#[account]
+#[derive(InitSpace)]
+pub struct VestingSchedule {
+    pub vault_config: Pubkey,
+    pub beneficiary: Pubkey,
+    pub schedule_id: u64,
+    pub start: i64,
+    pub cliff: i64,
+    pub end: i64,
+    pub total: u64,
+    pub released: u64,
+    pub bump: u8,
+}
+
+impl VestingSchedule {
+    /// Amount unlocked at `now`, released or not
+    pub fn vested_amount(&self, now: i64) -> u64 {
+        if now < self.cliff {
+            0
+        } else if now >= self.end {
+            self.total
+        } else {
+            let elapsed = (now - self.start) as u128;
+            let duration = (self.end - self.start) as u128;
+            (self.total as u128 * elapsed / duration) as u64
+        }
+    }
+    
+    /// Amount `withdraw_vested` would pay out at `now`
+    pub fn releasable(&self, now: i64) -> u64 {
+        self.vested_amount(now).saturating_sub(self.released)
+    }
+    
+    /// Whether everything has been released, so the schedule can close
+    pub fn is_fully_released(&self) -> bool {
+        self.released == self.total
+    }
+}
+
+/// A withdrawal above the vault's approval threshold, paid out once the
+/// quorum has approved and `unlock_at` has passed
+/// Seeds: ["proposal", vault_config, withdrawer]
+#[account]
+#[derive(InitSpace)]
+pub struct Proposal {
+    pub vault_config: Pubkey,
+    pub withdrawer: Pubkey,
+    /// Withdrawer's token account the payout goes to
+    pub destination: Pubkey,
+    pub amount: u64,
+    #[max_len(MAX_APPROVERS)]
+    pub approvals: Vec<Pubkey>,
+    /// Earliest execution time: proposed at + the vault's withdrawal delay,
+    /// so large withdrawals wait out the timelock as well as the quorum
+    pub unlock_at: i64,
+    pub bump: u8,
+}
+
+impl Proposal {
+    pub fn is_unlocked(&self, now: i64) -> bool {
+        now >= self.unlock_at
+    }
+}
+
+/// Member mints of a token group, so clients can enumerate every vault in
+/// the family from the group mint alone
+/// Seeds: ["vault_registry", group_mint]
+#[account]
+pub struct VaultRegistry {
+    pub group_mint: Pubkey,
+    /// Mints in the order they joined; each vault lives at the usual seeds
+    pub members: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+impl VaultRegistry {
+    pub fn space(members: usize) -> usize {
+        8 + 32 + 4 + members * 32 + 1
+    }
+}
+
+/// One custody policy over several mints, Token-2022 or legacy Token
+/// Seeds: ["multi_vault", authority, vault_id]
+#[account]
+#[derive(InitSpace)]
+pub struct MultiVault {
+    pub authority: Pubkey,
+    pub vault_id: u64,
+    pub mint_count: u32,
+    pub bump: u8,
+}
+
+/// A mint accepted by a multi-mint vault. Its tokens sit in a token account
+/// at ["vault", multi_vault, mint] that is its own authority.
+/// Seeds: ["vault_asset", multi_vault, mint]
+#[account]
+#[derive(InitSpace)]
+pub struct VaultAsset {
+    pub multi_vault: Pubkey,
+    pub mint: Pubkey,
+    pub token_program: Pubkey,
+    /// Per-withdrawal limit in this mint's base units; 0 = unlimited
+    pub max_amount: u64,
+    pub vault_bump: u8,
+    pub bump: u8,
+}
+
+impl VaultAsset {
+    /// Check if a withdrawal of this mint is within its limit
+    pub fn is_within_limit(&self, amount: u64) -> bool {
+        self.max_amount == 0 || amount <= self.max_amount
+    }
+}
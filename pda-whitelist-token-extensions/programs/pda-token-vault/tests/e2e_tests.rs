@@ -0,0 +1,2062 @@
+//! End-to-end tests that run the compiled program in LiteSVM against the
+//! bundled Token-2022 program.
+//!
+//! Build the program first (`anchor build` or `cargo build-sbf`); the tests
+//! look for `pda_token_vault.so` in `$SBF_OUT_DIR` or `target/deploy`. They
+//! are `#[ignore]`d; run them with `cargo test -- --ignored`, and they fail
+//! if the program isn't built.
+
+use std::path::PathBuf;
+use anchor_lang::{error::ErrorCode, AnchorDeserialize, Discriminator};
+use anchor_lang::solana_program::{instruction::{AccountMeta, Instruction}, pubkey::Pubkey};
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    error::TokenError,
+    extension::{transfer_fee::TransferFeeAmount, BaseStateWithExtensions, StateWithExtensions},
+    state::{Account as TokenAccount, Mint},
+};
+use base64::{prelude::BASE64_STANDARD, Engine};
+use litesvm::LiteSVM;
+use spl_token_metadata_interface::state::TokenMetadata;
+use pda_token_vault::{
+    client::{ix, pda::*},
+    error::VaultError,
+    instructions::{ExtensionArgs, MetadataField, TokenMetadataArgs},
+};
+use solana_sdk::{
+    instruction::InstructionError,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+
+const DECIMALS: u8 = 6;
+const FEE_BASIS_POINTS: u16 = 100;
+const ATA_PROGRAM: &str = "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+
+// LiteSVM speaks solana 2.0 types, the program crate 1.18; convert via bytes
+
+fn to_sdk(key: &Pubkey) -> solana_sdk::pubkey::Pubkey {
+    solana_sdk::pubkey::Pubkey::new_from_array(key.to_bytes())
+}
+
+fn from_sdk(key: &solana_sdk::pubkey::Pubkey) -> Pubkey {
+    Pubkey::new_from_array(key.to_bytes())
+}
+
+fn to_sdk_ix(ix: Instruction) -> solana_sdk::instruction::Instruction {
+    solana_sdk::instruction::Instruction {
+        program_id: to_sdk(&ix.program_id),
+        accounts: ix
+            .accounts
+            .iter()
+            .map(|meta| solana_sdk::instruction::AccountMeta {
+                pubkey: to_sdk(&meta.pubkey),
+                is_signer: meta.is_signer,
+                is_writable: meta.is_writable,
+            })
+            .collect(),
+        data: ix.data,
+    }
+}
+
+fn program_so() -> PathBuf {
+    let dir = std::env::var_os("SBF_OUT_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../target/deploy"));
+    let path = dir.join("pda_token_vault.so");
+    assert!(path.exists(), "{} not found; run `cargo build-sbf` first", path.display());
+    path
+}
+
+fn associated_token_address(wallet: &Pubkey, mint: &Pubkey) -> Pubkey {
+    let ata_program: Pubkey = ATA_PROGRAM.parse().unwrap();
+    Pubkey::find_program_address(&[wallet.as_ref(), spl_token_2022::ID.as_ref(), mint.as_ref()], &ata_program).0
+}
+
+struct Env {
+    svm: LiteSVM,
+    authority: Keypair,
+    mint: Pubkey,
+    /// Log lines of the last transaction `send` ran, successful or not
+    logs: Vec<String>,
+}
+
+impl Env {
+    /// Transfer-fee mint created by the program, with its vault initialized
+    fn new() -> Self {
+        Self::with_extensions(ExtensionArgs {
+            enable_transfer_fee: true,
+            transfer_fee_basis_points: FEE_BASIS_POINTS,
+            max_fee: u64::MAX,
+            enable_permanent_delegate: false,
+            enable_default_frozen: false,
+            enable_interest_bearing: false,
+            interest_rate: 0,
+            enable_mint_close_authority: false,
+            confidential_transfer: None,
+            group_max_size: None,
+            metadata: None,
+        })
+    }
+    
+    /// Transfer-fee mint that also makes the vault config its permanent delegate
+    fn with_clawback() -> Self {
+        Self::with_extensions(ExtensionArgs {
+            enable_transfer_fee: true,
+            transfer_fee_basis_points: FEE_BASIS_POINTS,
+            max_fee: u64::MAX,
+            enable_permanent_delegate: true,
+            enable_default_frozen: false,
+            enable_interest_bearing: false,
+            interest_rate: 0,
+            enable_mint_close_authority: false,
+            confidential_transfer: None,
+            group_max_size: None,
+            metadata: None,
+        })
+    }
+    
+    fn with_extensions(extension_args: ExtensionArgs) -> Self {
+        let mut svm = LiteSVM::new();
+        svm.add_program_from_file(to_sdk(&pda_token_vault::ID), program_so()).unwrap();
+        let authority = Keypair::new();
+        svm.airdrop(&authority.pubkey(), 100_000_000_000).unwrap();
+    
+        let mint = Keypair::new();
+        let mut env = Self { svm, authority, mint: from_sdk(&mint.pubkey()), logs: Vec::new() };
+        let authority = env.authority();
+        env.send(
+            vec![
+                ix::create_mint(&authority, &env.mint, DECIMALS, extension_args),
+                ix::initialize(&authority, &env.mint, &spl_token_2022::ID),
+            ],
+            &[&mint],
+        )
+        .unwrap();
+        env
+    }
+    
+    fn authority(&self) -> Pubkey {
+        from_sdk(&self.authority.pubkey())
+    }
+    
+    /// Sends with the authority as fee payer, then expires the blockhash so
+    /// identical follow-up transactions get distinct signatures
+    fn send(&mut self, ixs: Vec<Instruction>, signers: &[&Keypair]) -> Result<(), TransactionError> {
+        let ixs: Vec<_> = ixs.into_iter().map(to_sdk_ix).collect();
+        let mut all_signers = vec![&self.authority];
+        all_signers.extend_from_slice(signers);
+        let tx = Transaction::new_signed_with_payer(
+            &ixs,
+            Some(&self.authority.pubkey()),
+            &all_signers,
+            self.svm.latest_blockhash(),
+        );
+        let result = match self.svm.send_transaction(tx) {
+            Ok(meta) => {
+                self.logs = meta.logs;
+                Ok(())
+            }
+            Err(failed) => {
+                self.logs = failed.meta.logs;
+                Err(failed.err)
+            }
+        };
+        self.svm.expire_blockhash();
+        result
+    }
+    
+    /// Events of type `E` decoded from the `Program data:` lines of the
+    /// last transaction, in emission order
+    fn events<E: AnchorDeserialize + Discriminator>(&self) -> Vec<E> {
+        self.logs
+            .iter()
+            .filter_map(|line| line.strip_prefix("Program data: "))
+            .filter_map(|data| BASE64_STANDARD.decode(data).ok())
+            .filter(|data| data.starts_with(&E::DISCRIMINATOR))
+            .map(|data| E::try_from_slice(&data[8..]).unwrap())
+            .collect()
+    }
+    
+    /// New funded wallet with an associated token account holding `amount`;
+    /// with `amount == 0` nothing is minted, so frozen accounts can be made
+    fn user(&mut self, amount: u64) -> (Keypair, Pubkey) {
+        let user = Keypair::new();
+        self.svm.airdrop(&user.pubkey(), 1_000_000_000).unwrap();
+        let mint = self.mint;
+        let ata = self.associated_token_account(&from_sdk(&user.pubkey()), &mint);
+        if amount > 0 {
+            self.mint_to(&ata, amount);
+        }
+        (user, ata)
+    }
+    
+    /// Creates `wallet`'s associated token account for `mint`, paid by the authority
+    fn associated_token_account(&mut self, wallet: &Pubkey, mint: &Pubkey) -> Pubkey {
+        let authority = self.authority();
+        let ata = associated_token_address(wallet, mint);
+        let create = Instruction {
+            program_id: ATA_PROGRAM.parse().unwrap(),
+            accounts: vec![
+                AccountMeta::new(authority, true),
+                AccountMeta::new(ata, false),
+                AccountMeta::new_readonly(*wallet, false),
+                AccountMeta::new_readonly(*mint, false),
+                AccountMeta::new_readonly(anchor_lang::system_program::ID, false),
+                AccountMeta::new_readonly(spl_token_2022::ID, false),
+            ],
+            // CreateIdempotent
+            data: vec![1],
+        };
+        self.send(vec![create], &[]).unwrap();
+        ata
+    }
+    
+    fn mint_to(&mut self, token_account: &Pubkey, amount: u64) {
+        let authority = self.authority();
+        let mint_to =
+            spl_token_2022::instruction::mint_to(&spl_token_2022::ID, &self.mint, token_account, &authority, &[], amount)
+                .unwrap();
+        self.send(vec![mint_to], &[]).unwrap();
+    }
+    
+    fn whitelist(&mut self, user: &Keypair, max_amount: u64) {
+        let authority = self.authority();
+        let add = ix::add_to_whitelist(
+            &authority,
+            &self.mint,
+            &from_sdk(&user.pubkey()),
+            max_amount,
+            None,
+            None,
+            &spl_token_2022::ID,
+        );
+        self.send(vec![add], &[]).unwrap();
+    }
+    
+    fn deposit(&mut self, user: &Keypair, token_account: &Pubkey, amount: u64) -> Result<(), TransactionError> {
+        let deposit = ix::deposit(&from_sdk(&user.pubkey()), &self.mint, token_account, amount, &spl_token_2022::ID);
+        self.send(vec![deposit], &[user])
+    }
+    
+    fn withdraw(&mut self, user: &Keypair, token_account: &Pubkey, amount: u64) -> Result<(), TransactionError> {
+        let withdraw = ix::withdraw(&from_sdk(&user.pubkey()), &self.mint, token_account, amount, &spl_token_2022::ID);
+        self.send(vec![withdraw], &[user])
+    }
+    
+    fn is_frozen(&self, token_account: &Pubkey) -> bool {
+        let account = self.svm.get_account(&to_sdk(token_account)).unwrap();
+        StateWithExtensions::<TokenAccount>::unpack(&account.data).unwrap().base.is_frozen()
+    }
+    
+    /// The mint's embedded metadata, and whether the mint is still rent exempt
+    fn token_metadata(&self) -> (TokenMetadata, bool) {
+        let account = self.svm.get_account(&to_sdk(&self.mint)).unwrap();
+        let mint = StateWithExtensions::<Mint>::unpack(&account.data).unwrap();
+        let rent_exempt = account.lamports >= self.svm.minimum_balance_for_rent_exemption(account.data.len());
+        (mint.get_variable_len_extension::<TokenMetadata>().unwrap(), rent_exempt)
+    }
+    
+    /// Balance and fees withheld on the account
+    fn balance(&self, token_account: &Pubkey) -> (u64, u64) {
+        let account = self.svm.get_account(&to_sdk(token_account)).unwrap();
+        let state = StateWithExtensions::<TokenAccount>::unpack(&account.data).unwrap();
+        let withheld = state.get_extension::<TransferFeeAmount>().unwrap().withheld_amount;
+        (state.base.amount, withheld.into())
+    }
+}
+
+fn assert_custom(result: Result<(), TransactionError>, code: impl Into<u32>) {
+    match result {
+        Err(TransactionError::InstructionError(_, err)) => assert_eq!(err, InstructionError::Custom(code.into())),
+        other => panic!("expected custom error, got {other:?}"),
+    }
+}
+
+#[cfg(test)]
+mod test_transfer_fee {
+    use super::*;
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn deposit_withholds_fee_in_vault() {
+        let mut env = Env::new();
+        let (alice, alice_ata) = env.user(20_000);
+        let vault = derive_vault(&env.mint).0;
+    
+        env.deposit(&alice, &alice_ata, 10_000).unwrap();
+        assert_eq!(env.balance(&alice_ata), (10_000, 0));
+        assert_eq!(env.balance(&vault), (9_900, 100));
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn withdraw_deducts_fee_from_recipient() {
+        let mut env = Env::new();
+        let (alice, alice_ata) = env.user(10_000);
+        let vault = derive_vault(&env.mint).0;
+        env.whitelist(&alice, 0);
+        env.deposit(&alice, &alice_ata, 10_000).unwrap();
+    
+        env.withdraw(&alice, &alice_ata, 5_000).unwrap();
+        assert_eq!(env.balance(&vault), (4_900, 100));
+        assert_eq!(env.balance(&alice_ata), (4_950, 50));
+    }
+}
+
+#[cfg(test)]
+mod test_initialize {
+    use super::*;
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn prefunded_vault_address_does_not_block_initialize() {
+        let mut env = Env::new();
+        let authority = env.authority();
+        let mint = Keypair::new();
+        let mint_key = from_sdk(&mint.pubkey());
+        let args = ExtensionArgs {
+            enable_transfer_fee: false,
+            transfer_fee_basis_points: 0,
+            max_fee: 0,
+            enable_permanent_delegate: false,
+            enable_default_frozen: false,
+            enable_interest_bearing: false,
+            interest_rate: 0,
+            enable_mint_close_authority: false,
+            confidential_transfer: None,
+            group_max_size: None,
+            metadata: None,
+        };
+        env.send(vec![ix::create_mint(&authority, &mint_key, DECIMALS, args)], &[&mint]).unwrap();
+    
+        let vault = derive_vault(&mint_key).0;
+        env.svm.airdrop(&to_sdk(&vault), 1).unwrap();
+        env.send(vec![ix::initialize(&authority, &mint_key, &spl_token_2022::ID)], &[]).unwrap();
+    
+        let account = env.svm.get_account(&to_sdk(&vault)).unwrap();
+        assert_eq!(from_sdk(&account.owner), spl_token_2022::ID);
+        assert!(account.lamports >= env.svm.minimum_balance_for_rent_exemption(account.data.len()));
+        assert_eq!(StateWithExtensions::<TokenAccount>::unpack(&account.data).unwrap().base.owner, vault);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn front_runner_cannot_become_clawback_authority_or_guardian() {
+        let mut env = Env::new();
+        let authority = env.authority();
+        let mint = Keypair::new();
+        let mint_key = from_sdk(&mint.pubkey());
+        let args = ExtensionArgs { enable_permanent_delegate: true, ..ExtensionArgs::default() };
+        env.send(vec![ix::create_mint(&authority, &mint_key, DECIMALS, args)], &[&mint]).unwrap();
+        let mallory = Keypair::new();
+        env.svm.airdrop(&mallory.pubkey(), 1_000_000_000).unwrap();
+    
+        let front_run = ix::initialize(&from_sdk(&mallory.pubkey()), &mint_key, &spl_token_2022::ID);
+        assert_custom(env.send(vec![front_run], &[&mallory]), VaultError::NotMintAuthority);
+        env.send(vec![ix::initialize(&authority, &mint_key, &spl_token_2022::ID)], &[]).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod test_whitelist {
+    use super::*;
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn non_whitelisted_withdraw_is_rejected() {
+        let mut env = Env::new();
+        let (alice, alice_ata) = env.user(10_000);
+        let (mallory, mallory_ata) = env.user(0);
+        env.deposit(&alice, &alice_ata, 10_000).unwrap();
+    
+        // No entry PDA exists for mallory
+        assert_custom(env.withdraw(&mallory, &mallory_ata, 1_000), ErrorCode::AccountNotInitialized);
+        assert_eq!(env.balance(&mallory_ata).0, 0);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn withdraw_over_limit_is_rejected() {
+        let mut env = Env::new();
+        let (alice, alice_ata) = env.user(10_000);
+        env.whitelist(&alice, 1_000);
+        env.deposit(&alice, &alice_ata, 10_000).unwrap();
+    
+        assert_custom(env.withdraw(&alice, &alice_ata, 1_001), VaultError::AmountExceedsLimit);
+        env.withdraw(&alice, &alice_ata, 1_000).unwrap();
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn removal_revokes_withdrawals() {
+        let mut env = Env::new();
+        let (alice, alice_ata) = env.user(10_000);
+        let authority = env.authority();
+        env.whitelist(&alice, 0);
+        env.deposit(&alice, &alice_ata, 10_000).unwrap();
+    
+        let alice_key = from_sdk(&alice.pubkey());
+        let remove = ix::remove_from_whitelist(&authority, &env.mint, &alice_key, None, None, &spl_token_2022::ID);
+        env.send(vec![remove], &[]).unwrap();
+        assert_custom(env.withdraw(&alice, &alice_ata, 1_000), ErrorCode::AccountNotInitialized);
+    }
+}
+
+#[cfg(test)]
+mod test_freeze {
+    use super::*;
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn vault_config_is_the_freeze_authority() {
+        let env = Env::new();
+        let account = env.svm.get_account(&to_sdk(&env.mint)).unwrap();
+        let mint = StateWithExtensions::<Mint>::unpack(&account.data).unwrap();
+    
+        assert_eq!(Option::<Pubkey>::from(mint.base.freeze_authority), Some(derive_vault_config(&env.mint).0));
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn frozen_account_cannot_deposit_until_thawed() {
+        let mut env = Env::new();
+        let (alice, alice_ata) = env.user(10_000);
+        let authority = env.authority();
+    
+        env.send(vec![ix::freeze_account(&authority, &env.mint, &alice_ata, &spl_token_2022::ID)], &[]).unwrap();
+        assert_custom(env.deposit(&alice, &alice_ata, 1_000), TokenError::AccountFrozen as u32);
+    
+        env.send(vec![ix::thaw_account(&authority, &env.mint, &alice_ata, &spl_token_2022::ID)], &[]).unwrap();
+        env.deposit(&alice, &alice_ata, 1_000).unwrap();
+        assert_eq!(env.balance(&alice_ata).0, 9_000);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn only_the_authority_can_freeze() {
+        let mut env = Env::new();
+        let (mallory, mallory_ata) = env.user(0);
+        let freeze = ix::freeze_account(&from_sdk(&mallory.pubkey()), &env.mint, &mallory_ata, &spl_token_2022::ID);
+    
+        assert_custom(env.send(vec![freeze], &[&mallory]), VaultError::UnauthorizedAuthority);
+    }
+}
+
+#[cfg(test)]
+mod test_clawback {
+    use super::*;
+    
+    const REASON_COURT_ORDER: u16 = 1;
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn clawback_moves_tokens_into_the_vault() {
+        let mut env = Env::with_clawback();
+        let (_, alice_ata) = env.user(10_000);
+        let authority = env.authority();
+        let vault = derive_vault(&env.mint).0;
+    
+        env.send(vec![ix::clawback(&authority, &env.mint, &alice_ata, None, 10_000, REASON_COURT_ORDER)], &[])
+            .unwrap();
+        assert_eq!(env.balance(&alice_ata), (0, 0));
+        assert_eq!(env.balance(&vault), (9_900, 100));
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn clawback_to_recovery_account() {
+        let mut env = Env::with_clawback();
+        let (_, alice_ata) = env.user(10_000);
+        let (_, recovery_ata) = env.user(0);
+        let authority = env.authority();
+    
+        let clawback = ix::clawback(&authority, &env.mint, &alice_ata, Some(&recovery_ata), 5_000, REASON_COURT_ORDER);
+        env.send(vec![clawback], &[]).unwrap();
+        assert_eq!(env.balance(&alice_ata).0, 5_000);
+        assert_eq!(env.balance(&recovery_ata), (4_950, 50));
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn frozen_holder_stays_frozen() {
+        let mut env = Env::with_clawback();
+        let (alice, alice_ata) = env.user(10_000);
+        let authority = env.authority();
+        env.send(vec![ix::freeze_account(&authority, &env.mint, &alice_ata, &spl_token_2022::ID)], &[]).unwrap();
+    
+        env.send(vec![ix::clawback(&authority, &env.mint, &alice_ata, None, 4_000, REASON_COURT_ORDER)], &[])
+            .unwrap();
+        assert_eq!(env.balance(&alice_ata).0, 6_000);
+        assert_custom(env.deposit(&alice, &alice_ata, 1), TokenError::AccountFrozen as u32);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn only_the_clawback_authority_can_claw_back() {
+        let mut env = Env::with_clawback();
+        let (_, alice_ata) = env.user(10_000);
+        let (officer, _) = env.user(0);
+        let authority = env.authority();
+        let officer_key = from_sdk(&officer.pubkey());
+        let by_officer = ix::clawback(&officer_key, &env.mint, &alice_ata, None, 1_000, REASON_COURT_ORDER);
+    
+        assert_custom(env.send(vec![by_officer.clone()], &[&officer]), VaultError::UnauthorizedAuthority);
+    
+        env.send(vec![ix::set_clawback_authority(&authority, &env.mint, &officer_key)], &[]).unwrap();
+        env.send(vec![by_officer], &[&officer]).unwrap();
+        let by_authority = ix::clawback(&authority, &env.mint, &alice_ata, None, 1_000, REASON_COURT_ORDER);
+        assert_custom(env.send(vec![by_authority], &[]), VaultError::UnauthorizedAuthority);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn reason_code_is_required() {
+        let mut env = Env::with_clawback();
+        let (_, alice_ata) = env.user(10_000);
+        let authority = env.authority();
+    
+        let clawback = ix::clawback(&authority, &env.mint, &alice_ata, None, 1_000, 0);
+        assert_custom(env.send(vec![clawback], &[]), VaultError::MissingReasonCode);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn mint_without_permanent_delegate_is_rejected() {
+        let mut env = Env::new();
+        let (_, alice_ata) = env.user(10_000);
+        let authority = env.authority();
+    
+        let clawback = ix::clawback(&authority, &env.mint, &alice_ata, None, 1_000, REASON_COURT_ORDER);
+        assert_custom(env.send(vec![clawback], &[]), VaultError::PermanentDelegateNotVault);
+    }
+}
+
+#[cfg(test)]
+mod test_default_frozen {
+    use super::*;
+    
+    fn frozen_env() -> Env {
+        Env::with_extensions(ExtensionArgs {
+            enable_transfer_fee: true,
+            transfer_fee_basis_points: FEE_BASIS_POINTS,
+            max_fee: u64::MAX,
+            enable_permanent_delegate: false,
+            enable_default_frozen: true,
+            enable_interest_bearing: false,
+            interest_rate: 0,
+            enable_mint_close_authority: false,
+            confidential_transfer: None,
+            group_max_size: None,
+            metadata: None,
+        })
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn whitelisting_thaws_and_removal_refreezes() {
+        let mut env = frozen_env();
+        let (alice, alice_ata) = env.user(0);
+        let authority = env.authority();
+        let user = from_sdk(&alice.pubkey());
+        assert!(env.is_frozen(&alice_ata));
+        assert!(!env.is_frozen(&derive_vault(&env.mint).0));
+    
+        let add = ix::add_to_whitelist(&authority, &env.mint, &user, 0, Some(&alice_ata), None, &spl_token_2022::ID);
+        env.send(vec![add], &[]).unwrap();
+        assert!(!env.is_frozen(&alice_ata));
+        env.mint_to(&alice_ata, 10_000);
+        env.deposit(&alice, &alice_ata, 10_000).unwrap();
+        env.withdraw(&alice, &alice_ata, 5_000).unwrap();
+    
+        let remove = ix::remove_from_whitelist(&authority, &env.mint, &user, Some(&alice_ata), None, &spl_token_2022::ID);
+        env.send(vec![remove], &[]).unwrap();
+        assert!(env.is_frozen(&alice_ata));
+        assert_custom(env.deposit(&alice, &alice_ata, 1), TokenError::AccountFrozen as u32);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn holder_account_must_belong_to_the_user() {
+        let mut env = frozen_env();
+        let (alice, _) = env.user(0);
+        let (_, mallory_ata) = env.user(0);
+        let authority = env.authority();
+    
+        let add = ix::add_to_whitelist(
+            &authority,
+            &env.mint,
+            &from_sdk(&alice.pubkey()),
+            0,
+            Some(&mallory_ata),
+            None,
+            &spl_token_2022::ID,
+        );
+        assert_custom(env.send(vec![add], &[]), VaultError::HolderMismatch);
+        assert!(env.is_frozen(&mallory_ata));
+    }
+}
+
+#[cfg(test)]
+mod test_metadata {
+    use super::*;
+    
+    fn metadata_env() -> Env {
+        Env::with_extensions(ExtensionArgs {
+            enable_transfer_fee: true,
+            transfer_fee_basis_points: FEE_BASIS_POINTS,
+            max_fee: u64::MAX,
+            enable_permanent_delegate: true,
+            enable_default_frozen: false,
+            enable_interest_bearing: false,
+            interest_rate: 0,
+            enable_mint_close_authority: false,
+            confidential_transfer: None,
+            group_max_size: None,
+            metadata: Some(TokenMetadataArgs {
+                name: "Vault".to_string(),
+                symbol: "VLT".to_string(),
+                uri: "https://example.com/vlt.json".to_string(),
+            }),
+        })
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn create_mint_embeds_metadata_alongside_other_extensions() {
+        let env = metadata_env();
+        let (metadata, rent_exempt) = env.token_metadata();
+    
+        assert_eq!((metadata.name.as_str(), metadata.symbol.as_str()), ("Vault", "VLT"));
+        assert_eq!(metadata.mint, env.mint);
+        assert_eq!(Option::<Pubkey>::from(metadata.update_authority), Some(derive_vault_config(&env.mint).0));
+        assert!(rent_exempt);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn updates_grow_the_mint_and_keep_it_rent_exempt() {
+        let mut env = metadata_env();
+        let authority = env.authority();
+        let name = ix::update_token_metadata_field(&authority, &env.mint, MetadataField::Name, "Vault Token Series A".into());
+        let key = ix::update_token_metadata_field(
+            &authority,
+            &env.mint,
+            MetadataField::Key("jurisdiction".into()),
+            "EU".into(),
+        );
+        env.send(vec![name, key], &[]).unwrap();
+    
+        let (metadata, rent_exempt) = env.token_metadata();
+        assert_eq!(metadata.name, "Vault Token Series A");
+        assert_eq!(metadata.additional_metadata, vec![("jurisdiction".to_string(), "EU".to_string())]);
+        assert!(rent_exempt);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn remove_metadata_key_is_optionally_idempotent() {
+        let mut env = metadata_env();
+        let authority = env.authority();
+        let set = ix::update_token_metadata_field(&authority, &env.mint, MetadataField::Key("region".into()), "EU".into());
+        env.send(vec![set, ix::remove_metadata_key(&authority, &env.mint, "region".into(), false)], &[]).unwrap();
+        assert!(env.token_metadata().0.additional_metadata.is_empty());
+    
+        assert!(env.send(vec![ix::remove_metadata_key(&authority, &env.mint, "region".into(), false)], &[]).is_err());
+        env.send(vec![ix::remove_metadata_key(&authority, &env.mint, "region".into(), true)], &[]).unwrap();
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn only_the_authority_can_update_metadata() {
+        let mut env = metadata_env();
+        let (mallory, _) = env.user(0);
+        let update = ix::update_token_metadata_field(&from_sdk(&mallory.pubkey()), &env.mint, MetadataField::Name, "x".into());
+    
+        assert_custom(env.send(vec![update], &[&mallory]), VaultError::UnauthorizedAuthority);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn update_without_metadata_is_rejected() {
+        let mut env = Env::new();
+        let authority = env.authority();
+        let update = ix::update_token_metadata_field(&authority, &env.mint, MetadataField::Name, "x".into());
+    
+        assert_custom(env.send(vec![update], &[]), VaultError::MetadataNotInitialized);
+    }
+}
+
+#[cfg(test)]
+mod test_interest_bearing {
+    use super::*;
+    use pda_token_vault::client::{decode_interest_rate, decode_ui_amount};
+    use solana_sdk::clock::Clock;
+    
+    const RATE: i16 = 500;
+    
+    fn interest_env() -> Env {
+        Env::with_extensions(ExtensionArgs {
+            enable_transfer_fee: true,
+            transfer_fee_basis_points: FEE_BASIS_POINTS,
+            max_fee: u64::MAX,
+            enable_permanent_delegate: false,
+            enable_default_frozen: false,
+            enable_interest_bearing: true,
+            interest_rate: RATE,
+            enable_mint_close_authority: false,
+            confidential_transfer: None,
+            group_max_size: None,
+            metadata: None,
+        })
+    }
+    
+    fn mint_data(env: &Env) -> Vec<u8> {
+        env.svm.get_account(&to_sdk(&env.mint)).unwrap().data
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn ui_amount_accrues_interest_across_deposit_and_withdraw() {
+        let mut env = interest_env();
+        let (alice, alice_ata) = env.user(10_000_000);
+        env.whitelist(&alice, 0);
+        env.deposit(&alice, &alice_ata, 10_000_000).unwrap();
+    
+        let mut clock: Clock = env.svm.get_sysvar();
+        clock.unix_timestamp += 365 * 24 * 60 * 60;
+        env.svm.set_sysvar(&clock);
+        let ui: f64 = decode_ui_amount(&mint_data(&env), 1_000_000, clock.unix_timestamp).unwrap().parse().unwrap();
+        assert!(ui > 1.05 && ui < 1.06, "5% compounded continuously for a year, got {ui}");
+    
+        env.withdraw(&alice, &alice_ata, 1_000_000).unwrap();
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn authority_updates_the_rate() {
+        let mut env = interest_env();
+        let authority = env.authority();
+        assert_eq!(decode_interest_rate(&mint_data(&env)).unwrap(), Some(RATE));
+    
+        env.send(vec![ix::update_interest_rate(&authority, &env.mint, -100)], &[]).unwrap();
+        assert_eq!(decode_interest_rate(&mint_data(&env)).unwrap(), Some(-100));
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn only_the_authority_can_update_the_rate() {
+        let mut env = interest_env();
+        let (mallory, _) = env.user(0);
+        let update = ix::update_interest_rate(&from_sdk(&mallory.pubkey()), &env.mint, 10_000);
+    
+        assert_custom(env.send(vec![update], &[&mallory]), VaultError::UnauthorizedAuthority);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn plain_mint_has_no_rate_to_update() {
+        let mut env = Env::new();
+        let authority = env.authority();
+    
+        assert_eq!(decode_interest_rate(&mint_data(&env)).unwrap(), None);
+        assert_custom(
+            env.send(vec![ix::update_interest_rate(&authority, &env.mint, 100)], &[]),
+            VaultError::InterestBearingNotEnabled,
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_credential {
+    use super::*;
+    use anchor_lang::solana_program::system_instruction;
+    use spl_token_2022::extension::ExtensionType;
+    
+    /// Transfer-fee vault that issues credentials, and the credential mint
+    fn credential_env() -> (Env, Pubkey) {
+        let mut env = Env::new();
+        let authority = env.authority();
+        env.send(vec![ix::create_credential_mint(&authority, &env.mint)], &[]).unwrap();
+        let credential_mint = derive_credential_mint(&derive_vault_config(&env.mint).0).0;
+        (env, credential_mint)
+    }
+    
+    fn credential_balance(env: &Env, account: &Pubkey) -> u64 {
+        let account = env.svm.get_account(&to_sdk(account)).unwrap();
+        StateWithExtensions::<TokenAccount>::unpack(&account.data).unwrap().base.amount
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn whitelisting_issues_and_removal_burns() {
+        let (mut env, credential_mint) = credential_env();
+        let (alice, _) = env.user(0);
+        let user = from_sdk(&alice.pubkey());
+        let authority = env.authority();
+        let credential = env.associated_token_account(&user, &credential_mint);
+    
+        let add = ix::add_to_whitelist(&authority, &env.mint, &user, 0, None, Some(&credential), &spl_token_2022::ID);
+        env.send(vec![add], &[]).unwrap();
+        assert_eq!(credential_balance(&env, &credential), 1);
+    
+        let remove = ix::remove_from_whitelist(&authority, &env.mint, &user, None, Some(&credential), &spl_token_2022::ID);
+        env.send(vec![remove], &[]).unwrap();
+        assert_eq!(credential_balance(&env, &credential), 0);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn updating_the_limit_keeps_the_credential() {
+        let (mut env, credential_mint) = credential_env();
+        let (alice, _) = env.user(0);
+        let user = from_sdk(&alice.pubkey());
+        let authority = env.authority();
+        let credential = env.associated_token_account(&user, &credential_mint);
+        let add = ix::add_to_whitelist(&authority, &env.mint, &user, 10, None, Some(&credential), &spl_token_2022::ID);
+        env.send(vec![add], &[]).unwrap();
+    
+        env.send(vec![ix::update_whitelist_entry(&authority, &env.mint, &user, 20)], &[]).unwrap();
+        assert_eq!(pda_token_vault::client::fetch_whitelist_entry(&env, &env.mint, &user).unwrap().unwrap().max_amount, 20);
+        assert_eq!(credential_balance(&env, &credential), 1);
+        assert_custom(
+            env.send(vec![ix::update_whitelist_entry(&authority, &env.mint, &Pubkey::new_unique(), 20)], &[]),
+            ErrorCode::AccountNotInitialized,
+        );
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn credential_cannot_be_transferred() {
+        let (mut env, credential_mint) = credential_env();
+        let (alice, _) = env.user(0);
+        let (bob, _) = env.user(0);
+        let authority = env.authority();
+        let alice_credential = env.associated_token_account(&from_sdk(&alice.pubkey()), &credential_mint);
+        let bob_credential = env.associated_token_account(&from_sdk(&bob.pubkey()), &credential_mint);
+        let add = ix::add_to_whitelist(
+            &authority,
+            &env.mint,
+            &from_sdk(&alice.pubkey()),
+            0,
+            None,
+            Some(&alice_credential),
+            &spl_token_2022::ID,
+        );
+        env.send(vec![add], &[]).unwrap();
+    
+        let transfer = spl_token_2022::instruction::transfer_checked(
+            &spl_token_2022::ID,
+            &alice_credential,
+            &credential_mint,
+            &bob_credential,
+            &from_sdk(&alice.pubkey()),
+            &[],
+            1,
+            0,
+        )
+        .unwrap();
+        assert_eq!(
+            env.send(vec![transfer], &[&alice]).unwrap_err(),
+            TransactionError::InstructionError(0, InstructionError::Custom(TokenError::NonTransferable as u32))
+        );
+        assert_eq!(credential_balance(&env, &bob_credential), 0);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn credential_accounts_are_required_once_enabled() {
+        let (mut env, credential_mint) = credential_env();
+        let (alice, _) = env.user(0);
+        let (mallory, _) = env.user(0);
+        let user = from_sdk(&alice.pubkey());
+        let authority = env.authority();
+        let mallory_credential = env.associated_token_account(&from_sdk(&mallory.pubkey()), &credential_mint);
+    
+        assert_custom(
+            env.send(vec![ix::add_to_whitelist(&authority, &env.mint, &user, 0, None, None, &spl_token_2022::ID)], &[]),
+            VaultError::MissingCredentialAccounts,
+        );
+        let add = ix::add_to_whitelist(&authority, &env.mint, &user, 0, None, Some(&mallory_credential), &spl_token_2022::ID);
+        assert_custom(env.send(vec![add], &[]), VaultError::HolderMismatch);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn credential_must_be_the_associated_account() {
+        let (mut env, credential_mint) = credential_env();
+        let (alice, _) = env.user(0);
+        let user = from_sdk(&alice.pubkey());
+        let authority = env.authority();
+        let credential = env.associated_token_account(&user, &credential_mint);
+        let decoy = Keypair::new();
+        let decoy_key = from_sdk(&decoy.pubkey());
+        let space = {
+            let account = env.svm.get_account(&to_sdk(&credential_mint)).unwrap();
+            let mint = StateWithExtensions::<Mint>::unpack(&account.data).unwrap();
+            let extensions = ExtensionType::get_required_init_account_extensions(&mint.get_extension_types().unwrap());
+            ExtensionType::try_calculate_account_len::<TokenAccount>(&extensions).unwrap()
+        };
+        let lamports = env.svm.minimum_balance_for_rent_exemption(space);
+        env.send(
+            vec![
+                system_instruction::create_account(&authority, &decoy_key, lamports, space as u64, &spl_token_2022::ID),
+                spl_token_2022::instruction::initialize_account3(&spl_token_2022::ID, &decoy_key, &credential_mint, &user)
+                    .unwrap(),
+            ],
+            &[&decoy],
+        )
+        .unwrap();
+    
+        let add = ix::add_to_whitelist(&authority, &env.mint, &user, 0, None, Some(&decoy_key), &spl_token_2022::ID);
+        assert_custom(env.send(vec![add], &[]), VaultError::CredentialNotAssociated);
+        let add = ix::add_to_whitelist(&authority, &env.mint, &user, 0, None, Some(&credential), &spl_token_2022::ID);
+        env.send(vec![add], &[]).unwrap();
+    
+        let remove = ix::remove_from_whitelist(&authority, &env.mint, &user, None, Some(&decoy_key), &spl_token_2022::ID);
+        assert_custom(env.send(vec![remove], &[]), VaultError::CredentialNotAssociated);
+        assert_eq!(credential_balance(&env, &credential), 1);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn prefunded_credential_mint_address_does_not_block_creation() {
+        let mut env = Env::new();
+        let authority = env.authority();
+        let credential_mint = derive_credential_mint(&derive_vault_config(&env.mint).0).0;
+        env.svm.airdrop(&to_sdk(&credential_mint), 1).unwrap();
+    
+        env.send(vec![ix::create_credential_mint(&authority, &env.mint)], &[]).unwrap();
+        let account = env.svm.get_account(&to_sdk(&credential_mint)).unwrap();
+        assert_eq!(from_sdk(&account.owner), spl_token_2022::ID);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn credential_mint_is_created_once() {
+        let (mut env, _) = credential_env();
+        let authority = env.authority();
+    
+        assert_custom(
+            env.send(vec![ix::create_credential_mint(&authority, &env.mint)], &[]),
+            VaultError::CredentialMintExists,
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_close_vault {
+    use super::*;
+    use spl_token_2022::extension::{
+        group_pointer::GroupPointer,
+        interest_bearing_mint::InterestBearingConfig,
+        metadata_pointer::MetadataPointer,
+        mint_close_authority::MintCloseAuthority,
+        permanent_delegate::PermanentDelegate,
+    };
+    use spl_token_group_interface::state::TokenGroup;
+    
+    /// Mint whose close authority is the vault config
+    fn closable_env() -> Env {
+        Env::with_extensions(ExtensionArgs { enable_mint_close_authority: true, ..Default::default() })
+    }
+    
+    fn exists(env: &Env, address: &Pubkey) -> bool {
+        env.svm.get_account(&to_sdk(address)).is_some_and(|account| account.lamports > 0)
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn closes_vault_entries_and_mint_to_the_authority() {
+        let mut env = closable_env();
+        let (alice, _) = env.user(0);
+        env.whitelist(&alice, 0);
+        let authority = env.authority();
+        let vault_config = derive_vault_config(&env.mint).0;
+        let entry = derive_whitelist_entry(&vault_config, &from_sdk(&alice.pubkey())).0;
+        let accounts = [vault_config, derive_vault(&env.mint).0, entry, env.mint];
+        let before = env.svm.get_balance(&to_sdk(&authority)).unwrap();
+    
+        env.send(vec![ix::close_vault(&authority, &env.mint, None, &[entry], &spl_token_2022::ID)], &[]).unwrap();
+    
+        assert!(accounts.iter().all(|address| !exists(&env, address)));
+        assert!(env.svm.get_balance(&to_sdk(&authority)).unwrap() > before);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn mint_with_supply_is_left_open() {
+        let mut env = closable_env();
+        env.user(100);
+        let authority = env.authority();
+    
+        env.send(vec![ix::close_vault(&authority, &env.mint, None, &[], &spl_token_2022::ID)], &[]).unwrap();
+    
+        assert!(!exists(&env, &derive_vault_config(&env.mint).0));
+        assert!(exists(&env, &env.mint));
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn open_mint_authorities_pass_to_the_authority() {
+        let mut env = Env::with_extensions(ExtensionArgs {
+            enable_permanent_delegate: true,
+            enable_interest_bearing: true,
+            enable_mint_close_authority: true,
+            group_max_size: Some(2),
+            metadata: Some(TokenMetadataArgs {
+                name: "Vault".to_string(),
+                symbol: "VLT".to_string(),
+                uri: "https://example.com/vlt.json".to_string(),
+            }),
+            ..Default::default()
+        });
+        env.user(100);
+        let authority = env.authority();
+    
+        env.send(vec![ix::close_vault(&authority, &env.mint, None, &[], &spl_token_2022::ID)], &[]).unwrap();
+    
+        let account = env.svm.get_account(&to_sdk(&env.mint)).unwrap();
+        let mint = StateWithExtensions::<Mint>::unpack(&account.data).unwrap();
+        let authorities = [
+            mint.base.freeze_authority.into(),
+            mint.get_extension::<PermanentDelegate>().unwrap().delegate.into(),
+            mint.get_extension::<InterestBearingConfig>().unwrap().rate_authority.into(),
+            mint.get_extension::<MintCloseAuthority>().unwrap().close_authority.into(),
+            mint.get_extension::<MetadataPointer>().unwrap().authority.into(),
+            mint.get_extension::<GroupPointer>().unwrap().authority.into(),
+            mint.get_extension::<TokenGroup>().unwrap().update_authority.into(),
+            mint.get_variable_len_extension::<TokenMetadata>().unwrap().update_authority.into(),
+        ];
+        assert!(authorities.iter().all(|key: &Option<Pubkey>| *key == Some(authority)), "{authorities:?}");
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn withheld_fees_are_harvested_before_closing() {
+        let mut env = Env::new();
+        let (alice, alice_ata) = env.user(1_000);
+        let vault = derive_vault(&env.mint).0;
+        let authority = env.authority();
+        env.whitelist(&alice, 0);
+        env.deposit(&alice, &alice_ata, 1_000).unwrap();
+        let (held, withheld) = env.balance(&vault);
+        env.withdraw(&alice, &alice_ata, held).unwrap();
+        assert_eq!(env.balance(&vault), (0, withheld));
+    
+        env.send(vec![ix::close_vault(&authority, &env.mint, None, &[], &spl_token_2022::ID)], &[]).unwrap();
+        assert!(!exists(&env, &vault));
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn vault_holding_tokens_cannot_close() {
+        let mut env = Env::new();
+        let (alice, alice_ata) = env.user(1_000);
+        let authority = env.authority();
+        env.whitelist(&alice, 0);
+        env.deposit(&alice, &alice_ata, 100).unwrap();
+    
+        assert_custom(
+            env.send(vec![ix::close_vault(&authority, &env.mint, None, &[], &spl_token_2022::ID)], &[]),
+            VaultError::VaultNotEmpty,
+        );
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn outstanding_credentials_block_closing() {
+        let mut env = closable_env();
+        let (alice, _) = env.user(0);
+        let user = from_sdk(&alice.pubkey());
+        let authority = env.authority();
+        env.send(vec![ix::create_credential_mint(&authority, &env.mint)], &[]).unwrap();
+        let credential_mint = derive_credential_mint(&derive_vault_config(&env.mint).0).0;
+        let credential = env.associated_token_account(&user, &credential_mint);
+        let add = ix::add_to_whitelist(&authority, &env.mint, &user, 0, None, Some(&credential), &spl_token_2022::ID);
+        env.send(vec![add], &[]).unwrap();
+    
+        assert_custom(
+            env.send(vec![ix::close_vault(&authority, &env.mint, None, &[], &spl_token_2022::ID)], &[]),
+            VaultError::MissingCredentialAccounts,
+        );
+        assert_custom(
+            env.send(vec![ix::close_vault(&authority, &env.mint, Some(&credential_mint), &[], &spl_token_2022::ID)], &[]),
+            VaultError::CredentialsOutstanding,
+        );
+    
+        let remove = ix::remove_from_whitelist(&authority, &env.mint, &user, None, Some(&credential), &spl_token_2022::ID);
+        env.send(vec![remove], &[]).unwrap();
+        let close = ix::close_vault(&authority, &env.mint, Some(&credential_mint), &[], &spl_token_2022::ID);
+        env.send(vec![close], &[]).unwrap();
+        assert!(!exists(&env, &credential_mint));
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn accounts_outside_the_vault_are_rejected() {
+        let mut env = closable_env();
+        let (_, alice_ata) = env.user(0);
+        let authority = env.authority();
+    
+        assert_custom(
+            env.send(vec![ix::close_vault(&authority, &env.mint, None, &[alice_ata], &spl_token_2022::ID)], &[]),
+            VaultError::ForeignAccount,
+        );
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn only_the_authority_can_close() {
+        let mut env = closable_env();
+        let (mallory, _) = env.user(0);
+        let close = ix::close_vault(&from_sdk(&mallory.pubkey()), &env.mint, None, &[], &spl_token_2022::ID);
+    
+        assert_custom(env.send(vec![close], &[&mallory]), VaultError::UnauthorizedAuthority);
+    }
+}
+
+/// LiteSVM 0.3 doesn't load the ZK token proof program, so these cover mint
+/// setup and the vault's own checks, which all run before Token-2022 looks
+/// for a proof. Full configure/transfer flows need a validator.
+mod test_confidential {
+    use super::*;
+    use anchor_spl::token_2022::spl_token_2022::{
+        extension::confidential_transfer::ConfidentialTransferMint,
+        solana_zk_token_sdk::zk_token_elgamal::pod::ElGamalPubkey,
+    };
+    use pda_token_vault::instructions::ConfidentialTransferArgs;
+    
+    const AUDITOR: [u8; 32] = [7; 32];
+    
+    fn confidential_env(auto_approve_new_accounts: bool) -> Env {
+        Env::with_extensions(ExtensionArgs {
+            confidential_transfer: Some(ConfidentialTransferArgs {
+                auto_approve_new_accounts,
+                auditor_elgamal_pubkey: Some(AUDITOR),
+            }),
+            ..Default::default()
+        })
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn vault_config_is_the_confidential_transfer_authority() {
+        let env = confidential_env(false);
+        let account = env.svm.get_account(&to_sdk(&env.mint)).unwrap();
+        let mint = StateWithExtensions::<Mint>::unpack(&account.data).unwrap();
+        let config = mint.get_extension::<ConfidentialTransferMint>().unwrap();
+        
+        assert_eq!(Option::<Pubkey>::from(config.authority), Some(derive_vault_config(&env.mint).0));
+        assert!(!bool::from(config.auto_approve_new_accounts));
+        assert!(config.auditor_elgamal_pubkey.equals(&ElGamalPubkey(AUDITOR)));
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn transfer_fee_and_confidential_transfer_are_exclusive() {
+        let mut env = Env::new();
+        let authority = env.authority();
+        let mint = Keypair::new();
+        let create = ix::create_mint(
+            &authority,
+            &from_sdk(&mint.pubkey()),
+            DECIMALS,
+            ExtensionArgs {
+                enable_transfer_fee: true,
+                transfer_fee_basis_points: FEE_BASIS_POINTS,
+                confidential_transfer: Some(ConfidentialTransferArgs::default()),
+                ..Default::default()
+            },
+        );
+        
+        assert_custom(env.send(vec![create], &[&mint]), VaultError::InvalidExtensionConfig);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn plain_mint_vault_cannot_be_configured() {
+        let mut env = Env::new();
+        let authority = env.authority();
+        let configure = ix::configure_confidential_vault(&authority, &env.mint, [0; 36], 64, None);
+        
+        assert_custom(env.send(vec![configure], &[]), VaultError::ConfidentialTransferNotEnabled);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn approval_requires_a_whitelisted_owner() {
+        let mut env = confidential_env(false);
+        let (alice, alice_ata) = env.user(0);
+        let authority = env.authority();
+        let approve = ix::approve_confidential_account(&authority, &env.mint, &from_sdk(&alice.pubkey()), &alice_ata);
+        
+        assert_custom(env.send(vec![approve], &[]), ErrorCode::AccountNotInitialized);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn capped_entries_cannot_receive_confidential_withdrawals() {
+        let mut env = confidential_env(true);
+        let (alice, alice_ata) = env.user(0);
+        env.whitelist(&alice, 500);
+        let authority = env.authority();
+        let withdraw = ix::confidential_withdraw(
+            &authority,
+            &env.mint,
+            &from_sdk(&alice.pubkey()),
+            &alice_ata,
+            [0; 36],
+            Some(&Pubkey::new_unique()),
+        );
+        
+        assert_custom(env.send(vec![withdraw], &[]), VaultError::ConfidentialLimitUnenforceable);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn withdrawal_controls_disable_confidential_withdrawals() {
+        let mut env = confidential_env(true);
+        let (alice, alice_ata) = env.user(0);
+        env.whitelist(&alice, 0);
+        let authority = env.authority();
+        env.send(vec![ix::set_withdrawal_timelock(&authority, &env.mint, 60, &authority)], &[]).unwrap();
+        let withdraw = ix::confidential_withdraw(
+            &authority,
+            &env.mint,
+            &from_sdk(&alice.pubkey()),
+            &alice_ata,
+            [0; 36],
+            Some(&Pubkey::new_unique()),
+        );
+        
+        assert_custom(env.send(vec![withdraw], &[]), VaultError::ConfidentialWithdrawRestricted);
+    }
+}
+
+mod test_token_group {
+    use super::*;
+    use spl_token_group_interface::state::{TokenGroup, TokenGroupMember};
+    use pda_token_vault::client::fetch::AccountFetcher;
+    
+    impl AccountFetcher for Env {
+        fn get_account_data(&self, address: &Pubkey) -> Option<Vec<u8>> {
+            self.svm.get_account(&to_sdk(address)).map(|account| account.data)
+        }
+    }
+    
+    /// Group mint of up to `max_size` members, with its vault and registry
+    fn group_env(max_size: u32) -> Env {
+        let mut env = Env::with_extensions(ExtensionArgs { group_max_size: Some(max_size), ..Default::default() });
+        let authority = env.authority();
+        env.send(vec![ix::create_vault_registry(&authority, &env.mint)], &[]).unwrap();
+        env
+    }
+    
+    fn create_member(env: &mut Env, authority: &Keypair) -> (Pubkey, Result<(), TransactionError>) {
+        let mint = Keypair::new();
+        let create = ix::create_member_mint(
+            &from_sdk(&authority.pubkey()),
+            &from_sdk(&mint.pubkey()),
+            &env.mint,
+            DECIMALS,
+            ExtensionArgs::default(),
+        );
+        (from_sdk(&mint.pubkey()), env.send(vec![create], &[authority, &mint]))
+    }
+    
+    fn group(env: &Env) -> TokenGroup {
+        let account = env.svm.get_account(&to_sdk(&env.mint)).unwrap();
+        *StateWithExtensions::<Mint>::unpack(&account.data).unwrap().get_extension::<TokenGroup>().unwrap()
+    }
+    
+    fn member(env: &Env, mint: &Pubkey) -> TokenGroupMember {
+        let account = env.svm.get_account(&to_sdk(mint)).unwrap();
+        *StateWithExtensions::<Mint>::unpack(&account.data).unwrap().get_extension::<TokenGroupMember>().unwrap()
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn members_join_the_group_and_its_registry() {
+        let mut env = group_env(2);
+        let authority = env.authority.insecure_clone();
+        let (first, result) = create_member(&mut env, &authority);
+        result.unwrap();
+        let (second, result) = create_member(&mut env, &authority);
+        result.unwrap();
+        
+        let registry = pda_token_vault::client::fetch_vault_registry(&env, &env.mint).unwrap().unwrap();
+        assert_eq!(registry.members, vec![first, second]);
+        assert_eq!(u32::from(group(&env).size), 2);
+        let member = member(&env, &second);
+        assert_eq!(member.group, env.mint);
+        assert_eq!(u32::from(member.member_number), 2);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn group_max_size_is_enforced() {
+        let mut env = group_env(1);
+        let authority = env.authority.insecure_clone();
+        create_member(&mut env, &authority).1.unwrap();
+        
+        assert!(create_member(&mut env, &authority).1.is_err());
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn only_the_group_authority_adds_members() {
+        let mut env = group_env(2);
+        let intruder = Keypair::new();
+        env.svm.airdrop(&intruder.pubkey(), 1_000_000_000).unwrap();
+        
+        assert_custom(create_member(&mut env, &intruder).1, VaultError::UnauthorizedAuthority);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn registry_needs_a_group_mint() {
+        let mut env = Env::new();
+        let authority = env.authority();
+        
+        assert_custom(
+            env.send(vec![ix::create_vault_registry(&authority, &env.mint)], &[]),
+            VaultError::NotATokenGroup,
+        );
+    }
+}
+
+mod test_multi_vault {
+    use super::*;
+    use anchor_lang::solana_program::{program_pack::Pack, system_instruction};
+    
+    const LEGACY: Pubkey = anchor_spl::token::ID;
+    
+    impl Env {
+        /// Legacy Token mint with the authority as mint authority
+        fn legacy_mint(&mut self) -> Pubkey {
+            let authority = self.authority();
+            let mint = Keypair::new();
+            let address = from_sdk(&mint.pubkey());
+            let create = system_instruction::create_account(
+                &authority,
+                &address,
+                self.svm.minimum_balance_for_rent_exemption(Mint::LEN),
+                Mint::LEN as u64,
+                &LEGACY,
+            );
+            let init = spl_token_2022::instruction::initialize_mint2(&LEGACY, &address, &authority, None, DECIMALS).unwrap();
+            self.send(vec![create, init], &[&mint]).unwrap();
+            address
+        }
+        
+        /// Token account of `owner` for any mint, holding `amount`
+        fn token_account(&mut self, owner: &Pubkey, mint: &Pubkey, token_program: &Pubkey, amount: u64) -> Pubkey {
+            let authority = self.authority();
+            let account = Keypair::new();
+            let address = from_sdk(&account.pubkey());
+            let space = if *token_program == LEGACY { TokenAccount::LEN } else { self.account_len(mint) };
+            let mut ixs = vec![
+                system_instruction::create_account(
+                    &authority,
+                    &address,
+                    self.svm.minimum_balance_for_rent_exemption(space),
+                    space as u64,
+                    token_program,
+                ),
+                spl_token_2022::instruction::initialize_account3(token_program, &address, mint, owner).unwrap(),
+            ];
+            if amount > 0 {
+                ixs.push(spl_token_2022::instruction::mint_to(token_program, mint, &address, &authority, &[], amount).unwrap());
+            }
+            self.send(ixs, &[&account]).unwrap();
+            address
+        }
+        
+        fn account_len(&self, mint: &Pubkey) -> usize {
+            let account = self.svm.get_account(&to_sdk(mint)).unwrap();
+            let mint = StateWithExtensions::<Mint>::unpack(&account.data).unwrap();
+            let extensions = spl_token_2022::extension::ExtensionType::get_required_init_account_extensions(
+                &mint.get_extension_types().unwrap(),
+            );
+            spl_token_2022::extension::ExtensionType::try_calculate_account_len::<TokenAccount>(&extensions).unwrap()
+        }
+        
+        fn amount(&self, token_account: &Pubkey) -> u64 {
+            let account = self.svm.get_account(&to_sdk(token_account)).unwrap();
+            StateWithExtensions::<TokenAccount>::unpack(&account.data).unwrap().base.amount
+        }
+    }
+    
+    /// Multi vault over the env's Token-2022 mint (unlimited) and a legacy
+    /// mint limited to 100 per withdrawal
+    fn multi_env() -> (Env, Pubkey, Pubkey) {
+        let mut env = Env::with_extensions(ExtensionArgs::default());
+        let authority = env.authority();
+        let legacy = env.legacy_mint();
+        let multi_vault = derive_multi_vault(&authority, 1).0;
+        env.send(
+            vec![
+                ix::create_multi_vault(&authority, 1),
+                ix::add_vault_mint(&authority, &multi_vault, &env.mint, &spl_token_2022::ID, 0),
+                ix::add_vault_mint(&authority, &multi_vault, &legacy, &LEGACY, 100),
+            ],
+            &[],
+        )
+        .unwrap();
+        (env, multi_vault, legacy)
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn holds_token_2022_and_legacy_mints_with_separate_limits() {
+        let (mut env, multi_vault, legacy) = multi_env();
+        let (alice, _) = env.user(0);
+        let user = from_sdk(&alice.pubkey());
+        let mint = env.mint;
+        let own_account = env.token_account(&user, &mint, &spl_token_2022::ID, 500);
+        let legacy_account = env.token_account(&user, &legacy, &LEGACY, 500);
+        let authority = env.authority();
+        env.send(vec![ix::add_multi_vault_member(&authority, &multi_vault, &user)], &[]).unwrap();
+        
+        env.send(
+            vec![
+                ix::multi_vault_deposit(&user, &multi_vault, &mint, &own_account, 500, &spl_token_2022::ID),
+                ix::multi_vault_deposit(&user, &multi_vault, &legacy, &legacy_account, 500, &LEGACY),
+            ],
+            &[&alice],
+        )
+        .unwrap();
+        env.send(vec![ix::multi_vault_withdraw(&user, &multi_vault, &mint, &own_account, 300, &spl_token_2022::ID)], &[&alice])
+            .unwrap();
+        env.send(vec![ix::multi_vault_withdraw(&user, &multi_vault, &legacy, &legacy_account, 100, &LEGACY)], &[&alice])
+            .unwrap();
+        
+        assert_eq!(env.amount(&derive_multi_vault_account(&multi_vault, &mint).0), 200);
+        assert_eq!(env.amount(&derive_multi_vault_account(&multi_vault, &legacy).0), 400);
+        assert_custom(
+            env.send(vec![ix::multi_vault_withdraw(&user, &multi_vault, &legacy, &legacy_account, 101, &LEGACY)], &[&alice]),
+            VaultError::AmountExceedsLimit,
+        );
+        
+        env.send(vec![ix::set_vault_mint_limit(&authority, &multi_vault, &legacy, 0)], &[]).unwrap();
+        env.send(vec![ix::multi_vault_withdraw(&user, &multi_vault, &legacy, &legacy_account, 400, &LEGACY)], &[&alice])
+            .unwrap();
+        assert_eq!(env.amount(&legacy_account), 500);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn removed_members_cannot_withdraw() {
+        let (mut env, multi_vault, legacy) = multi_env();
+        let (alice, _) = env.user(0);
+        let user = from_sdk(&alice.pubkey());
+        let legacy_account = env.token_account(&user, &legacy, &LEGACY, 50);
+        let authority = env.authority();
+        env.send(
+            vec![
+                ix::add_multi_vault_member(&authority, &multi_vault, &user),
+                ix::remove_multi_vault_member(&authority, &multi_vault, &user),
+            ],
+            &[],
+        )
+        .unwrap();
+        env.send(vec![ix::multi_vault_deposit(&user, &multi_vault, &legacy, &legacy_account, 50, &LEGACY)], &[&alice])
+            .unwrap();
+        
+        assert_custom(
+            env.send(vec![ix::multi_vault_withdraw(&user, &multi_vault, &legacy, &legacy_account, 50, &LEGACY)], &[&alice]),
+            ErrorCode::AccountNotInitialized,
+        );
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn unlisted_mints_are_rejected() {
+        let (mut env, multi_vault, _) = multi_env();
+        let (alice, _) = env.user(0);
+        let user = from_sdk(&alice.pubkey());
+        let other = env.legacy_mint();
+        let account = env.token_account(&user, &other, &LEGACY, 10);
+        
+        assert_custom(
+            env.send(vec![ix::multi_vault_deposit(&user, &multi_vault, &other, &account, 10, &LEGACY)], &[&alice]),
+            ErrorCode::AccountNotInitialized,
+        );
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn single_mint_vault_accepts_a_legacy_mint() {
+        let mut env = Env::with_extensions(ExtensionArgs::default());
+        let authority = env.authority();
+        let legacy = env.legacy_mint();
+        env.send(vec![ix::initialize(&authority, &legacy, &LEGACY)], &[]).unwrap();
+        let (alice, _) = env.user(0);
+        let user = from_sdk(&alice.pubkey());
+        let account = env.token_account(&user, &legacy, &LEGACY, 80);
+        env.send(vec![ix::add_to_whitelist(&authority, &legacy, &user, 0, None, None, &LEGACY)], &[]).unwrap();
+        
+        env.send(vec![ix::deposit(&user, &legacy, &account, 80, &LEGACY)], &[&alice]).unwrap();
+        env.send(vec![ix::withdraw(&user, &legacy, &account, 30, &LEGACY)], &[&alice]).unwrap();
+        
+        assert_eq!(env.amount(&derive_vault(&legacy).0), 50);
+        assert_eq!(env.amount(&account), 30);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn legacy_vault_runs_its_full_lifecycle() {
+        let mut env = Env::with_extensions(ExtensionArgs::default());
+        let authority = env.authority();
+        let legacy = env.legacy_mint();
+        let vault_config = derive_vault_config(&legacy).0;
+        env.send(vec![ix::initialize(&authority, &legacy, &LEGACY)], &[]).unwrap();
+        let (alice, _) = env.user(0);
+        let user = from_sdk(&alice.pubkey());
+        let account = env.token_account(&user, &legacy, &LEGACY, 80);
+        env.send(vec![ix::add_to_whitelist(&authority, &legacy, &user, 0, None, None, &LEGACY)], &[]).unwrap();
+        
+        env.send(vec![ix::deposit(&user, &legacy, &account, 80, &LEGACY)], &[&alice]).unwrap();
+        env.send(vec![ix::withdraw(&user, &legacy, &account, 80, &LEGACY)], &[&alice]).unwrap();
+        env.send(vec![ix::remove_from_whitelist(&authority, &legacy, &user, None, None, &LEGACY)], &[]).unwrap();
+        env.send(vec![ix::close_vault(&authority, &legacy, None, &[], &LEGACY)], &[]).unwrap();
+        
+        for closed in [vault_config, derive_vault(&legacy).0] {
+            assert!(env.svm.get_account(&to_sdk(&closed)).is_none_or(|account| account.lamports == 0));
+        }
+        assert_eq!(env.amount(&account), 80);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn token_2022_features_reject_a_legacy_mint() {
+        let mut env = Env::with_extensions(ExtensionArgs::default());
+        let authority = env.authority();
+        let legacy = env.legacy_mint();
+        env.send(vec![ix::initialize(&authority, &legacy, &LEGACY)], &[]).unwrap();
+        let account = env.token_account(&authority, &legacy, &LEGACY, 10);
+        
+        assert_custom(
+            env.send(vec![ix::create_credential_mint(&authority, &legacy)], &[]),
+            VaultError::RequiresToken2022,
+        );
+        assert_custom(
+            env.send(vec![ix::clawback(&authority, &legacy, &account, None, 10, 1)], &[]),
+            VaultError::RequiresToken2022,
+        );
+        assert_custom(env.send(vec![ix::update_interest_rate(&authority, &legacy, 100)], &[]), VaultError::RequiresToken2022);
+    }
+}
+
+mod test_timelock {
+    use super::*;
+    use pda_token_vault::{
+        client::fetch_withdrawal_ticket,
+        events::{WithdrawalCancelled, WithdrawalRequested, Withdrew},
+    };
+    use solana_sdk::clock::Clock;
+    
+    const DELAY: i64 = 24 * 60 * 60;
+    
+    /// Vault with a day's delay, and alice whitelisted with 1_000 deposited
+    fn timelock_env(guardian: Option<&Pubkey>) -> (Env, Keypair, Pubkey) {
+        let mut env = Env::new();
+        let authority = env.authority();
+        let (alice, alice_ata) = env.user(1_000);
+        env.whitelist(&alice, 0);
+        env.deposit(&alice, &alice_ata, 1_000).unwrap();
+        let guardian = guardian.copied().unwrap_or(authority);
+        env.send(vec![ix::set_withdrawal_timelock(&authority, &env.mint, DELAY, &guardian)], &[]).unwrap();
+        (env, alice, alice_ata)
+    }
+    
+    fn advance(env: &mut Env, seconds: i64) {
+        let mut clock: Clock = env.svm.get_sysvar();
+        clock.unix_timestamp += seconds;
+        env.svm.set_sysvar(&clock);
+    }
+    
+    fn request(env: &mut Env, user: &Keypair, amount: u64) -> Result<(), TransactionError> {
+        let request = ix::request_withdrawal(&from_sdk(&user.pubkey()), &env.mint, amount);
+        env.send(vec![request], &[user])
+    }
+    
+    fn claim(env: &mut Env, user: &Keypair, token_account: &Pubkey) -> Result<(), TransactionError> {
+        let claim = ix::claim_withdrawal(&from_sdk(&user.pubkey()), &env.mint, token_account, &spl_token_2022::ID);
+        env.send(vec![claim], &[user])
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn withdrawals_wait_out_the_delay() {
+        let (mut env, alice, alice_ata) = timelock_env(None);
+        let vault = derive_vault(&env.mint).0;
+        
+        assert_custom(env.withdraw(&alice, &alice_ata, 100), VaultError::WithdrawalTimelocked);
+        request(&mut env, &alice, 400).unwrap();
+        assert_custom(claim(&mut env, &alice, &alice_ata), VaultError::WithdrawalLocked);
+        
+        advance(&mut env, DELAY);
+        claim(&mut env, &alice, &alice_ata).unwrap();
+        assert_eq!(env.balance(&vault).0, 600);
+        assert!(fetch_withdrawal_ticket(&env, &env.mint, &from_sdk(&alice.pubkey())).unwrap().is_none());
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn user_cancels_and_requests_again() {
+        let (mut env, alice, _) = timelock_env(None);
+        let user = from_sdk(&alice.pubkey());
+        request(&mut env, &alice, 100).unwrap();
+        
+        assert!(request(&mut env, &alice, 200).is_err(), "one ticket per user");
+        env.send(vec![ix::cancel_withdrawal(&user, &env.mint)], &[&alice]).unwrap();
+        request(&mut env, &alice, 200).unwrap();
+        assert_eq!(fetch_withdrawal_ticket(&env, &env.mint, &user).unwrap().unwrap().amount, 200);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn only_the_guardian_can_veto() {
+        let guardian = Keypair::new();
+        let (mut env, alice, alice_ata) = timelock_env(Some(&from_sdk(&guardian.pubkey())));
+        let user = from_sdk(&alice.pubkey());
+        let authority = env.authority();
+        request(&mut env, &alice, 400).unwrap();
+        
+        assert_custom(
+            env.send(vec![ix::veto_withdrawal(&authority, &env.mint, &user)], &[]),
+            VaultError::UnauthorizedAuthority,
+        );
+        env.send(vec![ix::veto_withdrawal(&from_sdk(&guardian.pubkey()), &env.mint, &user)], &[&guardian])
+            .unwrap();
+        advance(&mut env, DELAY);
+        assert_custom(claim(&mut env, &alice, &alice_ata), ErrorCode::AccountNotInitialized);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn claims_recheck_the_approval_threshold() {
+        let (mut env, alice, alice_ata) = timelock_env(None);
+        let authority = env.authority();
+        let approver = from_sdk(&Keypair::new().pubkey());
+        request(&mut env, &alice, 400).unwrap();
+        env.send(vec![ix::set_approvers(&authority, &env.mint, &[approver], 1, 100)], &[]).unwrap();
+        
+        advance(&mut env, DELAY);
+        assert_custom(claim(&mut env, &alice, &alice_ata), VaultError::ApprovalRequired);
+        env.send(vec![ix::set_approvers(&authority, &env.mint, &[], 0, 0)], &[]).unwrap();
+        claim(&mut env, &alice, &alice_ata).unwrap();
+        assert_eq!(env.balance(&derive_vault(&env.mint).0).0, 600);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn claims_recheck_the_whitelist() {
+        let (mut env, alice, alice_ata) = timelock_env(None);
+        let authority = env.authority();
+        request(&mut env, &alice, 400).unwrap();
+        let alice_key = from_sdk(&alice.pubkey());
+        let remove = ix::remove_from_whitelist(&authority, &env.mint, &alice_key, None, None, &spl_token_2022::ID);
+        env.send(vec![remove], &[]).unwrap();
+        advance(&mut env, DELAY);
+        
+        assert_custom(claim(&mut env, &alice, &alice_ata), ErrorCode::AccountNotInitialized);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn requests_respect_the_limit_and_delay_cannot_be_negative() {
+        let (mut env, alice, alice_ata) = timelock_env(None);
+        let authority = env.authority();
+        let alice_key = from_sdk(&alice.pubkey());
+        let remove = ix::remove_from_whitelist(&authority, &env.mint, &alice_key, None, None, &spl_token_2022::ID);
+        env.send(vec![remove], &[]).unwrap();
+        env.whitelist(&alice, 100);
+        
+        assert_custom(request(&mut env, &alice, 101), VaultError::AmountExceedsLimit);
+        assert_custom(
+            env.send(vec![ix::set_withdrawal_timelock(&authority, &env.mint, -1, &authority)], &[]),
+            VaultError::InvalidWithdrawalDelay,
+        );
+        env.send(vec![ix::set_withdrawal_timelock(&authority, &env.mint, 0, &authority)], &[]).unwrap();
+        env.withdraw(&alice, &alice_ata, 100).unwrap();
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn timelock_flow_logs_its_events() {
+        let guardian = Keypair::new();
+        let guardian_key = from_sdk(&guardian.pubkey());
+        let (mut env, alice, alice_ata) = timelock_env(Some(&guardian_key));
+        let user = from_sdk(&alice.pubkey());
+        
+        request(&mut env, &alice, 400).unwrap();
+        let requested = env.events::<WithdrawalRequested>();
+        assert_eq!(requested.len(), 1);
+        assert_eq!((requested[0].user, requested[0].amount), (user, 400));
+        assert_eq!(requested[0].unlock_time, requested[0].timestamp + DELAY);
+        
+        env.send(vec![ix::veto_withdrawal(&guardian_key, &env.mint, &user)], &[&guardian]).unwrap();
+        let vetoed = env.events::<WithdrawalCancelled>();
+        assert_eq!(vetoed.len(), 1);
+        assert_eq!((vetoed[0].user, vetoed[0].amount, vetoed[0].cancelled_by), (user, 400, guardian_key));
+        
+        request(&mut env, &alice, 300).unwrap();
+        advance(&mut env, DELAY);
+        claim(&mut env, &alice, &alice_ata).unwrap();
+        let withdrew = env.events::<Withdrew>();
+        assert_eq!(withdrew.len(), 1);
+        assert_eq!((withdrew[0].withdrawer, withdrew[0].amount), (user, 300));
+        assert!(env.events::<WithdrawalCancelled>().is_empty());
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn open_tickets_close_with_the_vault() {
+        let (mut env, alice, alice_ata) = timelock_env(None);
+        let user = from_sdk(&alice.pubkey());
+        let authority = env.authority();
+        let ticket = derive_withdrawal_ticket(&derive_vault_config(&env.mint).0, &user).0;
+        request(&mut env, &alice, 100).unwrap();
+        env.send(vec![ix::set_withdrawal_timelock(&authority, &env.mint, 0, &authority)], &[]).unwrap();
+        let held = env.balance(&derive_vault(&env.mint).0).0;
+        env.withdraw(&alice, &alice_ata, held).unwrap();
+        
+        assert_custom(
+            env.send(vec![ix::close_vault(&authority, &env.mint, None, &[], &spl_token_2022::ID)], &[]),
+            VaultError::OpenClaims,
+        );
+        assert_custom(
+            env.send(vec![ix::close_vault(&authority, &env.mint, None, &[ticket, authority], &spl_token_2022::ID)], &[]),
+            VaultError::RentRecipientMismatch,
+        );
+        let before = env.svm.get_balance(&to_sdk(&user)).unwrap();
+        env.send(vec![ix::close_vault(&authority, &env.mint, None, &[ticket, user], &spl_token_2022::ID)], &[]).unwrap();
+        assert!(fetch_withdrawal_ticket(&env, &env.mint, &user).unwrap().is_none());
+        assert!(env.svm.get_balance(&to_sdk(&user)).unwrap() > before);
+    }
+}
+
+mod test_vesting {
+    use super::*;
+    use pda_token_vault::client::fetch_vesting_schedule;
+    use solana_sdk::clock::Clock;
+    
+    /// Vault holding alice's 1_000 deposit, and bob with an empty account
+    fn vesting_env() -> (Env, Keypair, Pubkey, Keypair, Pubkey) {
+        let mut env = Env::new();
+        let (alice, alice_ata) = env.user(1_000);
+        env.whitelist(&alice, 0);
+        env.deposit(&alice, &alice_ata, 1_000).unwrap();
+        let (bob, bob_ata) = env.user(0);
+        (env, alice, alice_ata, bob, bob_ata)
+    }
+    
+    fn now(env: &Env) -> i64 {
+        env.svm.get_sysvar::<Clock>().unix_timestamp
+    }
+    
+    fn advance(env: &mut Env, seconds: i64) {
+        let mut clock: Clock = env.svm.get_sysvar();
+        clock.unix_timestamp += seconds;
+        env.svm.set_sysvar(&clock);
+    }
+    
+    fn vest(env: &mut Env, beneficiary: &Keypair, id: u64, total: u64, cliff: i64, end: i64) -> Result<(), TransactionError> {
+        let (authority, start) = (env.authority(), now(env));
+        let beneficiary = from_sdk(&beneficiary.pubkey());
+        let create = ix::create_vesting(&authority, &env.mint, &beneficiary, id, total, start, start + cliff, start + end);
+        env.send(vec![create], &[])
+    }
+    
+    fn release(env: &mut Env, beneficiary: &Keypair, id: u64, token_account: &Pubkey) -> Result<(), TransactionError> {
+        let beneficiary_key = from_sdk(&beneficiary.pubkey());
+        let release = ix::withdraw_vested(&beneficiary_key, &env.mint, id, token_account, &spl_token_2022::ID);
+        env.send(vec![release], &[beneficiary])
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn releases_linearly_after_the_cliff() {
+        let (mut env, _, _, bob, bob_ata) = vesting_env();
+        let vault = derive_vault(&env.mint).0;
+        vest(&mut env, &bob, 0, 1_000, 100, 1_000).unwrap();
+        
+        assert_custom(release(&mut env, &bob, 0, &bob_ata), VaultError::NothingVested);
+        advance(&mut env, 500);
+        release(&mut env, &bob, 0, &bob_ata).unwrap();
+        assert_eq!(env.balance(&vault).0, 500);
+        assert_custom(release(&mut env, &bob, 0, &bob_ata), VaultError::NothingVested);
+        
+        advance(&mut env, 1_000);
+        release(&mut env, &bob, 0, &bob_ata).unwrap();
+        assert_eq!(env.balance(&vault).0, 0);
+        let schedule = fetch_vesting_schedule(&env, &env.mint, &from_sdk(&bob.pubkey()), 0).unwrap().unwrap();
+        assert_eq!(schedule.released, 1_000);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn reserved_tokens_stay_out_of_reach() {
+        let (mut env, alice, alice_ata, bob, _) = vesting_env();
+        vest(&mut env, &bob, 0, 600, 0, 1_000).unwrap();
+        
+        assert_custom(env.withdraw(&alice, &alice_ata, 401), VaultError::VestingReserved);
+        env.withdraw(&alice, &alice_ata, 400).unwrap();
+        let (carol, _) = env.user(0);
+        assert_custom(vest(&mut env, &carol, 0, 1, 0, 1), VaultError::VestingReserved);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn schedules_must_be_ordered_and_non_empty() {
+        let (mut env, _, _, bob, _) = vesting_env();
+        
+        assert_custom(vest(&mut env, &bob, 0, 0, 0, 10), VaultError::InvalidVestingSchedule);
+        assert_custom(vest(&mut env, &bob, 0, 10, 20, 10), VaultError::InvalidVestingSchedule);
+        assert_custom(vest(&mut env, &bob, 0, 10, -1, 10), VaultError::InvalidVestingSchedule);
+        vest(&mut env, &bob, 0, 10, 10, 10).unwrap();
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn a_beneficiary_holds_several_schedules() {
+        let (mut env, _, _, bob, bob_ata) = vesting_env();
+        let bob_key = from_sdk(&bob.pubkey());
+        vest(&mut env, &bob, 0, 400, 0, 100).unwrap();
+        vest(&mut env, &bob, 1, 600, 0, 1_000).unwrap();
+        assert!(vest(&mut env, &bob, 1, 1, 0, 1).is_err(), "ids are unique per beneficiary");
+        
+        advance(&mut env, 100);
+        release(&mut env, &bob, 0, &bob_ata).unwrap();
+        release(&mut env, &bob, 1, &bob_ata).unwrap();
+        let released = |id| fetch_vesting_schedule(&env, &env.mint, &bob_key, id).unwrap().unwrap().released;
+        assert_eq!((released(0), released(1)), (400, 60));
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn released_schedules_close_before_the_vault() {
+        let (mut env, _, _, bob, bob_ata) = vesting_env();
+        let authority = env.authority();
+        let bob_key = from_sdk(&bob.pubkey());
+        let second = derive_vesting_schedule(&derive_vault_config(&env.mint).0, &bob_key, 1).0;
+        vest(&mut env, &bob, 0, 400, 0, 100).unwrap();
+        vest(&mut env, &bob, 1, 600, 0, 100).unwrap();
+        
+        assert_custom(
+            env.send(vec![ix::close_vesting(&authority, &env.mint, &bob_key, 0)], &[]),
+            VaultError::VestingNotFullyReleased,
+        );
+        advance(&mut env, 100);
+        release(&mut env, &bob, 0, &bob_ata).unwrap();
+        env.send(vec![ix::close_vesting(&authority, &env.mint, &bob_key, 0)], &[]).unwrap();
+        assert!(fetch_vesting_schedule(&env, &env.mint, &bob_key, 0).unwrap().is_none());
+        
+        let close = ix::close_vault(&authority, &env.mint, None, &[second], &spl_token_2022::ID);
+        assert_custom(env.send(vec![close.clone()], &[]), VaultError::VaultNotEmpty);
+        release(&mut env, &bob, 1, &bob_ata).unwrap();
+        let without_schedule = ix::close_vault(&authority, &env.mint, None, &[], &spl_token_2022::ID);
+        assert_custom(env.send(vec![without_schedule], &[]), VaultError::OpenClaims);
+        env.send(vec![close], &[]).unwrap();
+        assert!(fetch_vesting_schedule(&env, &env.mint, &bob_key, 1).unwrap().is_none());
+    }
+}
+
+mod test_approvals {
+    use super::*;
+    use pda_token_vault::client::fetch_proposal;
+    use solana_sdk::clock::Clock;
+    
+    const THRESHOLD: u64 = 100;
+    
+    /// Vault holding alice's 1_000 deposit
+    fn approval_env() -> (Env, Keypair, Pubkey) {
+        let mut env = Env::new();
+        let (alice, alice_ata) = env.user(1_000);
+        env.whitelist(&alice, 0);
+        env.deposit(&alice, &alice_ata, 1_000).unwrap();
+        (env, alice, alice_ata)
+    }
+    
+    fn set_approvers(env: &mut Env, approvers: &[&Keypair], quorum: u8) -> Result<(), TransactionError> {
+        let authority = env.authority();
+        let approvers: Vec<_> = approvers.iter().map(|a| from_sdk(&a.pubkey())).collect();
+        env.send(vec![ix::set_approvers(&authority, &env.mint, &approvers, quorum, THRESHOLD)], &[])
+    }
+    
+    fn propose(env: &mut Env, user: &Keypair, token_account: &Pubkey, amount: u64) -> Result<(), TransactionError> {
+        let propose = ix::propose_withdrawal(&from_sdk(&user.pubkey()), &env.mint, token_account, amount);
+        env.send(vec![propose], &[user])
+    }
+    
+    fn approve(env: &mut Env, approver: &Keypair, user: &Keypair, token_account: &Pubkey) -> Result<(), TransactionError> {
+        let user = from_sdk(&user.pubkey());
+        let approve =
+            ix::approve_withdrawal(&from_sdk(&approver.pubkey()), &env.mint, &user, token_account, &spl_token_2022::ID);
+        env.send(vec![approve], &[approver])
+    }
+    
+    fn execute(env: &mut Env, user: &Keypair, token_account: &Pubkey) -> Result<(), TransactionError> {
+        let execute = ix::execute_proposal(&from_sdk(&user.pubkey()), &env.mint, token_account, &spl_token_2022::ID);
+        env.send(vec![execute], &[user])
+    }
+    
+    fn advance(env: &mut Env, seconds: i64) {
+        let mut clock: Clock = env.svm.get_sysvar();
+        clock.unix_timestamp += seconds;
+        env.svm.set_sysvar(&clock);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn large_withdrawals_wait_for_the_quorum() {
+        let (mut env, alice, alice_ata) = approval_env();
+        let (first, second, outsider) = (Keypair::new(), Keypair::new(), Keypair::new());
+        let vault = derive_vault(&env.mint).0;
+        set_approvers(&mut env, &[&first, &second, &Keypair::new()], 2).unwrap();
+        
+        env.withdraw(&alice, &alice_ata, THRESHOLD).unwrap();
+        assert_custom(env.withdraw(&alice, &alice_ata, THRESHOLD + 1), VaultError::ApprovalRequired);
+        assert_custom(propose(&mut env, &alice, &alice_ata, THRESHOLD), VaultError::ApprovalNotRequired);
+        propose(&mut env, &alice, &alice_ata, 500).unwrap();
+        
+        approve(&mut env, &first, &alice, &alice_ata).unwrap();
+        assert_eq!(env.balance(&vault).0, 900);
+        assert_custom(approve(&mut env, &first, &alice, &alice_ata), VaultError::AlreadyApproved);
+        assert_custom(approve(&mut env, &outsider, &alice, &alice_ata), VaultError::NotAnApprover);
+        
+        approve(&mut env, &second, &alice, &alice_ata).unwrap();
+        assert_eq!(env.balance(&vault).0, 400);
+        assert!(fetch_proposal(&env, &env.mint, &from_sdk(&alice.pubkey())).unwrap().is_none());
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn removed_approvers_stop_counting() {
+        let (mut env, alice, alice_ata) = approval_env();
+        let (first, second, third) = (Keypair::new(), Keypair::new(), Keypair::new());
+        let vault = derive_vault(&env.mint).0;
+        set_approvers(&mut env, &[&first, &second], 2).unwrap();
+        propose(&mut env, &alice, &alice_ata, 500).unwrap();
+        approve(&mut env, &first, &alice, &alice_ata).unwrap();
+        
+        set_approvers(&mut env, &[&second, &third], 2).unwrap();
+        approve(&mut env, &second, &alice, &alice_ata).unwrap();
+        assert_eq!(env.balance(&vault).0, 1_000);
+        approve(&mut env, &third, &alice, &alice_ata).unwrap();
+        assert_eq!(env.balance(&vault).0, 500);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn a_zero_quorum_never_pays_out() {
+        let (mut env, alice, alice_ata) = approval_env();
+        let first = Keypair::new();
+        let vault = derive_vault(&env.mint).0;
+        set_approvers(&mut env, &[&first], 1).unwrap();
+        propose(&mut env, &alice, &alice_ata, 500).unwrap();
+        set_approvers(&mut env, &[&first], 0).unwrap();
+        
+        approve(&mut env, &first, &alice, &alice_ata).unwrap();
+        assert_eq!(env.balance(&vault).0, 1_000);
+        assert_custom(execute(&mut env, &alice, &alice_ata), VaultError::QuorumNotReached);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn proposer_can_cancel() {
+        let (mut env, alice, alice_ata) = approval_env();
+        let first = Keypair::new();
+        let user = from_sdk(&alice.pubkey());
+        set_approvers(&mut env, &[&first], 1).unwrap();
+        propose(&mut env, &alice, &alice_ata, 500).unwrap();
+        
+        env.send(vec![ix::cancel_proposal(&user, &env.mint)], &[&alice]).unwrap();
+        assert!(fetch_proposal(&env, &env.mint, &user).unwrap().is_none());
+        assert_custom(approve(&mut env, &first, &alice, &alice_ata), ErrorCode::AccountNotInitialized);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn approver_sets_are_validated() {
+        let (mut env, _, _) = approval_env();
+        let (first, second) = (Keypair::new(), Keypair::new());
+        let six: Vec<Keypair> = (0..6).map(|_| Keypair::new()).collect();
+        
+        assert_custom(set_approvers(&mut env, &[&first, &first], 1), VaultError::InvalidApproverSet);
+        assert_custom(set_approvers(&mut env, &[&first, &second], 3), VaultError::InvalidApproverSet);
+        assert_custom(set_approvers(&mut env, &six.iter().collect::<Vec<_>>(), 2), VaultError::InvalidApproverSet);
+        set_approvers(&mut env, &[], 0).unwrap();
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn approved_proposals_wait_out_the_timelock() {
+        let (mut env, alice, alice_ata) = approval_env();
+        let (first, second) = (Keypair::new(), Keypair::new());
+        let authority = env.authority();
+        let user = from_sdk(&alice.pubkey());
+        let vault = derive_vault(&env.mint).0;
+        env.send(vec![ix::set_withdrawal_timelock(&authority, &env.mint, 60, &authority)], &[]).unwrap();
+        set_approvers(&mut env, &[&first, &second], 2).unwrap();
+        propose(&mut env, &alice, &alice_ata, 500).unwrap();
+        let proposed_at = env.svm.get_sysvar::<Clock>().unix_timestamp;
+        assert_eq!(fetch_proposal(&env, &env.mint, &user).unwrap().unwrap().unlock_at, proposed_at + 60);
+        
+        approve(&mut env, &first, &alice, &alice_ata).unwrap();
+        assert_custom(execute(&mut env, &alice, &alice_ata), VaultError::QuorumNotReached);
+        approve(&mut env, &second, &alice, &alice_ata).unwrap();
+        assert_eq!(env.balance(&vault).0, 1_000, "the quorum alone doesn't pay out");
+        assert_custom(execute(&mut env, &alice, &alice_ata), VaultError::WithdrawalLocked);
+        
+        advance(&mut env, 60);
+        execute(&mut env, &alice, &alice_ata).unwrap();
+        assert_eq!(env.balance(&vault).0, 500);
+        assert!(fetch_proposal(&env, &env.mint, &user).unwrap().is_none());
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn guardian_can_veto() {
+        let (mut env, alice, alice_ata) = approval_env();
+        let (first, second, outsider) = (Keypair::new(), Keypair::new(), Keypair::new());
+        let guardian = env.authority();
+        let user = from_sdk(&alice.pubkey());
+        set_approvers(&mut env, &[&first, &second], 2).unwrap();
+        propose(&mut env, &alice, &alice_ata, 500).unwrap();
+        approve(&mut env, &first, &alice, &alice_ata).unwrap();
+        
+        let mint = env.mint;
+        let veto = |guardian: &Pubkey| ix::veto_proposal(guardian, &mint, &user);
+        let outsider_veto = veto(&from_sdk(&outsider.pubkey()));
+        assert_custom(env.send(vec![outsider_veto], &[&outsider]), VaultError::UnauthorizedAuthority);
+        let before = env.svm.get_balance(&alice.pubkey()).unwrap();
+        env.send(vec![veto(&guardian)], &[]).unwrap();
+        assert!(fetch_proposal(&env, &env.mint, &user).unwrap().is_none());
+        assert!(env.svm.get_balance(&alice.pubkey()).unwrap() > before);
+        assert_custom(approve(&mut env, &second, &alice, &alice_ata), ErrorCode::AccountNotInitialized);
+    }
+    
+    #[test]
+    #[ignore = "needs pda_token_vault.so; run `cargo build-sbf` first"]
+    fn open_proposals_close_with_the_vault() {
+        let (mut env, alice, alice_ata) = approval_env();
+        let authority = env.authority();
+        let user = from_sdk(&alice.pubkey());
+        let proposal = derive_proposal(&derive_vault_config(&env.mint).0, &user).0;
+        set_approvers(&mut env, &[&Keypair::new()], 1).unwrap();
+        propose(&mut env, &alice, &alice_ata, 500).unwrap();
+        set_approvers(&mut env, &[], 0).unwrap();
+        env.withdraw(&alice, &alice_ata, 1_000).unwrap();
+        
+        let mint = env.mint;
+        let close = |entries: &[Pubkey]| ix::close_vault(&authority, &mint, None, entries, &spl_token_2022::ID);
+        assert_custom(env.send(vec![close(&[])], &[]), VaultError::OpenClaims);
+        assert_custom(env.send(vec![close(&[proposal, authority])], &[]), VaultError::RentRecipientMismatch);
+        env.send(vec![close(&[proposal, user])], &[]).unwrap();
+        assert!(fetch_proposal(&env, &env.mint, &user).unwrap().is_none());
+    }
+}
@@ -0,0 +1,241 @@
+//! Property tests: random operation sequences run against the whitelist
+//! entry accounts and limit check, and compared with a simple model
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::OnceLock;
+use anchor_lang::prelude::*;
+use pda_token_vault::{
+    client::pda::*,
+    error::VaultError,
+    state::{VestingSchedule, WhitelistEntry},
+};
+use proptest::prelude::*;
+
+const USERS: usize = 8;
+const INITIAL_BALANCE: u64 = 1_000;
+
+fn user(index: usize) -> Pubkey {
+    Pubkey::new_from_array([index as u8 + 1; 32])
+}
+
+fn mint() -> Pubkey {
+    Pubkey::new_from_array([0xAA; 32])
+}
+
+#[derive(Clone, Debug)]
+enum Op {
+    Add { user: usize, max_amount: u64 },
+    Remove { user: usize },
+    Deposit { user: usize, amount: u64 },
+    Withdraw { user: usize, amount: u64 },
+}
+
+/// Small amounts and limits so boundaries are hit often
+fn op() -> impl Strategy<Value = Op> {
+    let user = 0..USERS;
+    let amount = prop_oneof![0u64..=300, Just(u64::MAX)];
+    prop_oneof![
+        (user.clone(), 0u64..=300).prop_map(|(user, max_amount)| Op::Add { user, max_amount }),
+        user.clone().prop_map(|user| Op::Remove { user }),
+        (user.clone(), amount.clone()).prop_map(|(user, amount)| Op::Deposit { user, amount }),
+        (user, amount).prop_map(|(user, amount)| Op::Withdraw { user, amount }),
+    ]
+}
+
+/// Outcome shared by the model and the program so they can be compared
+#[derive(Debug, PartialEq)]
+enum Outcome {
+    Ok,
+    /// `init` on an entry that already exists
+    AlreadyInUse,
+    /// Entry account missing when it's required
+    NotInitialized,
+    Vault(u32),
+    InsufficientFunds,
+}
+
+/// Reference implementation, written independently of the program's helpers
+#[derive(Default)]
+struct Model {
+    limits: BTreeMap<usize, u64>,
+    balances: [u64; USERS],
+    vault: u64,
+}
+
+impl Model {
+    fn apply(&mut self, op: &Op) -> Outcome {
+        match *op {
+            Op::Add { user, max_amount } => {
+                if self.limits.contains_key(&user) {
+                    return Outcome::AlreadyInUse;
+                }
+                self.limits.insert(user, max_amount);
+                Outcome::Ok
+            }
+            Op::Remove { user } => match self.limits.remove(&user) {
+                Some(_) => Outcome::Ok,
+                None => Outcome::NotInitialized,
+            },
+            // Deposits aren't gated by the whitelist
+            Op::Deposit { user, amount } => {
+                if self.balances[user] < amount {
+                    return Outcome::InsufficientFunds;
+                }
+                self.balances[user] -= amount;
+                self.vault += amount;
+                Outcome::Ok
+            }
+            Op::Withdraw { user, amount } => {
+                let Some(&limit) = self.limits.get(&user) else { return Outcome::NotInitialized };
+                if limit != 0 && amount > limit {
+                    return Outcome::Vault(VaultError::AmountExceedsLimit.into());
+                }
+                if self.vault < amount {
+                    return Outcome::InsufficientFunds;
+                }
+                self.vault -= amount;
+                self.balances[user] += amount;
+                Outcome::Ok
+            }
+        }
+    }
+}
+
+/// The program's side: serialized entry accounts keyed by PDA address, checked
+/// the way the `withdraw` constraints and handler check them
+struct Program {
+    accounts: HashMap<Pubkey, Vec<u8>>,
+    balances: [u64; USERS],
+    vault: u64,
+}
+
+impl Program {
+    /// PDA derivation dominates the run time, so derive each address once
+    fn entry_address(user: usize) -> Pubkey {
+        static ADDRESSES: OnceLock<Vec<Pubkey>> = OnceLock::new();
+        ADDRESSES.get_or_init(|| {
+            let vault_config = derive_vault_config(&mint()).0;
+            (0..USERS).map(|i| derive_whitelist_entry(&vault_config, &self::user(i)).0).collect()
+        })[user]
+    }
+    
+    fn apply(&mut self, op: &Op) -> Outcome {
+        match *op {
+            Op::Add { user, max_amount } => {
+                let address = Self::entry_address(user);
+                if self.accounts.contains_key(&address) {
+                    return Outcome::AlreadyInUse;
+                }
+                let vault_config = derive_vault_config(&mint()).0;
+                let entry = WhitelistEntry { user: self::user(user), max_amount, vault_config, bump: 0 };
+                let mut data = Vec::new();
+                entry.try_serialize(&mut data).unwrap();
+                self.accounts.insert(address, data);
+                Outcome::Ok
+            }
+            Op::Remove { user } => match self.accounts.remove(&Self::entry_address(user)) {
+                Some(_) => Outcome::Ok,
+                None => Outcome::NotInitialized,
+            },
+            Op::Deposit { user, amount } => {
+                if self.balances[user] < amount {
+                    return Outcome::InsufficientFunds;
+                }
+                self.balances[user] -= amount;
+                self.vault += amount;
+                Outcome::Ok
+            }
+            Op::Withdraw { user, amount } => {
+                let Some(data) = self.accounts.get(&Self::entry_address(user)) else {
+                    return Outcome::NotInitialized;
+                };
+                let entry = WhitelistEntry::try_deserialize(&mut data.as_slice()).unwrap();
+                if entry.user != self::user(user) {
+                    return Outcome::Vault(VaultError::NotWhitelisted.into());
+                }
+                if !entry.is_within_limit(amount) {
+                    return Outcome::Vault(VaultError::AmountExceedsLimit.into());
+                }
+                if self.vault < amount {
+                    return Outcome::InsufficientFunds;
+                }
+                self.vault -= amount;
+                self.balances[user] += amount;
+                Outcome::Ok
+            }
+        }
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(512))]
+    
+    #[test]
+    fn whitelist_state_machine_matches_model(ops in prop::collection::vec(op(), 1..80)) {
+        let mut program = Program { accounts: HashMap::new(), balances: [INITIAL_BALANCE; USERS], vault: 0 };
+        let mut model = Model { balances: [INITIAL_BALANCE; USERS], ..Model::default() };
+    
+        for op in &ops {
+            prop_assert_eq!(program.apply(op), model.apply(op), "{:?}", op);
+    
+            // One entry per whitelisted user, and nothing else
+            prop_assert_eq!(program.accounts.len(), model.limits.len());
+            for (&user, &limit) in &model.limits {
+                let data = &program.accounts[&Program::entry_address(user)];
+                let entry = WhitelistEntry::try_deserialize(&mut data.as_slice()).unwrap();
+                prop_assert_eq!(entry.max_amount, limit);
+            }
+    
+            let total = program.balances.iter().sum::<u64>() + program.vault;
+            prop_assert_eq!(total, INITIAL_BALANCE * USERS as u64, "tokens created or destroyed");
+            prop_assert_eq!(program.balances, model.balances);
+            prop_assert_eq!(program.vault, model.vault);
+        }
+    }
+    
+    /// Exactly `max_amount` passes and one more fails
+    #[test]
+    fn limit_is_inclusive(max_amount in 1u64..u64::MAX, amount in any::<u64>()) {
+        let entry = WhitelistEntry { user: user(0), max_amount, vault_config: Pubkey::default(), bump: 0 };
+        prop_assert!(entry.is_within_limit(max_amount));
+        prop_assert!(!entry.is_within_limit(max_amount + 1));
+        prop_assert_eq!(entry.is_within_limit(amount), amount <= max_amount);
+    }
+    
+    #[test]
+    fn zero_limit_is_unlimited(amount in any::<u64>()) {
+        let entry = WhitelistEntry { user: user(0), max_amount: 0, vault_config: Pubkey::default(), bump: 0 };
+        prop_assert!(entry.is_within_limit(amount));
+    }
+    
+    /// Nothing before the cliff, everything from the end, never decreasing
+    /// and never above the total in between
+    #[test]
+    fn vesting_is_monotonic_and_bounded(
+        total in 1u64..=u64::MAX,
+        start in -1_000_000i64..1_000_000,
+        cliff_offset in 0i64..1_000_000,
+        end_offset in 0i64..1_000_000,
+        a in -2_000_000i64..4_000_000,
+        b in -2_000_000i64..4_000_000,
+    ) {
+        let cliff = start + cliff_offset;
+        let schedule = VestingSchedule {
+            vault_config: Pubkey::default(),
+            beneficiary: user(0),
+            schedule_id: 0,
+            start,
+            cliff,
+            end: cliff + end_offset,
+            total,
+            released: 0,
+            bump: 0,
+        };
+        let (earlier, later) = (a.min(b), a.max(b));
+        
+        prop_assert!(schedule.vested_amount(earlier) <= schedule.vested_amount(later));
+        prop_assert!(schedule.vested_amount(later) <= total);
+        prop_assert_eq!(schedule.vested_amount(cliff - 1), 0);
+        prop_assert_eq!(schedule.vested_amount(schedule.end), total);
+    }
+}
@@ -2,10 +2,12 @@
 
 use std::str::FromStr;
 use anchor_lang::Space;
+use anchor_spl::{token::ID as TOKEN, token_2022::ID as TOKEN_2022};
 use solana_program::pubkey::Pubkey;
 use pda_token_vault::{
-    constants::*,
-    state::{VaultConfig, WhitelistEntry},
+    client::pda::*,
+    events::*,
+    state::{VaultAsset, VaultConfig, VaultRegistry, VestingSchedule, WhitelistEntry, WithdrawalTicket},
     instructions::create_mint::ExtensionArgs,
 };
 
@@ -13,21 +15,6 @@ fn program_id() -> Pubkey {
     Pubkey::from_str("6K7gP2L8j9hq1B3m4kC5n2D4f5E6a7b8c9d1e2F3G4H").unwrap()
 }
 
-fn derive_vault_config(mint: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(&[VAULT_CONFIG_SEED, mint.as_ref()], &program_id())
-}
-
-fn derive_vault(mint: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(&[VAULT_SEED, mint.as_ref()], &program_id())
-}
-
-fn derive_whitelist_entry(vault_config: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(
-        &[WHITELIST_ENTRY_SEED, vault_config.as_ref(), user.as_ref()],
-        &program_id(),
-    )
-}
-
 // =============================================================================
 // Part 1: PDA Whitelist Tests
 // =============================================================================
@@ -35,13 +22,13 @@ fn derive_whitelist_entry(vault_config: &Pubkey, user: &Pubkey) -> (Pubkey, u8)
 #[cfg(test)]
 mod test_pda_whitelist {
     use super::*;
-
+    
     #[test]
     fn whitelist_entry_space_is_correct() {
         let expected = 8 + WhitelistEntry::INIT_SPACE;
         assert!(expected < 100, "WhitelistEntry too large: {}", expected);
     }
-
+    
     #[test]
     fn pda_derivation_unique_per_user() {
         let vault_config = Pubkey::new_unique();
@@ -53,7 +40,7 @@ mod test_pda_whitelist {
         
         assert_ne!(pda1, pda2);
     }
-
+    
     #[test]
     fn pda_derivation_deterministic() {
         let vault_config = Pubkey::new_unique();
@@ -65,7 +52,7 @@ mod test_pda_whitelist {
         assert_eq!(pda1, pda2);
         assert_eq!(bump1, bump2);
     }
-
+    
     #[test]
     fn whitelist_entry_limit_check() {
         let entry_unlimited = WhitelistEntry {
@@ -86,6 +73,59 @@ mod test_pda_whitelist {
         assert!(entry_limited.is_within_limit(500_000_000));
         assert!(!entry_limited.is_within_limit(1_000_000_001));
     }
+    
+    #[test]
+    fn vault_asset_limit_check() {
+        let asset = VaultAsset {
+            multi_vault: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            token_program: Pubkey::new_unique(),
+            max_amount: 100,
+            vault_bump: 255,
+            bump: 255,
+        };
+        
+        assert!(asset.is_within_limit(100));
+        assert!(!asset.is_within_limit(101));
+        assert!(VaultAsset { max_amount: 0, ..asset }.is_within_limit(u64::MAX));
+    }
+    
+    #[test]
+    fn withdrawal_ticket_unlocks_at_its_time() {
+        let ticket = WithdrawalTicket {
+            vault_config: Pubkey::new_unique(),
+            user: Pubkey::new_unique(),
+            amount: 100,
+            unlock_time: 1_000,
+            bump: 255,
+        };
+        
+        assert!(!ticket.is_unlocked(999));
+        assert!(ticket.is_unlocked(1_000));
+    }
+    
+    #[test]
+    fn vesting_schedules_are_told_apart_by_id() {
+        let vault_config = Pubkey::new_unique();
+        let beneficiary = Pubkey::new_unique();
+        let mut schedule = VestingSchedule {
+            vault_config,
+            beneficiary,
+            schedule_id: 1,
+            start: 0,
+            cliff: 0,
+            end: 100,
+            total: 100,
+            released: 99,
+            bump: 255,
+        };
+        
+        let derive = |id| derive_vesting_schedule(&vault_config, &beneficiary, id).0;
+        assert_ne!(derive(0), derive(1));
+        assert!(!schedule.is_fully_released());
+        schedule.released = 100;
+        assert!(schedule.is_fully_released());
+    }
 }
 
 // =============================================================================
@@ -95,15 +135,17 @@ mod test_pda_whitelist {
 #[cfg(test)]
 mod test_extension_args {
     use super::*;
-
+    
     #[test]
     fn default_extension_args_disabled() {
         let args = ExtensionArgs::default();
         
         assert!(!args.enable_transfer_fee);
         assert!(!args.enable_permanent_delegate);
+        assert!(!args.enable_default_frozen);
+        assert!(args.metadata.is_none());
     }
-
+    
     #[test]
     fn extension_args_with_transfer_fee() {
         let args = ExtensionArgs {
@@ -111,6 +153,13 @@ mod test_extension_args {
             transfer_fee_basis_points: 100, // 1%
             max_fee: 1_000_000,
             enable_permanent_delegate: false,
+            enable_default_frozen: false,
+            enable_interest_bearing: false,
+            interest_rate: 0,
+            enable_mint_close_authority: false,
+            confidential_transfer: None,
+            group_max_size: None,
+            metadata: None,
         };
         
         assert!(args.enable_transfer_fee);
@@ -126,13 +175,72 @@ mod test_extension_args {
 #[cfg(test)]
 mod test_vault_config {
     use super::*;
-
+    
     #[test]
     fn vault_config_space_is_reasonable() {
         let expected = 8 + VaultConfig::INIT_SPACE;
-        assert!(expected < 150, "VaultConfig too large: {}", expected);
+        assert!(expected < 400, "VaultConfig too large: {}", expected);
     }
-
+    
+    #[test]
+    fn approval_applies_above_the_threshold_from_current_approvers() {
+        let (a, b, removed) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let mut config = VaultConfig {
+            authority: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            clawback_authority: Pubkey::new_unique(),
+            credential_mint: Pubkey::default(),
+            guardian: Pubkey::new_unique(),
+            withdrawal_delay: 0,
+            vesting_reserved: 0,
+            approvers: vec![a, b],
+            approval_quorum: 0,
+            approval_threshold: 100,
+            open_tickets: 0,
+            vesting_schedules: 0,
+            open_proposals: 0,
+            config_bump: 255,
+            vault_bump: 255,
+        };
+        
+        assert!(!config.requires_approval(u64::MAX), "quorum 0 disables approvals");
+        config.approval_quorum = 2;
+        assert!(!config.requires_approval(100));
+        assert!(config.requires_approval(101));
+        assert_eq!(config.counted_approvals(&[a, removed, b]), 2);
+    }
+    
+    #[test]
+    fn any_withdrawal_control_restricts_confidential_withdrawals() {
+        let mut config = VaultConfig {
+            authority: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            clawback_authority: Pubkey::new_unique(),
+            credential_mint: Pubkey::default(),
+            guardian: Pubkey::new_unique(),
+            withdrawal_delay: 0,
+            vesting_reserved: 0,
+            approvers: vec![],
+            approval_quorum: 0,
+            approval_threshold: 0,
+            open_tickets: 0,
+            vesting_schedules: 0,
+            open_proposals: 0,
+            config_bump: 255,
+            vault_bump: 255,
+        };
+        assert!(!config.has_withdrawal_controls());
+        
+        config.withdrawal_delay = 1;
+        assert!(config.has_withdrawal_controls());
+        config.withdrawal_delay = 0;
+        config.vesting_reserved = 1;
+        assert!(config.has_withdrawal_controls());
+        config.vesting_reserved = 0;
+        config.approval_quorum = 1;
+        assert!(config.has_withdrawal_controls());
+    }
+    
     #[test]
     fn all_pdas_unique_for_same_mint() {
         let mint = Pubkey::new_unique();
@@ -143,3 +251,309 @@ mod test_vault_config {
         assert_ne!(vault_config_pda, vault_pda);
     }
 }
+
+#[cfg(test)]
+mod test_events {
+    use super::*;
+    use anchor_lang::{AnchorDeserialize, Discriminator, Event};
+    
+    #[test]
+    fn withdrew_event_round_trips() {
+        let event = Withdrew {
+            vault_config: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            withdrawer: Pubkey::new_unique(),
+            amount: 42,
+            ui_amount: "0.000042".to_string(),
+            timestamp: 1_700_000_000,
+        };
+        let data = event.data();
+        
+        assert_eq!(&data[..8], Withdrew::DISCRIMINATOR);
+        let decoded = Withdrew::try_from_slice(&data[8..]).unwrap();
+        assert_eq!(decoded.withdrawer, event.withdrawer);
+        assert_eq!(decoded.amount, 42);
+        assert_eq!(decoded.ui_amount, "0.000042");
+    }
+    
+    #[test]
+    fn mint_created_records_enabled_extensions() {
+        let event = MintCreated {
+            mint: Pubkey::new_unique(),
+            authority: Pubkey::new_unique(),
+            decimals: 6,
+            transfer_fee_basis_points: Some(100),
+            permanent_delegate: false,
+            default_frozen: true,
+            interest_rate: Some(-50),
+            mint_close_authority: true,
+            confidential_transfer: true,
+            group_max_size: Some(4),
+            group: None,
+            timestamp: 0,
+        };
+        let decoded = MintCreated::try_from_slice(&event.data()[8..]).unwrap();
+        
+        assert_eq!(decoded.transfer_fee_basis_points, Some(100));
+        assert!(!decoded.permanent_delegate);
+        assert!(decoded.default_frozen);
+        assert_eq!(decoded.interest_rate, Some(-50));
+        assert!(decoded.mint_close_authority);
+        assert!(decoded.confidential_transfer);
+        assert_eq!(decoded.group_max_size, Some(4));
+        assert!(decoded.group.is_none());
+    }
+}
+
+#[cfg(test)]
+mod test_client {
+    use super::*;
+    use std::collections::HashMap;
+    use anchor_lang::{AccountSerialize, InstructionData};
+    use pda_token_vault::{client, instruction};
+    
+    #[test]
+    fn withdraw_builder_includes_signer_entry() {
+        let mint = Pubkey::new_unique();
+        let withdrawer = Pubkey::new_unique();
+        let ix = client::withdraw(&withdrawer, &mint, &Pubkey::new_unique(), 25, &anchor_spl::token::ID);
+        let vault_config = derive_vault_config(&mint).0;
+        
+        assert_eq!(ix.program_id, program_id());
+        assert_eq!(ix.data, instruction::Withdraw { amount: 25 }.data());
+        assert!(ix.accounts[0].is_signer && ix.accounts[0].pubkey == withdrawer);
+        assert_eq!(ix.accounts[3].pubkey, derive_whitelist_entry(&vault_config, &withdrawer).0);
+        assert!(ix.accounts.iter().any(|m| m.pubkey == derive_vault(&mint).0 && m.is_writable));
+        assert_eq!(ix.accounts.last().unwrap().pubkey, anchor_spl::token::ID);
+    }
+    
+    #[test]
+    fn multi_vault_accounts_are_per_mint() {
+        let authority = Pubkey::new_unique();
+        let multi_vault = derive_multi_vault(&authority, 7).0;
+        let (usdc, own) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let user = Pubkey::new_unique();
+        let ix = client::multi_vault_withdraw(&user, &multi_vault, &usdc, &Pubkey::new_unique(), 10, &anchor_spl::token::ID);
+        
+        assert_ne!(derive_multi_vault(&authority, 8).0, multi_vault);
+        assert_ne!(derive_vault_asset(&multi_vault, &usdc).0, derive_vault_asset(&multi_vault, &own).0);
+        assert_ne!(derive_multi_vault_account(&multi_vault, &usdc).0, derive_vault(&usdc).0);
+        assert_eq!(ix.accounts[3].pubkey, derive_vault_asset(&multi_vault, &usdc).0);
+        assert_eq!(ix.accounts[4].pubkey, derive_whitelist_entry(&multi_vault, &user).0);
+        assert!(ix.accounts[6].is_writable && ix.accounts[6].pubkey == derive_multi_vault_account(&multi_vault, &usdc).0);
+        assert_eq!(ix.accounts[7].pubkey, anchor_spl::token::ID);
+    }
+    
+    #[test]
+    fn veto_pays_the_ticket_rent_back_to_the_user() {
+        let (guardian, mint, user) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let ix = client::veto_withdrawal(&guardian, &mint, &user);
+        let ticket = derive_withdrawal_ticket(&derive_vault_config(&mint).0, &user).0;
+        
+        assert!(ix.accounts[0].is_signer);
+        assert!(ix.accounts[2].is_writable && ix.accounts[2].pubkey == user);
+        assert_eq!(ix.accounts[3].pubkey, ticket);
+        assert_eq!(client::request_withdrawal(&user, &mint, 5).accounts[3].pubkey, ticket);
+    }
+    
+    #[test]
+    fn create_mint_builder_encodes_extension_args() {
+        let args = ExtensionArgs { enable_transfer_fee: true, transfer_fee_basis_points: 50, ..Default::default() };
+        let ix = client::create_mint(&Pubkey::new_unique(), &Pubkey::new_unique(), 6, args.clone());
+        
+        assert_eq!(ix.data, instruction::CreateMint { decimals: 6, extension_args: args }.data());
+        assert!(ix.accounts[1].is_signer);
+    }
+    
+    #[test]
+    fn freeze_builder_signs_with_vault_config() {
+        let mint = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let token_account = Pubkey::new_unique();
+        let freeze = client::freeze_account(&authority, &mint, &token_account, &TOKEN_2022);
+        let thaw = client::thaw_account(&authority, &mint, &token_account, &TOKEN_2022);
+        
+        assert_eq!(freeze.data, instruction::FreezeAccount {}.data());
+        assert_eq!(thaw.data, instruction::ThawAccount {}.data());
+        for ix in [&freeze, &thaw] {
+            assert!(ix.accounts[0].is_signer && ix.accounts[0].pubkey == authority);
+            assert_eq!(ix.accounts[1].pubkey, derive_vault_config(&mint).0);
+            assert!(ix.accounts[3].is_writable && ix.accounts[3].pubkey == token_account);
+        }
+    }
+    
+    #[test]
+    fn ui_amount_of_plain_mint_uses_decimals() {
+        use anchor_lang::solana_program::program_pack::Pack;
+        use anchor_spl::token_2022::spl_token_2022::state::Mint;
+        let mint = Mint { decimals: 6, is_initialized: true, ..Default::default() };
+        let mut data = vec![0; Mint::LEN];
+        Mint::pack(mint, &mut data).unwrap();
+        
+        assert_eq!(client::decode_ui_amount(&data, 1_500_000, 0).unwrap(), "1.5");
+        assert_eq!(client::decode_interest_rate(&data).unwrap(), None);
+    }
+    
+    #[test]
+    fn credential_accounts_follow_the_credential_account() {
+        let mint = Pubkey::new_unique();
+        let user = Pubkey::new_unique();
+        let credential = Pubkey::new_unique();
+        let vault_config = derive_vault_config(&mint).0;
+        let add = client::add_to_whitelist(&Pubkey::new_unique(), &mint, &user, 0, None, Some(&credential), &TOKEN_2022);
+        let create = client::create_credential_mint(&Pubkey::new_unique(), &mint);
+        let credential_mint = derive_credential_mint(&vault_config).0;
+        
+        // Holder accounts stay omitted; the token program is shared
+        assert_eq!(add.accounts[4].pubkey, program_id());
+        assert_eq!(add.accounts[5].pubkey, program_id());
+        assert_eq!(add.accounts[6].pubkey, TOKEN_2022);
+        assert!(add.accounts[7].is_writable && add.accounts[7].pubkey == credential_mint);
+        assert!(add.accounts[8].is_writable && add.accounts[8].pubkey == credential);
+        assert_eq!(create.accounts[2].pubkey, mint);
+        assert_eq!(create.accounts[3].pubkey, credential_mint);
+        assert_ne!(credential_mint, derive_vault(&mint).0);
+    }
+    
+    #[test]
+    fn close_vault_builder_appends_entries() {
+        let mint = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let vault_config = derive_vault_config(&mint).0;
+        let entry = derive_whitelist_entry(&vault_config, &Pubkey::new_unique()).0;
+        let credential_mint = derive_credential_mint(&vault_config).0;
+        let without = client::close_vault(&authority, &mint, None, &[], &TOKEN_2022);
+        let with = client::close_vault(&authority, &mint, Some(&credential_mint), &[entry], &TOKEN_2022);
+        
+        assert!(with.accounts[0].is_signer && with.accounts[0].is_writable);
+        assert!(with.accounts[1].is_writable && with.accounts[2].is_writable);
+        assert_eq!(without.accounts[4].pubkey, program_id());
+        assert!(with.accounts[4].is_writable && with.accounts[4].pubkey == credential_mint);
+        assert_eq!(with.accounts.len(), without.accounts.len() + 1);
+        assert!(with.accounts[6].is_writable && with.accounts[6].pubkey == entry);
+    }
+    
+    #[test]
+    fn confidential_builders_locate_the_proof() {
+        let mint = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let context = Pubkey::new_unique();
+        let inline = client::configure_confidential_vault(&authority, &mint, [0; 36], 64, None);
+        let withdraw = client::confidential_withdraw(&authority, &mint, &Pubkey::new_unique(), &Pubkey::new_unique(), [0; 36], Some(&context));
+        
+        assert!(inline.accounts[0].is_signer && inline.accounts[0].is_writable);
+        assert!(inline.accounts[3].is_writable && inline.accounts[3].pubkey == derive_vault(&mint).0);
+        assert_eq!(inline.accounts[4].pubkey, anchor_lang::solana_program::sysvar::instructions::ID);
+        assert_eq!(*inline.data.last().unwrap(), 1);
+        assert_eq!(withdraw.accounts[6].pubkey, context);
+        assert_eq!(*withdraw.data.last().unwrap(), 0);
+    }
+    
+    #[test]
+    fn member_mint_builder_passes_group_accounts() {
+        let authority = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let group_mint = Pubkey::new_unique();
+        let standalone = client::create_mint(&authority, &mint, 6, ExtensionArgs::default());
+        let member = client::create_member_mint(&authority, &mint, &group_mint, 6, ExtensionArgs::default());
+        
+        assert_eq!(standalone.accounts[3].pubkey, program_id());
+        assert!(member.accounts[3].is_writable && member.accounts[3].pubkey == group_mint);
+        assert_eq!(member.accounts[4].pubkey, derive_vault_config(&group_mint).0);
+        assert!(member.accounts[5].is_writable && member.accounts[5].pubkey == derive_vault_registry(&group_mint).0);
+        assert_eq!(member.data, standalone.data);
+    }
+    
+    #[test]
+    fn update_interest_rate_builder_signs_with_authority() {
+        let mint = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let ix = client::update_interest_rate(&authority, &mint, -25);
+        
+        assert_eq!(ix.data, instruction::UpdateInterestRate { rate: -25 }.data());
+        assert!(ix.accounts[0].is_signer && ix.accounts[0].pubkey == authority);
+        assert_eq!(ix.accounts[1].pubkey, derive_vault_config(&mint).0);
+        assert!(ix.accounts[2].is_writable && ix.accounts[2].pubkey == mint);
+    }
+    
+    #[test]
+    fn metadata_builders_target_the_mint() {
+        use pda_token_vault::instructions::{MetadataField, TokenMetadataArgs};
+        let mint = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let args = ExtensionArgs { metadata: Some(TokenMetadataArgs::default()), ..Default::default() };
+        let create = client::create_mint(&authority, &mint, 6, args);
+        let update = client::update_token_metadata_field(&authority, &mint, MetadataField::Uri, "https://x".into());
+        let remove = client::remove_metadata_key(&authority, &mint, "region".into(), true);
+        
+        assert_eq!(create.accounts[2].pubkey, derive_vault_config(&mint).0);
+        assert_eq!(
+            remove.data,
+            instruction::RemoveMetadataKey { key: "region".into(), idempotent: true }.data()
+        );
+        for ix in [&update, &remove] {
+            assert!(ix.accounts[0].is_signer && ix.accounts[0].pubkey == authority);
+            assert_eq!(ix.accounts[1].pubkey, derive_vault_config(&mint).0);
+            assert!(ix.accounts[2].is_writable && ix.accounts[2].pubkey == mint);
+        }
+    }
+    
+    #[test]
+    fn whitelist_builders_pass_holder_accounts_together() {
+        let mint = Pubkey::new_unique();
+        let user = Pubkey::new_unique();
+        let holder = Pubkey::new_unique();
+        let without = client::add_to_whitelist(&Pubkey::new_unique(), &mint, &user, 0, None, None, &TOKEN_2022);
+        let with = client::remove_from_whitelist(&Pubkey::new_unique(), &mint, &user, Some(&holder), None, &TOKEN);
+        
+        // Anchor passes the program id in place of each omitted optional account
+        assert!(without.accounts[4..].iter().all(|m| m.pubkey == program_id()));
+        let keys: Vec<Pubkey> = with.accounts[3..].iter().map(|m| m.pubkey).collect();
+        assert_eq!(keys, vec![mint, holder, TOKEN, program_id(), program_id()]);
+        assert!(with.accounts[4].is_writable);
+    }
+    
+    #[test]
+    fn clawback_defaults_to_the_vault() {
+        let mint = Pubkey::new_unique();
+        let (source, recovery) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let into_vault = client::clawback(&Pubkey::new_unique(), &mint, &source, None, 10, 3);
+        let into_recovery = client::clawback(&Pubkey::new_unique(), &mint, &source, Some(&recovery), 10, 3);
+        
+        assert_eq!(into_vault.data, instruction::Clawback { amount: 10, reason_code: 3 }.data());
+        assert!(into_vault.accounts[4].is_writable && into_vault.accounts[4].pubkey == derive_vault(&mint).0);
+        assert_eq!(into_recovery.accounts[4].pubkey, recovery);
+        assert!(into_recovery.accounts[3].is_writable && into_recovery.accounts[3].pubkey == source);
+    }
+    
+    #[test]
+    fn fetch_entry_from_any_fetcher() {
+        let mint = Pubkey::new_unique();
+        let user = Pubkey::new_unique();
+        let vault_config = derive_vault_config(&mint).0;
+        let entry = WhitelistEntry { user, max_amount: 9, vault_config, bump: 0 };
+        let mut data = Vec::new();
+        entry.try_serialize(&mut data).unwrap();
+        
+        let mut bank: HashMap<Pubkey, Vec<u8>> = HashMap::new();
+        bank.insert(derive_whitelist_entry(&vault_config, &user).0, data);
+        
+        assert_eq!(client::fetch_whitelist_entry(&bank, &mint, &user).unwrap().unwrap().max_amount, 9);
+        assert!(client::is_whitelisted(&bank, &mint, &user).unwrap());
+        assert!(!client::is_whitelisted(&bank, &mint, &Pubkey::new_unique()).unwrap());
+        assert!(client::fetch_vault_config(&bank, &mint).unwrap().is_none());
+    }
+    
+    #[test]
+    fn registry_space_fits_its_members() {
+        let group_mint = Pubkey::new_unique();
+        let registry = VaultRegistry { group_mint, members: vec![Pubkey::new_unique(); 3], bump: 255 };
+        let mut data = Vec::new();
+        registry.try_serialize(&mut data).unwrap();
+        
+        assert_eq!(data.len(), VaultRegistry::space(3));
+        let mut bank: HashMap<Pubkey, Vec<u8>> = HashMap::new();
+        bank.insert(derive_vault_registry(&group_mint).0, data);
+        assert_eq!(client::fetch_vault_registry(&bank, &group_mint).unwrap().unwrap().members.len(), 3);
+    }
+}
@@ -0,0 +1,88 @@
+//! Throws arbitrary bytes at everything that decodes untrusted input:
+//! instruction dispatch, each instruction's arguments, the SPL Execute
+//! interface and the account decoders.
+//!
+//! Run from this directory with `cargo +nightly fuzz run instruction_data`.
+
+#![no_main]
+
+use anchor_lang::solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+use anchor_lang::{AnchorDeserialize, Discriminator, InstructionData};
+use libfuzzer_sys::fuzz_target;
+use spl_transfer_hook_interface::instruction::TransferHookInstruction;
+use transfer_hook_vault::{client::fetch, instruction, state::Whitelist};
+
+/// Drops `msg!` output so logging doesn't dominate each run
+struct QuietStubs;
+
+impl SyscallStubs for QuietStubs {
+    fn sol_log(&self, _message: &str) {}
+}
+
+/// Anything that decodes must re-encode to exactly the input
+macro_rules! round_trip {
+    ($data:expr, $($ix:ident),+ $(,)?) => {
+        $(
+            if let Some(args) = $data.strip_prefix(&instruction::$ix::DISCRIMINATOR[..]) {
+                if let Ok(ix) = instruction::$ix::try_from_slice(args) {
+                    assert_eq!(ix.data(), $data, concat!(stringify!($ix), " did not round-trip"));
+                }
+            }
+        )+
+    };
+}
+
+fuzz_target!(
+    init: {
+        set_syscall_stubs(Box::new(QuietStubs));
+    },
+    |data: &[u8]| {
+        // With no accounts, dispatch must fail cleanly whatever the data
+        let _ = transfer_hook_vault::entry(&transfer_hook_vault::ID, &[], data);
+
+        round_trip!(
+            data,
+            CreateMint,
+            Initialize,
+            InitializeExtraMetas,
+            MintTokens,
+            AddToWhitelist,
+            RemoveFromWhitelist,
+            ResizeWhitelist,
+            PdaWhitelistAdd,
+            PdaWhitelistRemove,
+            PdaWhitelistCheck,
+            SetMerkleRoot,
+            ClaimWhitelist,
+            CreateTier,
+            UpdateTier,
+            Deposit,
+            Withdraw,
+            TransferHook,
+        );
+
+        // Token-2022 and Anchor must agree on what an Execute carries
+        if let Ok(TransferHookInstruction::Execute { amount }) = TransferHookInstruction::unpack(data) {
+            let ix = instruction::TransferHook::deserialize(&mut &data[8..]).expect("Execute not routed to transfer_hook");
+            assert_eq!(ix.amount, amount);
+        }
+
+        let _ = fetch::decode_vault_config(data);
+        let _ = fetch::decode_whitelist_entry_pda(data);
+        let _ = fetch::decode_tier(data);
+        if let Ok((header, entries)) = fetch::decode_whitelist(data) {
+            assert_eq!(entries.len(), header.len as usize);
+        }
+
+        // The on-chain views need 8-byte alignment; copy into an aligned buffer
+        let mut aligned = vec![0u64; data.len().div_ceil(8)];
+        let bytes = &mut bytemuck::cast_slice_mut::<u64, u8>(&mut aligned)[..data.len()];
+        bytes.copy_from_slice(data);
+        if let Ok((header, entries)) = Whitelist::from_data(bytes) {
+            assert_eq!(entries.len(), header.len as usize);
+        }
+        if let Ok((header, slots)) = Whitelist::from_data_mut(bytes) {
+            assert_eq!(slots.len(), header.capacity as usize);
+        }
+    }
+);
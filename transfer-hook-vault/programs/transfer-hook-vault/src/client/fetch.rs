@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::client::pda::*;
+use crate::state::*;
+
+/// Anything that can return raw account data, e.g. an RPC client or a test bank
+pub trait AccountFetcher {
+    fn get_account_data(&self, address: &Pubkey) -> Option<Vec<u8>>;
+}
+
+impl AccountFetcher for HashMap<Pubkey, Vec<u8>> {
+    fn get_account_data(&self, address: &Pubkey) -> Option<Vec<u8>> {
+        self.get(address).cloned()
+    }
+}
+
+pub fn decode_vault_config(data: &[u8]) -> Result<VaultConfig> {
+    VaultConfig::try_deserialize(&mut &data[..])
+}
+
+pub fn decode_whitelist_entry_pda(data: &[u8]) -> Result<WhitelistEntryPda> {
+    WhitelistEntryPda::try_deserialize(&mut &data[..])
+}
+
+pub fn decode_tier(data: &[u8]) -> Result<Tier> {
+    Tier::try_deserialize(&mut &data[..])
+}
+
+/// Header and populated entries. Copies out of `data`, so unlike
+/// `Whitelist::from_data` it doesn't need an 8-byte aligned buffer.
+pub fn decode_whitelist(data: &[u8]) -> Result<(Whitelist, Vec<WhitelistEntry>)> {
+    require!(
+        data.len() >= Whitelist::ENTRIES_OFFSET && data[..8] == Whitelist::DISCRIMINATOR,
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+    let header: Whitelist = bytemuck::pod_read_unaligned(&data[8..Whitelist::ENTRIES_OFFSET]);
+    let entries: Vec<WhitelistEntry> = data[Whitelist::ENTRIES_OFFSET..]
+        .chunks_exact(Whitelist::ENTRY_LEN)
+        .take(header.len as usize)
+        .map(bytemuck::pod_read_unaligned)
+        .collect();
+    require!(entries.len() == header.len as usize, ErrorCode::AccountDidNotDeserialize);
+    Ok((header, entries))
+}
+
+pub fn fetch_vault_config(fetcher: &impl AccountFetcher, mint: &Pubkey) -> Result<Option<VaultConfig>> {
+    fetcher.get_account_data(&derive_vault_config(mint).0).map(|d| decode_vault_config(&d)).transpose()
+}
+
+pub fn fetch_whitelist(
+    fetcher: &impl AccountFetcher,
+    mint: &Pubkey,
+) -> Result<Option<(Whitelist, Vec<WhitelistEntry>)>> {
+    fetcher.get_account_data(&derive_whitelist(mint).0).map(|d| decode_whitelist(&d)).transpose()
+}
+
+pub fn fetch_whitelist_entry_pda(
+    fetcher: &impl AccountFetcher,
+    mint: &Pubkey,
+    user: &Pubkey,
+) -> Result<Option<WhitelistEntryPda>> {
+    let vault_config = derive_vault_config(mint).0;
+    fetcher
+        .get_account_data(&derive_whitelist_entry_pda(&vault_config, user).0)
+        .map(|d| decode_whitelist_entry_pda(&d))
+        .transpose()
+}
+
+pub fn fetch_tier(fetcher: &impl AccountFetcher, mint: &Pubkey, tier_id: u8) -> Result<Option<Tier>> {
+    let vault_config = derive_vault_config(mint).0;
+    fetcher.get_account_data(&derive_tier(&vault_config, tier_id).0).map(|d| decode_tier(&d)).transpose()
+}
+
+/// Whether the hook would find `user` in either the Vec or PDA whitelist.
+/// Merkle-only users aren't visible until they claim.
+pub fn is_whitelisted(fetcher: &impl AccountFetcher, mint: &Pubkey, user: &Pubkey) -> Result<bool> {
+    if let Some((_, entries)) = fetch_whitelist(fetcher, mint)? {
+        if Whitelist::get_entry(&entries, user).is_some() {
+            return Ok(true);
+        }
+    }
+    Ok(fetch_whitelist_entry_pda(fetcher, mint, user)?.is_some())
+}
@@ -0,0 +1,500 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{instruction::Instruction, program_error::ProgramError, system_program};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::token_2022::spl_token_2022;
+use crate::client::pda::*;
+use crate::constants::*;
+use crate::instructions::{MetadataField, TokenMetadataArgs};
+use crate::merkle::WhitelistProof;
+use crate::{accounts, instruction};
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+fn tier_account(mint: &Pubkey, tier_id: u8) -> Option<Pubkey> {
+    (tier_id != 0).then(|| derive_tier(&derive_vault_config(mint).0, tier_id).0)
+}
+
+// Setup
+
+pub fn create_mint(
+    authority: &Pubkey,
+    mint: &Pubkey,
+    decimals: u8,
+    default_frozen: bool,
+    metadata: Option<TokenMetadataArgs>,
+) -> Instruction {
+    build(
+        accounts::CreateMint {
+            authority: *authority,
+            mint: *mint,
+            vault_config: derive_vault_config(mint).0,
+            token_program: spl_token_2022::ID,
+            system_program: system_program::ID,
+        },
+        instruction::CreateMint { decimals, default_frozen, metadata },
+    )
+}
+
+pub fn initialize(authority: &Pubkey, mint: &Pubkey, capacity: u32) -> Instruction {
+    build(
+        accounts::Initialize {
+            authority: *authority,
+            mint: *mint,
+            vault_config: derive_vault_config(mint).0,
+            whitelist: derive_whitelist(mint).0,
+            vault: derive_vault(mint).0,
+            token_program: spl_token_2022::ID,
+            system_program: system_program::ID,
+        },
+        instruction::Initialize { capacity },
+    )
+}
+
+pub fn initialize_extra_metas(payer: &Pubkey, authority: &Pubkey, mint: &Pubkey) -> Instruction {
+    build(
+        accounts::InitializeExtraMetas {
+            payer: *payer,
+            vault_config: derive_vault_config(mint).0,
+            authority: *authority,
+            extra_account_metas: derive_extra_metas(mint).0,
+            mint: *mint,
+            whitelist: derive_whitelist(mint).0,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeExtraMetas {},
+    )
+}
+
+pub fn mint_tokens(authority: &Pubkey, mint: &Pubkey, destination: &Pubkey, amount: u64) -> Instruction {
+    build(
+        accounts::MintTokens {
+            authority: *authority,
+            vault_config: derive_vault_config(mint).0,
+            mint: *mint,
+            destination: *destination,
+            token_program: spl_token_2022::ID,
+        },
+        instruction::MintTokens { amount },
+    )
+}
+
+// Metadata
+
+pub fn update_token_metadata_field(authority: &Pubkey, mint: &Pubkey, field: MetadataField, value: String) -> Instruction {
+    build(
+        accounts::UpdateTokenMetadataField {
+            authority: *authority,
+            vault_config: derive_vault_config(mint).0,
+            mint: *mint,
+            token_program: spl_token_2022::ID,
+            system_program: system_program::ID,
+        },
+        instruction::UpdateTokenMetadataField { field, value },
+    )
+}
+
+pub fn remove_metadata_key(authority: &Pubkey, mint: &Pubkey, key: String, idempotent: bool) -> Instruction {
+    build(
+        accounts::RemoveMetadataKey {
+            authority: *authority,
+            vault_config: derive_vault_config(mint).0,
+            mint: *mint,
+            token_program: spl_token_2022::ID,
+        },
+        instruction::RemoveMetadataKey { key, idempotent },
+    )
+}
+
+// Compliance
+
+pub fn freeze_account(authority: &Pubkey, mint: &Pubkey, token_account: &Pubkey) -> Instruction {
+    build(
+        accounts::FreezeAccount {
+            authority: *authority,
+            vault_config: derive_vault_config(mint).0,
+            mint: *mint,
+            token_account: *token_account,
+            token_program: spl_token_2022::ID,
+        },
+        instruction::FreezeAccount {},
+    )
+}
+
+pub fn thaw_account(authority: &Pubkey, mint: &Pubkey, token_account: &Pubkey) -> Instruction {
+    build(
+        accounts::ThawAccount {
+            authority: *authority,
+            vault_config: derive_vault_config(mint).0,
+            mint: *mint,
+            token_account: *token_account,
+            token_program: spl_token_2022::ID,
+        },
+        instruction::ThawAccount {},
+    )
+}
+
+// Vec Whitelist
+
+pub fn add_to_whitelist(
+    authority: &Pubkey,
+    mint: &Pubkey,
+    user: &Pubkey,
+    max_amount: u64,
+    tier_id: u8,
+    holder_token_account: Option<&Pubkey>,
+) -> Instruction {
+    build(
+        accounts::AddToWhitelist {
+            authority: *authority,
+            vault_config: derive_vault_config(mint).0,
+            whitelist: derive_whitelist(mint).0,
+            mint: holder_token_account.map(|_| *mint),
+            holder_token_account: holder_token_account.copied(),
+            token_program: holder_token_account.map(|_| spl_token_2022::ID),
+        },
+        instruction::AddToWhitelist { user: *user, max_amount, tier_id },
+    )
+}
+
+pub fn remove_from_whitelist(
+    authority: &Pubkey,
+    mint: &Pubkey,
+    user: &Pubkey,
+    holder_token_account: Option<&Pubkey>,
+) -> Instruction {
+    build(
+        accounts::RemoveFromWhitelist {
+            authority: *authority,
+            vault_config: derive_vault_config(mint).0,
+            whitelist: derive_whitelist(mint).0,
+            mint: holder_token_account.map(|_| *mint),
+            holder_token_account: holder_token_account.copied(),
+            token_program: holder_token_account.map(|_| spl_token_2022::ID),
+        },
+        instruction::RemoveFromWhitelist { user: *user },
+    )
+}
+
+pub fn update_whitelist_entry(authority: &Pubkey, mint: &Pubkey, user: &Pubkey, max_amount: u64, tier_id: u8) -> Instruction {
+    build(
+        accounts::UpdateWhitelistEntry {
+            authority: *authority,
+            vault_config: derive_vault_config(mint).0,
+            whitelist: derive_whitelist(mint).0,
+        },
+        instruction::UpdateWhitelistEntry { user: *user, max_amount, tier_id },
+    )
+}
+
+pub fn resize_whitelist(authority: &Pubkey, mint: &Pubkey, capacity: u32) -> Instruction {
+    build(
+        accounts::ResizeWhitelist {
+            authority: *authority,
+            vault_config: derive_vault_config(mint).0,
+            whitelist: derive_whitelist(mint).0,
+            system_program: system_program::ID,
+        },
+        instruction::ResizeWhitelist { capacity },
+    )
+}
+
+/// Migrates a whitelist filled before entries were kept sorted
+pub fn sort_whitelist(authority: &Pubkey, mint: &Pubkey) -> Instruction {
+    build(
+        accounts::SortWhitelist {
+            authority: *authority,
+            vault_config: derive_vault_config(mint).0,
+            whitelist: derive_whitelist(mint).0,
+        },
+        instruction::SortWhitelist {},
+    )
+}
+
+// PDA Whitelist
+
+pub fn pda_whitelist_add(
+    authority: &Pubkey,
+    mint: &Pubkey,
+    user: &Pubkey,
+    max_amount: u64,
+    tier_id: u8,
+    holder_token_account: Option<&Pubkey>,
+) -> Instruction {
+    let vault_config = derive_vault_config(mint).0;
+    build(
+        accounts::PdaWhitelistAdd {
+            authority: *authority,
+            vault_config,
+            whitelist_entry: derive_whitelist_entry_pda(&vault_config, user).0,
+            system_program: system_program::ID,
+            mint: holder_token_account.map(|_| *mint),
+            holder_token_account: holder_token_account.copied(),
+            token_program: holder_token_account.map(|_| spl_token_2022::ID),
+        },
+        instruction::PdaWhitelistAdd { user: *user, max_amount, tier_id },
+    )
+}
+
+pub fn pda_whitelist_remove(
+    authority: &Pubkey,
+    mint: &Pubkey,
+    user: &Pubkey,
+    holder_token_account: Option<&Pubkey>,
+) -> Instruction {
+    let vault_config = derive_vault_config(mint).0;
+    build(
+        accounts::PdaWhitelistRemove {
+            authority: *authority,
+            vault_config,
+            whitelist_entry: derive_whitelist_entry_pda(&vault_config, user).0,
+            mint: holder_token_account.map(|_| *mint),
+            holder_token_account: holder_token_account.copied(),
+            token_program: holder_token_account.map(|_| spl_token_2022::ID),
+        },
+        instruction::PdaWhitelistRemove { user: *user },
+    )
+}
+
+pub fn pda_whitelist_update(authority: &Pubkey, mint: &Pubkey, user: &Pubkey, max_amount: u64, tier_id: u8) -> Instruction {
+    let vault_config = derive_vault_config(mint).0;
+    build(
+        accounts::PdaWhitelistUpdate {
+            authority: *authority,
+            vault_config,
+            whitelist_entry: derive_whitelist_entry_pda(&vault_config, user).0,
+        },
+        instruction::PdaWhitelistUpdate { user: *user, max_amount, tier_id },
+    )
+}
+
+pub fn pda_whitelist_check(mint: &Pubkey, user: &Pubkey) -> Instruction {
+    let vault_config = derive_vault_config(mint).0;
+    build(
+        accounts::PdaWhitelistCheck {
+            vault_config,
+            whitelist_entry: derive_whitelist_entry_pda(&vault_config, user).0,
+        },
+        instruction::PdaWhitelistCheck { user: *user },
+    )
+}
+
+// Merkle Whitelist
+
+pub fn set_merkle_root(authority: &Pubkey, mint: &Pubkey, merkle_root: [u8; 32]) -> Instruction {
+    build(
+        accounts::SetMerkleRoot {
+            authority: *authority,
+            vault_config: derive_vault_config(mint).0,
+        },
+        instruction::SetMerkleRoot { merkle_root },
+    )
+}
+
+pub fn claim_whitelist(user: &Pubkey, mint: &Pubkey, max_amount: u64, proof: Vec<[u8; 32]>) -> Instruction {
+    let vault_config = derive_vault_config(mint).0;
+    build(
+        accounts::ClaimWhitelist {
+            user: *user,
+            vault_config,
+            whitelist_entry: derive_whitelist_entry_pda(&vault_config, user).0,
+            system_program: system_program::ID,
+        },
+        instruction::ClaimWhitelist { max_amount, proof },
+    )
+}
+
+// Tiers
+
+pub fn create_tier(
+    authority: &Pubkey,
+    mint: &Pubkey,
+    tier_id: u8,
+    per_tx_limit: u64,
+    daily_limit: u64,
+    lifetime_limit: u64,
+) -> Instruction {
+    let vault_config = derive_vault_config(mint).0;
+    build(
+        accounts::CreateTier {
+            authority: *authority,
+            vault_config,
+            tier: derive_tier(&vault_config, tier_id).0,
+            system_program: system_program::ID,
+        },
+        instruction::CreateTier { tier_id, per_tx_limit, daily_limit, lifetime_limit },
+    )
+}
+
+pub fn update_tier(
+    authority: &Pubkey,
+    mint: &Pubkey,
+    tier_id: u8,
+    per_tx_limit: u64,
+    daily_limit: u64,
+    lifetime_limit: u64,
+) -> Instruction {
+    let vault_config = derive_vault_config(mint).0;
+    build(
+        accounts::UpdateTier {
+            authority: *authority,
+            vault_config,
+            tier: derive_tier(&vault_config, tier_id).0,
+        },
+        instruction::UpdateTier { tier_id, per_tx_limit, daily_limit, lifetime_limit },
+    )
+}
+
+// Vault Operations
+
+/// `tier_id` is the depositor's entry tier (0 for none); `proof` is only
+/// needed for Merkle-only users
+pub fn deposit(
+    depositor: &Pubkey,
+    mint: &Pubkey,
+    depositor_token_account: &Pubkey,
+    amount: u64,
+    tier_id: u8,
+    proof: Option<WhitelistProof>,
+) -> Instruction {
+    build(
+        accounts::Deposit {
+            depositor: *depositor,
+            vault_config: derive_vault_config(mint).0,
+            whitelist: derive_whitelist(mint).0,
+            tier: tier_account(mint, tier_id),
+            mint: *mint,
+            depositor_token_account: *depositor_token_account,
+            vault: derive_vault(mint).0,
+            token_program: spl_token_2022::ID,
+        },
+        instruction::Deposit { amount, proof },
+    )
+}
+
+pub fn withdraw(
+    withdrawer: &Pubkey,
+    mint: &Pubkey,
+    withdrawer_token_account: &Pubkey,
+    amount: u64,
+    tier_id: u8,
+    proof: Option<WhitelistProof>,
+) -> Instruction {
+    build(
+        accounts::Withdraw {
+            withdrawer: *withdrawer,
+            vault_config: derive_vault_config(mint).0,
+            whitelist: derive_whitelist(mint).0,
+            tier: tier_account(mint, tier_id),
+            mint: *mint,
+            vault: derive_vault(mint).0,
+            withdrawer_token_account: *withdrawer_token_account,
+            token_program: spl_token_2022::ID,
+        },
+        instruction::Withdraw { amount, proof },
+    )
+}
+
+// Teardown
+
+/// `closable` are the vault's remaining `WhitelistEntryPda` and `Tier`
+/// addresses; each is closed to `authority` along with the vault itself
+pub fn close_vault(authority: &Pubkey, mint: &Pubkey, closable: &[Pubkey]) -> Instruction {
+    let mut ix = build(
+        accounts::CloseVault {
+            authority: *authority,
+            vault_config: derive_vault_config(mint).0,
+            mint: *mint,
+            whitelist: derive_whitelist(mint).0,
+            extra_account_metas: derive_extra_metas(mint).0,
+            vault: derive_vault(mint).0,
+            token_program: spl_token_2022::ID,
+        },
+        instruction::CloseVault {},
+    );
+    ix.accounts.extend(closable.iter().map(|address| AccountMeta::new(*address, false)));
+    ix
+}
+
+// Transfer Hook
+
+/// Accounts Token-2022 resolves from the extra metas list, in list order
+pub fn hook_extra_account_metas(mint: &Pubkey, source_authority: &Pubkey) -> Vec<AccountMeta> {
+    let vault_config = derive_vault_config(mint).0;
+    let mut metas = vec![
+        AccountMeta::new_readonly(derive_vault(mint).0, false),
+        AccountMeta::new(derive_whitelist(mint).0, false),
+        AccountMeta::new_readonly(vault_config, false),
+        AccountMeta::new(derive_whitelist_entry_pda(&vault_config, source_authority).0, false),
+    ];
+    for tier_id in 1..=MAX_TIERS {
+        metas.push(AccountMeta::new_readonly(derive_tier(&vault_config, tier_id).0, false));
+    }
+    metas
+}
+
+/// What Token-2022 needs after the four `transfer_checked` accounts to run
+/// the hook: the resolved extras, the hook program, then the metas list.
+/// Also what a CPI caller forwards as remaining accounts.
+pub fn transfer_hook_accounts(mint: &Pubkey, authority: &Pubkey) -> Vec<AccountMeta> {
+    let mut metas = hook_extra_account_metas(mint, authority);
+    metas.push(AccountMeta::new_readonly(crate::ID, false));
+    metas.push(AccountMeta::new_readonly(derive_extra_metas(mint).0, false));
+    metas
+}
+
+/// Token-2022 `transfer_checked` with everything the hook needs appended,
+/// matching what `add_extra_account_metas_for_execute` would resolve
+pub fn transfer_checked_with_hook(
+    source: &Pubkey,
+    mint: &Pubkey,
+    destination: &Pubkey,
+    authority: &Pubkey,
+    amount: u64,
+    decimals: u8,
+) -> std::result::Result<Instruction, ProgramError> {
+    let mut ix = spl_token_2022::instruction::transfer_checked(
+        &spl_token_2022::ID,
+        source,
+        mint,
+        destination,
+        authority,
+        &[],
+        amount,
+        decimals,
+    )?;
+    ix.accounts.extend(transfer_hook_accounts(mint, authority));
+    Ok(ix)
+}
+
+/// Direct call to the hook entrypoint, mainly useful for simulation
+pub fn transfer_hook(
+    source: &Pubkey,
+    mint: &Pubkey,
+    destination: &Pubkey,
+    source_authority: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let vault_config = derive_vault_config(mint).0;
+    let mut ix = build(
+        accounts::TransferHook {
+            source: *source,
+            mint: *mint,
+            destination: *destination,
+            source_authority: *source_authority,
+            extra_metas: derive_extra_metas(mint).0,
+            vault: derive_vault(mint).0,
+            whitelist: derive_whitelist(mint).0,
+            vault_config,
+            whitelist_entry: derive_whitelist_entry_pda(&vault_config, source_authority).0,
+        },
+        instruction::TransferHook { amount },
+    );
+    ix.accounts.extend(hook_extra_account_metas(mint, source_authority).split_off(4));
+    ix
+}
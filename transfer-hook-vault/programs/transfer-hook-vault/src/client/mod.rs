@@ -0,0 +1,12 @@
+//! Off-chain helpers for building and inspecting vault transactions.
+//!
+//! `pda` derives every program address, `ix` builds an `Instruction` for each
+//! entrypoint, and `fetch` decodes account data from any `AccountFetcher`.
+
+pub mod fetch;
+pub mod ix;
+pub mod pda;
+
+pub use fetch::*;
+pub use ix::*;
+pub use pda::*;
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+pub fn derive_vault_config(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_CONFIG_SEED, mint.as_ref()], &crate::ID)
+}
+
+/// Vault token account, owned by itself
+pub fn derive_vault(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_SEED, mint.as_ref()], &crate::ID)
+}
+
+pub fn derive_whitelist(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[WHITELIST_SEED, mint.as_ref()], &crate::ID)
+}
+
+pub fn derive_extra_metas(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[EXTRA_METAS_SEED, mint.as_ref()], &crate::ID)
+}
+
+pub fn derive_whitelist_entry_pda(vault_config: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[WHITELIST_ENTRY_SEED, vault_config.as_ref(), user.as_ref()],
+        &crate::ID,
+    )
+}
+
+pub fn derive_tier(vault_config: &Pubkey, tier_id: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[TIER_SEED, vault_config.as_ref(), &[tier_id]], &crate::ID)
+}
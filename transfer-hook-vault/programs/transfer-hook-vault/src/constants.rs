@@ -3,4 +3,9 @@ pub const VAULT_SEED: &[u8] = b"vault";
 pub const WHITELIST_SEED: &[u8] = b"whitelist";
 pub const EXTRA_METAS_SEED: &[u8] = b"extra_metas";
 pub const WHITELIST_ENTRY_SEED: &[u8] = b"whitelist_entry";
+pub const TIER_SEED: &[u8] = b"tier";
+/// Largest capacity `initialize` will allocate; grow beyond it with `resize_whitelist`
 pub const MAX_WHITELIST_ENTRIES: usize = 100;
+/// Tier ids run 1..=MAX_TIERS; 0 means an entry uses only its own max_amount
+pub const MAX_TIERS: u8 = 4;
+pub const SECONDS_PER_DAY: i64 = 86_400;
@@ -25,4 +25,46 @@ pub enum VaultError {
     
     #[msg("Invalid extension")]
     InvalidExtension,
+    
+    #[msg("Invalid whitelist capacity")]
+    InvalidWhitelistCapacity,
+    
+    #[msg("Invalid Merkle proof")]
+    InvalidMerkleProof,
+    
+    #[msg("Invalid or missing tier")]
+    InvalidTier,
+    
+    #[msg("Daily limit exceeded")]
+    DailyLimitExceeded,
+    
+    #[msg("Lifetime limit exceeded")]
+    LifetimeLimitExceeded,
+    
+    #[msg("Mint, holder token account and token program must be passed together")]
+    IncompleteHolderAccounts,
+    
+    #[msg("Token account is not owned by the user")]
+    HolderMismatch,
+    
+    #[msg("Mint has no token metadata")]
+    MetadataNotInitialized,
+    
+    #[msg("Vault still holds tokens")]
+    VaultNotEmpty,
+    
+    #[msg("Account does not belong to this vault")]
+    ForeignAccount,
+    
+    #[msg("Whitelist growth exceeds the per-call realloc limit")]
+    ResizeTooLarge,
+    
+    #[msg("Whitelist lists a user more than once")]
+    DuplicateWhitelistEntry,
+    
+    #[msg("Transfer hook can only run inside a Token-2022 transfer")]
+    NotTransferring,
+    
+    #[msg("Only the mint authority can initialize a vault for this mint")]
+    NotMintAuthority,
 }
@@ -0,0 +1,174 @@
+use anchor_lang::prelude::*;
+use crate::instructions::MetadataField;
+
+#[event]
+pub struct MintCreated {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub decimals: u8,
+    pub default_frozen: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokensMinted {
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultInitialized {
+    pub vault_config: Pubkey,
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub whitelist_capacity: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted for Vec, PDA and Merkle-claimed entries alike
+#[event]
+pub struct WhitelistAdded {
+    pub vault_config: Pubkey,
+    pub user: Pubkey,
+    pub max_amount: u64,
+    pub tier_id: u8,
+    pub pda_entry: bool,
+    pub timestamp: i64,
+}
+
+/// A limit or tier change that keeps the entry's usage counters
+#[event]
+pub struct WhitelistUpdated {
+    pub vault_config: Pubkey,
+    pub user: Pubkey,
+    pub max_amount: u64,
+    pub tier_id: u8,
+    pub pda_entry: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WhitelistRemoved {
+    pub vault_config: Pubkey,
+    pub user: Pubkey,
+    pub pda_entry: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WhitelistResized {
+    pub vault_config: Pubkey,
+    pub old_capacity: u32,
+    pub new_capacity: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WhitelistSorted {
+    pub vault_config: Pubkey,
+    pub entries: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MerkleRootUpdated {
+    pub vault_config: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub timestamp: i64,
+}
+
+/// Emitted by both create_tier and update_tier
+#[event]
+pub struct TierUpdated {
+    pub vault_config: Pubkey,
+    pub tier_id: u8,
+    pub per_tx_limit: u64,
+    pub daily_limit: u64,
+    pub lifetime_limit: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct Deposited {
+    pub vault_config: Pubkey,
+    pub mint: Pubkey,
+    pub depositor: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct Withdrew {
+    pub vault_config: Pubkey,
+    pub mint: Pubkey,
+    pub withdrawer: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TransferValidated {
+    pub mint: Pubkey,
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    pub source_authority: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// Logged before the hook fails; only visible in the failed transaction's logs
+#[event]
+pub struct TransferRejected {
+    pub mint: Pubkey,
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    pub source_authority: Pubkey,
+    pub amount: u64,
+    pub error_code: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountFrozen {
+    pub vault_config: Pubkey,
+    pub token_account: Pubkey,
+    pub owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountThawed {
+    pub vault_config: Pubkey,
+    pub token_account: Pubkey,
+    pub owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokenMetadataUpdated {
+    pub vault_config: Pubkey,
+    pub mint: Pubkey,
+    pub field: MetadataField,
+    pub value: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokenMetadataKeyRemoved {
+    pub vault_config: Pubkey,
+    pub mint: Pubkey,
+    pub key: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultClosed {
+    pub vault_config: Pubkey,
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    /// Entry and tier accounts closed alongside the vault
+    pub accounts_closed: u32,
+    pub timestamp: i64,
+}
@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use crate::constants::*;
 use crate::error::VaultError;
+use crate::events::*;
+use crate::instructions::holder::sync_holder_frozen;
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -21,17 +24,51 @@ pub struct AddToWhitelist<'info> {
         seeds = [WHITELIST_SEED, vault_config.mint.as_ref()],
         bump = vault_config.whitelist_bump,
     )]
-    pub whitelist: Account<'info, Whitelist>,
+    pub whitelist: AccountLoader<'info, Whitelist>,
+    
+    /// Optional with `holder_token_account` and `token_program`: the user's
+    /// token account is thawed via the vault config's freeze authority
+    #[account(address = vault_config.mint @ VaultError::InvalidMint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        mut,
+        constraint = holder_token_account.mint == vault_config.mint @ VaultError::InvalidMint,
+        constraint = holder_token_account.owner == user @ VaultError::HolderMismatch,
+    )]
+    pub holder_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub token_program: Option<Interface<'info, TokenInterface>>,
 }
 
-pub fn handler(ctx: Context<AddToWhitelist>, user: Pubkey, max_amount: u64) -> Result<()> {
-    let whitelist = &mut ctx.accounts.whitelist;
+pub(crate) fn handler(ctx: Context<AddToWhitelist>, user: Pubkey, max_amount: u64, tier_id: u8) -> Result<()> {
+    require!(tier_id <= MAX_TIERS, VaultError::InvalidTier);
+    
+    let whitelist_info = ctx.accounts.whitelist.to_account_info();
+    let mut data = whitelist_info.try_borrow_mut_data()?;
+    let (whitelist, slots) = Whitelist::from_data_mut(&mut data)?;
     
     require!(whitelist.can_add_entry(), VaultError::WhitelistFull);
-    require!(!whitelist.is_whitelisted(&user), VaultError::AlreadyWhitelisted);
+    require!(
+        whitelist.insert_entry(slots, WhitelistEntry::new(user, max_amount, tier_id)),
+        VaultError::AlreadyWhitelisted
+    );
     
-    whitelist.entries.push(WhitelistEntry { user, max_amount });
+    sync_holder_frozen(
+        false,
+        &ctx.accounts.vault_config,
+        ctx.accounts.mint.as_ref(),
+        ctx.accounts.holder_token_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    )?;
     
-    msg!("Added {} to whitelist (max: {})", user, max_amount);
+    emit!(WhitelistAdded {
+        vault_config: ctx.accounts.vault_config.key(),
+        user,
+        max_amount,
+        tier_id,
+        pda_entry: false,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
     Ok(())
 }
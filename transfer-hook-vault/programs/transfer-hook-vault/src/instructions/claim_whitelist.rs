@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
+
+/// Materialize the signer's `WhitelistEntryPda` from a Merkle proof, so the
+/// transfer hook can find it without a proof in the transfer instruction.
+#[derive(Accounts)]
+pub struct ClaimWhitelist<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(
+        seeds = [VAULT_CONFIG_SEED, vault_config.mint.as_ref()],
+        bump = vault_config.config_bump,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    #[account(
+        init,
+        payer = user,
+        space = 8 + WhitelistEntryPda::INIT_SPACE,
+        seeds = [WHITELIST_ENTRY_SEED, vault_config.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub whitelist_entry: Account<'info, WhitelistEntryPda>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<ClaimWhitelist>, max_amount: u64, proof: Vec<[u8; 32]>) -> Result<()> {
+    let user = ctx.accounts.user.key();
+    
+    require!(
+        ctx.accounts.vault_config.verify_whitelist_proof(&user, max_amount, &proof),
+        VaultError::InvalidMerkleProof
+    );
+    
+    let whitelist_entry = &mut ctx.accounts.whitelist_entry;
+    whitelist_entry.user = user;
+    whitelist_entry.max_amount = max_amount;
+    whitelist_entry.vault_config = ctx.accounts.vault_config.key();
+    whitelist_entry.bump = ctx.bumps.whitelist_entry;
+    whitelist_entry.tier_id = 0;
+    whitelist_entry.usage = TierUsage::default();
+    
+    emit!(WhitelistAdded {
+        vault_config: whitelist_entry.vault_config,
+        user,
+        max_amount,
+        tier_id: 0,
+        pda_entry: true,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
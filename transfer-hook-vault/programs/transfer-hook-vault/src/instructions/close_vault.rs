@@ -0,0 +1,218 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{program::invoke_signed, program_option::COption};
+use anchor_lang::system_program;
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::{
+        confidential_transfer::ConfidentialTransferMint,
+        confidential_transfer_fee::ConfidentialTransferFeeConfig,
+        group_member_pointer::GroupMemberPointer,
+        group_pointer::GroupPointer,
+        interest_bearing_mint::InterestBearingConfig,
+        metadata_pointer::MetadataPointer,
+        mint_close_authority::MintCloseAuthority,
+        permanent_delegate::PermanentDelegate,
+        transfer_fee::TransferFeeConfig,
+        transfer_hook::TransferHook,
+        BaseStateWithExtensions,
+        StateWithExtensions,
+    },
+    instruction::AuthorityType,
+    state::Mint as MintState,
+};
+use anchor_spl::token_interface::{self, Mint, SetAuthority, TokenAccount, TokenInterface};
+use spl_pod::optional_keys::OptionalNonZeroPubkey;
+use spl_token_group_interface::{instruction::update_group_authority, state::TokenGroup};
+use spl_token_metadata_interface::{instruction::update_authority, state::TokenMetadata};
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
+
+/// Decommission an empty vault, returning the rent of every account the
+/// program created for it to the authority. `WhitelistEntryPda` and `Tier`
+/// accounts go in `remaining_accounts`; any left behind can still be closed
+/// by `pda_whitelist_remove` until this runs, and are stranded after.
+///
+/// Transfers of the mint fail once the vault config and extra metas are gone,
+/// so only close a vault whose tokens are no longer meant to move. Every
+/// authority the vault config holds over the mint, such as freezing and
+/// metadata updates, passes to the authority, so whoever initializes a vault
+/// for the mint again doesn't inherit them.
+#[derive(Accounts)]
+pub struct CloseVault<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        close = authority,
+        seeds = [VAULT_CONFIG_SEED, mint.key().as_ref()],
+        bump = vault_config.config_bump,
+        has_one = authority @ VaultError::UnauthorizedAuthority,
+        has_one = mint @ VaultError::InvalidMint,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        close = authority,
+        seeds = [WHITELIST_SEED, mint.key().as_ref()],
+        bump = vault_config.whitelist_bump,
+    )]
+    pub whitelist: AccountLoader<'info, Whitelist>,
+    
+    /// CHECK: Closed if `initialize_extra_metas` created it
+    #[account(mut, seeds = [EXTRA_METAS_SEED, mint.key().as_ref()], bump)]
+    pub extra_account_metas: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, mint.key().as_ref()],
+        bump = vault_config.vault_bump,
+        constraint = vault.amount == 0 @ VaultError::VaultNotEmpty,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, '_, 'info, CloseVault<'info>>) -> Result<()> {
+    let authority = ctx.accounts.authority.to_account_info();
+    let vault_config_key = ctx.accounts.vault_config.key();
+    let mint_key = ctx.accounts.mint.key();
+    
+    let vault_seeds = &[VAULT_SEED, mint_key.as_ref(), &[ctx.accounts.vault_config.vault_bump]];
+    token_interface::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token_interface::CloseAccount {
+            account: ctx.accounts.vault.to_account_info(),
+            destination: authority.clone(),
+            authority: ctx.accounts.vault.to_account_info(),
+        },
+        &[&vault_seeds[..]],
+    ))?;
+    
+    let extra_metas = ctx.accounts.extra_account_metas.to_account_info();
+    if *extra_metas.owner == crate::ID {
+        close_program_account(&extra_metas, &authority)?;
+    }
+    
+    for info in ctx.remaining_accounts {
+        require!(*info.owner == crate::ID, VaultError::ForeignAccount);
+        let belongs = {
+            let data = info.try_borrow_data()?;
+            if let Ok(entry) = WhitelistEntryPda::try_deserialize(&mut &data[..]) {
+                entry.vault_config == vault_config_key
+            } else if let Ok(tier) = Tier::try_deserialize(&mut &data[..]) {
+                tier.vault_config == vault_config_key
+            } else {
+                false
+            }
+        };
+        require!(belongs, VaultError::ForeignAccount);
+        close_program_account(info, &authority)?;
+    }
+    
+    let config_seeds = &[VAULT_CONFIG_SEED, mint_key.as_ref(), &[ctx.accounts.vault_config.config_bump]];
+    release_mint_authorities(&ctx, &config_seeds[..])?;
+    
+    emit!(VaultClosed {
+        vault_config: vault_config_key,
+        mint: mint_key,
+        authority: authority.key(),
+        accounts_closed: ctx.remaining_accounts.len() as u32,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+/// The authorities over `mint` that `vault_config` holds: those moved by
+/// `set_authority`, and whether it updates the token metadata and group
+fn held_authorities(mint: &AccountInfo, vault_config: &Pubkey) -> Result<(Vec<AuthorityType>, bool, bool)> {
+    let data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<MintState>::unpack(&data)?;
+    let held = |authority: OptionalNonZeroPubkey| Option::<Pubkey>::from(authority) == Some(*vault_config);
+    
+    let mut authority_types = Vec::new();
+    if mint.base.mint_authority == COption::Some(*vault_config) {
+        authority_types.push(AuthorityType::MintTokens);
+    }
+    if mint.base.freeze_authority == COption::Some(*vault_config) {
+        authority_types.push(AuthorityType::FreezeAccount);
+    }
+    let extensions = [
+        (AuthorityType::CloseMint, mint.get_extension::<MintCloseAuthority>().map(|e| e.close_authority)),
+        (AuthorityType::PermanentDelegate, mint.get_extension::<PermanentDelegate>().map(|e| e.delegate)),
+        (AuthorityType::TransferFeeConfig, mint.get_extension::<TransferFeeConfig>().map(|e| e.transfer_fee_config_authority)),
+        (AuthorityType::WithheldWithdraw, mint.get_extension::<TransferFeeConfig>().map(|e| e.withdraw_withheld_authority)),
+        (AuthorityType::InterestRate, mint.get_extension::<InterestBearingConfig>().map(|e| e.rate_authority)),
+        (AuthorityType::ConfidentialTransferMint, mint.get_extension::<ConfidentialTransferMint>().map(|e| e.authority)),
+        (
+            AuthorityType::ConfidentialTransferFeeConfig,
+            mint.get_extension::<ConfidentialTransferFeeConfig>().map(|e| e.authority),
+        ),
+        (AuthorityType::TransferHookProgramId, mint.get_extension::<TransferHook>().map(|e| e.authority)),
+        (AuthorityType::MetadataPointer, mint.get_extension::<MetadataPointer>().map(|e| e.authority)),
+        (AuthorityType::GroupPointer, mint.get_extension::<GroupPointer>().map(|e| e.authority)),
+        (AuthorityType::GroupMemberPointer, mint.get_extension::<GroupMemberPointer>().map(|e| e.authority)),
+    ];
+    authority_types.extend(
+        extensions
+            .into_iter()
+            .filter(|(_, authority)| authority.as_ref().is_ok_and(|authority| held(*authority)))
+            .map(|(authority_type, _)| authority_type),
+    );
+    
+    let metadata = mint.get_variable_len_extension::<TokenMetadata>().is_ok_and(|m| held(m.update_authority));
+    let group = mint.get_extension::<TokenGroup>().is_ok_and(|group| held(group.update_authority));
+    Ok((authority_types, metadata, group))
+}
+
+/// Hand every authority the vault config holds over the mint to the vault
+/// authority
+fn release_mint_authorities<'info>(ctx: &Context<'_, '_, '_, 'info, CloseVault<'info>>, config_seeds: &[&[u8]]) -> Result<()> {
+    let mint = ctx.accounts.mint.to_account_info();
+    let vault_config = ctx.accounts.vault_config.to_account_info();
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let authority = ctx.accounts.authority.key();
+    let (authority_types, metadata, group) = held_authorities(&mint, vault_config.key)?;
+    
+    for authority_type in authority_types {
+        token_interface::set_authority(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                SetAuthority { current_authority: vault_config.clone(), account_or_mint: mint.clone() },
+                &[config_seeds],
+            ),
+            authority_type,
+            Some(authority),
+        )?;
+    }
+    if metadata {
+        invoke_signed(
+            &update_authority(token_program.key, mint.key, vault_config.key, Some(authority).try_into()?),
+            &[mint.clone(), vault_config.clone()],
+            &[config_seeds],
+        )?;
+    }
+    if group {
+        invoke_signed(
+            &update_group_authority(token_program.key, mint.key, vault_config.key, Some(authority)),
+            &[mint, vault_config],
+            &[config_seeds],
+        )?;
+    }
+    Ok(())
+}
+
+/// What Anchor's `close` constraint does, for accounts not in the struct
+fn close_program_account<'info>(info: &AccountInfo<'info>, destination: &AccountInfo<'info>) -> Result<()> {
+    **destination.try_borrow_mut_lamports()? += info.lamports();
+    **info.try_borrow_mut_lamports()? = 0;
+    info.assign(&system_program::ID);
+    info.realloc(0, false)?;
+    Ok(())
+}
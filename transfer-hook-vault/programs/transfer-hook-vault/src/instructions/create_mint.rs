@@ -2,10 +2,26 @@ use anchor_lang::prelude::*;
 use anchor_lang::solana_program::{program::invoke, system_instruction};
 use anchor_spl::token_2022::spl_token_2022::{
     self,
-    extension::{transfer_hook::instruction::initialize as initialize_transfer_hook, ExtensionType},
+    extension::{
+        default_account_state::instruction::initialize_default_account_state,
+        metadata_pointer::instruction::initialize as initialize_metadata_pointer,
+        transfer_hook::instruction::initialize as initialize_transfer_hook,
+        ExtensionType,
+    },
     instruction::initialize_mint2,
-    state::Mint,
+    state::{AccountState, Mint},
 };
+use spl_token_metadata_interface::state::TokenMetadata;
+use crate::constants::*;
+use crate::events::*;
+
+/// Name, symbol and URI embedded in the mint via `TokenMetadata`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct TokenMetadataArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
 
 #[derive(Accounts)]
 pub struct CreateMint<'info> {
@@ -16,6 +32,11 @@ pub struct CreateMint<'info> {
     #[account(mut)]
     pub mint: Signer<'info>,
     
+    /// CHECK: Not created yet; its address becomes the freeze authority and
+    /// metadata update authority
+    #[account(seeds = [VAULT_CONFIG_SEED, mint.key().as_ref()], bump)]
+    pub vault_config: UncheckedAccount<'info>,
+    
     /// CHECK: Token 2022 program
     #[account(address = spl_token_2022::ID)]
     pub token_program: AccountInfo<'info>,
@@ -23,17 +44,43 @@ pub struct CreateMint<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<CreateMint>, decimals: u8) -> Result<()> {
+pub(crate) fn handler(
+    ctx: Context<CreateMint>,
+    decimals: u8,
+    default_frozen: bool,
+    metadata: Option<TokenMetadataArgs>,
+) -> Result<()> {
     let mint = &ctx.accounts.mint;
     let authority = &ctx.accounts.authority;
+    let vault_config = &ctx.accounts.vault_config;
     let system_program = &ctx.accounts.system_program;
     
-    let extensions = [ExtensionType::TransferHook];
+    let mut extensions = vec![ExtensionType::TransferHook];
+    if default_frozen {
+        extensions.push(ExtensionType::DefaultAccountState);
+    }
+    if metadata.is_some() {
+        extensions.push(ExtensionType::MetadataPointer);
+    }
     let space = ExtensionType::try_calculate_account_len::<Mint>(&extensions)
         .map_err(|_| error!(crate::error::VaultError::InvalidExtension))?;
     
+    // Token-2022 reallocs the mint when writing metadata but doesn't fund it,
+    // so pay rent for the metadata up front
+    let metadata_space = match &metadata {
+        Some(args) => TokenMetadata {
+            update_authority: Some(vault_config.key()).try_into()?,
+            mint: mint.key(),
+            name: args.name.clone(),
+            symbol: args.symbol.clone(),
+            uri: args.uri.clone(),
+            additional_metadata: vec![],
+        }
+        .tlv_size_of()?,
+        None => 0,
+    };
     let rent = Rent::get()?;
-    let lamports = rent.minimum_balance(space);
+    let lamports = rent.minimum_balance(space + metadata_space);
     
     invoke(
         &system_instruction::create_account(
@@ -47,11 +94,51 @@ pub fn handler(ctx: Context<CreateMint>, decimals: u8) -> Result<()> {
         &[mint.to_account_info()],
     )?;
     
+    // New accounts start frozen until whitelisting thaws them
+    if default_frozen {
+        invoke(
+            &initialize_default_account_state(&spl_token_2022::ID, mint.key, &AccountState::Frozen)?,
+            &[mint.to_account_info()],
+        )?;
+    }
+    
+    // The metadata lives in the mint itself
+    if metadata.is_some() {
+        invoke(
+            &initialize_metadata_pointer(&spl_token_2022::ID, mint.key, Some(vault_config.key()), Some(mint.key()))?,
+            &[mint.to_account_info()],
+        )?;
+    }
+    
+    // The vault config PDA holds the freeze authority so freezes go through
+    // the program's authority checks
     invoke(
-        &initialize_mint2(&spl_token_2022::ID, mint.key, &authority.key(), Some(&authority.key()), decimals)?,
+        &initialize_mint2(&spl_token_2022::ID, mint.key, &authority.key(), Some(vault_config.key), decimals)?,
         &[mint.to_account_info()],
     )?;
     
-    msg!("Mint created with Transfer Hook extension");
+    if let Some(args) = metadata {
+        invoke(
+            &spl_token_metadata_interface::instruction::initialize(
+                &spl_token_2022::ID,
+                mint.key,
+                vault_config.key,
+                mint.key,
+                authority.key,
+                args.name,
+                args.symbol,
+                args.uri,
+            ),
+            &[mint.to_account_info(), vault_config.to_account_info(), authority.to_account_info()],
+        )?;
+    }
+    
+    emit!(MintCreated {
+        mint: mint.key(),
+        authority: authority.key(),
+        decimals,
+        default_frozen,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
     Ok(())
 }
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(tier_id: u8)]
+pub struct CreateTier<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [VAULT_CONFIG_SEED, vault_config.mint.as_ref()],
+        bump = vault_config.config_bump,
+        has_one = authority @ VaultError::UnauthorizedAuthority,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Tier::INIT_SPACE,
+        seeds = [TIER_SEED, vault_config.key().as_ref(), &[tier_id]],
+        bump,
+    )]
+    pub tier: Account<'info, Tier>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<CreateTier>,
+    tier_id: u8,
+    per_tx_limit: u64,
+    daily_limit: u64,
+    lifetime_limit: u64,
+) -> Result<()> {
+    require!((1..=MAX_TIERS).contains(&tier_id), VaultError::InvalidTier);
+    
+    let tier = &mut ctx.accounts.tier;
+    tier.vault_config = ctx.accounts.vault_config.key();
+    tier.tier_id = tier_id;
+    tier.per_tx_limit = per_tx_limit;
+    tier.daily_limit = daily_limit;
+    tier.lifetime_limit = lifetime_limit;
+    tier.bump = ctx.bumps.tier;
+    
+    emit!(TierUpdated {
+        vault_config: ctx.accounts.vault_config.key(),
+        tier_id,
+        per_tx_limit,
+        daily_limit,
+        lifetime_limit,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
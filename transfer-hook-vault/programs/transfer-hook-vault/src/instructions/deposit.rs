@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, transfer_checked, TransferChecked};
+use anchor_spl::token_2022::spl_token_2022::onchain::invoke_transfer_checked;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use crate::constants::*;
 use crate::error::VaultError;
+use crate::events::*;
+use crate::merkle::WhitelistProof;
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -17,48 +20,81 @@ pub struct Deposit<'info> {
     pub vault_config: Account<'info, VaultConfig>,
     
     #[account(
+        mut,
         seeds = [WHITELIST_SEED, mint.key().as_ref()],
         bump = vault_config.whitelist_bump,
     )]
-    pub whitelist: Account<'info, Whitelist>,
+    pub whitelist: AccountLoader<'info, Whitelist>,
+    
+    /// Tier referenced by the signer's whitelist entry, if any
+    #[account(
+        seeds = [TIER_SEED, vault_config.key().as_ref(), &[tier.tier_id]],
+        bump = tier.bump,
+    )]
+    pub tier: Option<Account<'info, Tier>>,
     
     pub mint: InterfaceAccount<'info, Mint>,
     
     #[account(mut, token::mint = mint, token::authority = depositor)]
     pub depositor_token_account: InterfaceAccount<'info, TokenAccount>,
     
-    #[account(mut, token::mint = mint)]
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, mint.key().as_ref()],
+        bump = vault_config.vault_bump,
+        token::mint = mint,
+    )]
     pub vault: InterfaceAccount<'info, TokenAccount>,
     
     pub token_program: Interface<'info, TokenInterface>,
 }
 
-pub fn handler(ctx: Context<Deposit>, amount: u64) -> Result<()> {
-    let whitelist = &ctx.accounts.whitelist;
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, '_, 'info, Deposit<'info>>, amount: u64, proof: Option<WhitelistProof>) -> Result<()> {
     let depositor = ctx.accounts.depositor.key();
+    let whitelist_info = ctx.accounts.whitelist.to_account_info();
+    let now = Clock::get()?.unix_timestamp;
+    let day = now / SECONDS_PER_DAY;
     
-    require!(whitelist.is_whitelisted(&depositor), VaultError::NotWhitelisted);
-    
-    let entry = whitelist.get_entry(&depositor).unwrap();
-    require!(
-        entry.max_amount == 0 || amount <= entry.max_amount,
-        VaultError::AmountExceedsLimit
-    );
+    // Vec entries take precedence; otherwise fall back to a Merkle proof
+    {
+        let mut data = whitelist_info.try_borrow_mut_data()?;
+        let (whitelist, slots) = Whitelist::from_data_mut(&mut data)?;
+        match Whitelist::get_entry_mut(&mut slots[..whitelist.len as usize], &depositor) {
+            Some(entry) => entry.record_transfer(ctx.accounts.tier.as_deref(), amount, day)?,
+            None => {
+                let proof = proof.ok_or(VaultError::NotWhitelisted)?;
+                require!(
+                    ctx.accounts.vault_config.verify_whitelist_proof(&depositor, proof.max_amount, &proof.proof),
+                    VaultError::InvalidMerkleProof
+                );
+                require!(
+                    proof.max_amount == 0 || amount <= proof.max_amount,
+                    VaultError::AmountExceedsLimit
+                );
+            }
+        }
+    }
     
-    transfer_checked(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            TransferChecked {
-                from: ctx.accounts.depositor_token_account.to_account_info(),
-                mint: ctx.accounts.mint.to_account_info(),
-                to: ctx.accounts.vault.to_account_info(),
-                authority: ctx.accounts.depositor.to_account_info(),
-            },
-        ),
+    // Forward remaining accounts so mints with a transfer hook can resolve
+    // its extra accounts
+    invoke_transfer_checked(
+        ctx.accounts.token_program.key,
+        ctx.accounts.depositor_token_account.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.vault.to_account_info(),
+        ctx.accounts.depositor.to_account_info(),
+        ctx.remaining_accounts,
         amount,
         ctx.accounts.mint.decimals,
+        &[],
     )?;
     
-    msg!("Deposited {} tokens", amount);
+    emit!(Deposited {
+        vault_config: ctx.accounts.vault_config.key(),
+        mint: ctx.accounts.mint.key(),
+        depositor,
+        amount,
+        timestamp: now,
+    });
     Ok(())
 }
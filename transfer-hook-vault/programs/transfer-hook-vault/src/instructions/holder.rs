@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
+
+/// Brings a holder's token account in line with their whitelist status:
+/// thawed while whitelisted, frozen otherwise. All three accounts are
+/// optional on the whitelist instructions but must be passed together, and
+/// the vault config must be the mint's freeze authority.
+pub fn sync_holder_frozen<'info>(
+    frozen: bool,
+    vault_config: &Account<'info, VaultConfig>,
+    mint: Option<&InterfaceAccount<'info, Mint>>,
+    holder_token_account: Option<&InterfaceAccount<'info, TokenAccount>>,
+    token_program: Option<&Interface<'info, TokenInterface>>,
+) -> Result<()> {
+    let (mint, holder, token_program) = match (mint, holder_token_account, token_program) {
+        (None, None, None) => return Ok(()),
+        (Some(mint), Some(holder), Some(token_program)) => (mint, holder, token_program),
+        _ => return err!(VaultError::IncompleteHolderAccounts),
+    };
+    if holder.is_frozen() == frozen {
+        return Ok(());
+    }
+    
+    let mint_key = mint.key();
+    let seeds = &[VAULT_CONFIG_SEED, mint_key.as_ref(), &[vault_config.config_bump]];
+    let timestamp = Clock::get()?.unix_timestamp;
+    if frozen {
+        token_interface::freeze_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token_interface::FreezeAccount {
+                account: holder.to_account_info(),
+                mint: mint.to_account_info(),
+                authority: vault_config.to_account_info(),
+            },
+            &[&seeds[..]],
+        ))?;
+        emit!(AccountFrozen {
+            vault_config: vault_config.key(),
+            token_account: holder.key(),
+            owner: holder.owner,
+            timestamp,
+        });
+    } else {
+        token_interface::thaw_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token_interface::ThawAccount {
+                account: holder.to_account_info(),
+                mint: mint.to_account_info(),
+                authority: vault_config.to_account_info(),
+            },
+            &[&seeds[..]],
+        ))?;
+        emit!(AccountThawed {
+            vault_config: vault_config.key(),
+            token_account: holder.key(),
+            owner: holder.owner,
+            timestamp,
+        });
+    }
+    Ok(())
+}
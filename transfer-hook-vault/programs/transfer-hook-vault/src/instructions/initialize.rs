@@ -1,14 +1,29 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_lang::system_program::{allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer};
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+    state::{Account as TokenAccountState, Mint as MintState},
+};
+use anchor_spl::token_interface::{initialize_account3, thaw_account, InitializeAccount3, Mint, ThawAccount, TokenInterface};
 use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
 use crate::state::*;
 
 #[derive(Accounts)]
+#[instruction(capacity: u32)]
 pub struct Initialize<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
     
-    /// CHECK: Validated in init
-    pub mint: AccountInfo<'info>,
+    /// Only the mint authority may claim the mint's vault, which inherits
+    /// the freeze authority and every other power the mint grants the PDA
+    #[account(
+        mint::token_program = token_program,
+        constraint = mint.mint_authority == COption::Some(authority.key()) @ VaultError::NotMintAuthority,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
     
     #[account(
         init,
@@ -22,34 +37,126 @@ pub struct Initialize<'info> {
     #[account(
         init,
         payer = authority,
-        space = Whitelist::space(MAX_WHITELIST_ENTRIES),
+        space = Whitelist::space(capacity as usize),
         seeds = [WHITELIST_SEED, mint.key().as_ref()],
         bump,
     )]
-    pub whitelist: Account<'info, Whitelist>,
+    pub whitelist: AccountLoader<'info, Whitelist>,
+    
+    /// CHECK: Created in the handler as a token account that is its own authority
+    #[account(mut, seeds = [VAULT_SEED, mint.key().as_ref()], bump)]
+    pub vault: UncheckedAccount<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
     
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<Initialize>) -> Result<()> {
-    let vault_config = &mut ctx.accounts.vault_config;
-    let whitelist = &mut ctx.accounts.whitelist;
+pub(crate) fn handler(ctx: Context<Initialize>, capacity: u32) -> Result<()> {
+    require!(capacity as usize <= MAX_WHITELIST_ENTRIES, VaultError::InvalidWhitelistCapacity);
+    
+    create_vault(&ctx)?;
     
-    let (_, vault_bump) = Pubkey::find_program_address(
-        &[VAULT_SEED, ctx.accounts.mint.key().as_ref()],
-        ctx.program_id,
-    );
+    let vault_config = &mut ctx.accounts.vault_config;
+    let mut whitelist = ctx.accounts.whitelist.load_init()?;
     
     vault_config.authority = ctx.accounts.authority.key();
     vault_config.mint = ctx.accounts.mint.key();
     vault_config.config_bump = ctx.bumps.vault_config;
-    vault_config.vault_bump = vault_bump;
+    vault_config.vault_bump = ctx.bumps.vault;
     vault_config.whitelist_bump = ctx.bumps.whitelist;
     vault_config.extra_metas_bump = 0;
+    vault_config.merkle_root = [0; 32];
     
     whitelist.authority = ctx.accounts.authority.key();
-    whitelist.entries = Vec::new();
+    whitelist.capacity = capacity;
+    whitelist.len = 0;
+    
+    emit!(VaultInitialized {
+        vault_config: vault_config.key(),
+        authority: vault_config.authority,
+        mint: vault_config.mint,
+        whitelist_capacity: capacity,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+/// `init` can't make a token account its own authority, so create it by hand,
+/// sized for whatever account extensions the mint requires
+fn create_vault(ctx: &Context<Initialize>) -> Result<()> {
+    let mint = ctx.accounts.mint.to_account_info();
+    let space = if *mint.owner == anchor_spl::token_2022::ID {
+        let data = mint.try_borrow_data()?;
+        let state = StateWithExtensions::<MintState>::unpack(&data)?;
+        let extensions = ExtensionType::get_required_init_account_extensions(&state.get_extension_types()?);
+        ExtensionType::try_calculate_account_len::<TokenAccountState>(&extensions)?
+    } else {
+        anchor_spl::token::TokenAccount::LEN
+    };
+    
+    let mint_key = mint.key();
+    let seeds: &[&[u8]] = &[VAULT_SEED, mint_key.as_ref(), &[ctx.bumps.vault]];
+    create_vault_account(ctx, space, seeds)?;
     
-    msg!("Vault initialized for mint: {}", ctx.accounts.mint.key());
+    initialize_account3(CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        InitializeAccount3 {
+            account: ctx.accounts.vault.to_account_info(),
+            mint: mint.clone(),
+            authority: ctx.accounts.vault.to_account_info(),
+        },
+    ))?;
+    
+    // Mints whose accounts default to frozen would leave the vault unusable
+    let vault_frozen = {
+        let data = ctx.accounts.vault.try_borrow_data()?;
+        StateWithExtensions::<TokenAccountState>::unpack(&data)?.base.is_frozen()
+    };
+    if vault_frozen {
+        let config_seeds: &[&[u8]] = &[VAULT_CONFIG_SEED, mint_key.as_ref(), &[ctx.bumps.vault_config]];
+        thaw_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            ThawAccount {
+                account: ctx.accounts.vault.to_account_info(),
+                mint,
+                authority: ctx.accounts.vault_config.to_account_info(),
+            },
+            &[config_seeds],
+        ))?;
+    }
     Ok(())
 }
+
+/// Like Anchor's `init`: `create_account` fails on an address that already
+/// holds lamports, and anyone can fund the vault PDA ahead of `initialize`,
+/// so a pre-funded vault is topped up to rent exemption, then allocated and
+/// assigned to the token program
+fn create_vault_account(ctx: &Context<Initialize>, space: usize, seeds: &[&[u8]]) -> Result<()> {
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let payer = ctx.accounts.authority.to_account_info();
+    let vault = ctx.accounts.vault.to_account_info();
+    let rent = Rent::get()?.minimum_balance(space);
+    let lamports = vault.lamports();
+    if lamports == 0 {
+        return create_account(
+            CpiContext::new_with_signer(system_program, CreateAccount { from: payer, to: vault }, &[seeds]),
+            rent,
+            space as u64,
+            ctx.accounts.token_program.key,
+        );
+    }
+    
+    let top_up = rent.saturating_sub(lamports);
+    if top_up > 0 {
+        transfer(CpiContext::new(system_program.clone(), Transfer { from: payer, to: vault.clone() }), top_up)?;
+    }
+    allocate(
+        CpiContext::new_with_signer(system_program.clone(), Allocate { account_to_allocate: vault.clone() }, &[seeds]),
+        space as u64,
+    )?;
+    assign(
+        CpiContext::new_with_signer(system_program, Assign { account_to_assign: vault }, &[seeds]),
+        ctx.accounts.token_program.key,
+    )
+}
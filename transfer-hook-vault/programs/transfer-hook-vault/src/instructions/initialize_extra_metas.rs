@@ -37,7 +37,7 @@ pub struct InitializeExtraMetas<'info> {
     #[account(
         init,
         payer = payer,
-        space = ExtraAccountMetaList::size_of(1).unwrap(),
+        space = ExtraAccountMetaList::size_of(4 + MAX_TIERS as usize).unwrap(),
         seeds = [EXTRA_METAS_SEED, mint.key().as_ref()],
         bump,
     )]
@@ -51,7 +51,7 @@ pub struct InitializeExtraMetas<'info> {
         seeds = [WHITELIST_SEED, mint.key().as_ref()],
         bump = vault_config.whitelist_bump,
     )]
-    pub whitelist: Account<'info, Whitelist>,
+    pub whitelist: AccountLoader<'info, Whitelist>,
     
     /// System program
     pub system_program: Program<'info, System>,
@@ -61,21 +61,66 @@ pub struct InitializeExtraMetas<'info> {
 /// 
 /// The Transfer Hook spec requires us to declare which additional accounts
 /// our `transfer_hook` instruction needs.
-pub fn handler(ctx: Context<InitializeExtraMetas>) -> Result<()> {
+pub(crate) fn handler(ctx: Context<InitializeExtraMetas>) -> Result<()> {
     msg!("Initializing extra account meta list for transfer hook");
     
-    // Define the extra accounts our transfer_hook instruction needs
-    let account_metas = vec![
+    // Define the extra accounts our transfer_hook instruction needs.
+    // Indices 0-4 are source, mint, destination, owner and this list.
+    // The whitelist and entry PDA are writable so the hook can record tier
+    // usage, which serializes transfers of this mint on the whitelist account.
+    let mut account_metas = vec![
+        // 5: vault token account
         ExtraAccountMeta::new_with_seeds(
             &[
-                Seed::Literal { bytes: WHITELIST_SEED.to_vec() },
-                Seed::AccountKey { index: 1 }, // Index 1 is the mint
+                Seed::Literal { bytes: VAULT_SEED.to_vec() },
+                Seed::AccountKey { index: 1 },
             ],
             false, // is_signer
             false, // is_writable
         )?,
+        // 6: Vec whitelist
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal { bytes: WHITELIST_SEED.to_vec() },
+                Seed::AccountKey { index: 1 },
+            ],
+            false,
+            true,
+        )?,
+        // 7: vault config
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal { bytes: VAULT_CONFIG_SEED.to_vec() },
+                Seed::AccountKey { index: 1 },
+            ],
+            false,
+            false,
+        )?,
+        // 8: source owner's whitelist entry PDA
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal { bytes: WHITELIST_ENTRY_SEED.to_vec() },
+                Seed::AccountKey { index: 7 },
+                Seed::AccountKey { index: 3 },
+            ],
+            false,
+            true,
+        )?,
     ];
     
+    // 9..: one tier PDA per tier id, whether or not it has been created yet
+    for tier_id in 1..=MAX_TIERS {
+        account_metas.push(ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal { bytes: TIER_SEED.to_vec() },
+                Seed::AccountKey { index: 7 },
+                Seed::Literal { bytes: vec![tier_id] },
+            ],
+            false,
+            false,
+        )?);
+    }
+    
     // Initialize the extra account metas account
     let extra_metas = &ctx.accounts.extra_account_metas;
     let mut data = extra_metas.try_borrow_mut_data()?;
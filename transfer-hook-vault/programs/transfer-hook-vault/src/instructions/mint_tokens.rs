@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, mint_to, MintTo};
 use crate::constants::*;
 use crate::error::VaultError;
+use crate::events::*;
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -26,7 +27,7 @@ pub struct MintTokens<'info> {
     pub token_program: Interface<'info, TokenInterface>,
 }
 
-pub fn handler(ctx: Context<MintTokens>, amount: u64) -> Result<()> {
+pub(crate) fn handler(ctx: Context<MintTokens>, amount: u64) -> Result<()> {
     mint_to(
         CpiContext::new(
             ctx.accounts.token_program.to_account_info(),
@@ -39,6 +40,11 @@ pub fn handler(ctx: Context<MintTokens>, amount: u64) -> Result<()> {
         amount,
     )?;
     
-    msg!("Minted {} tokens", amount);
+    emit!(TokensMinted {
+        mint: ctx.accounts.mint.key(),
+        destination: ctx.accounts.destination.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
     Ok(())
 }
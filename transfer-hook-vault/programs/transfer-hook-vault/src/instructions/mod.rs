@@ -2,24 +2,51 @@ pub mod create_mint;
 pub mod initialize;
 pub mod initialize_extra_metas;
 pub mod mint_tokens;
+pub mod freeze_account;
+pub mod thaw_account;
+pub mod update_token_metadata_field;
+pub mod remove_metadata_key;
 pub mod add_to_whitelist;
 pub mod remove_from_whitelist;
+pub mod update_whitelist_entry;
+pub mod resize_whitelist;
+pub mod sort_whitelist;
 pub mod pda_whitelist_add;
 pub mod pda_whitelist_remove;
+pub mod pda_whitelist_update;
 pub mod pda_whitelist_check;
+pub mod set_merkle_root;
+pub mod claim_whitelist;
+pub mod create_tier;
+pub mod update_tier;
 pub mod deposit;
 pub mod withdraw;
 pub mod transfer_hook;
+pub mod close_vault;
+pub(crate) mod holder;
 
 pub use create_mint::*;
 pub use initialize::*;
 pub use initialize_extra_metas::*;
 pub use mint_tokens::*;
+pub use freeze_account::*;
+pub use thaw_account::*;
+pub use update_token_metadata_field::*;
+pub use remove_metadata_key::*;
 pub use add_to_whitelist::*;
 pub use remove_from_whitelist::*;
+pub use update_whitelist_entry::*;
+pub use resize_whitelist::*;
+pub use sort_whitelist::*;
 pub use pda_whitelist_add::*;
 pub use pda_whitelist_remove::*;
+pub use pda_whitelist_update::*;
 pub use pda_whitelist_check::*;
+pub use set_merkle_root::*;
+pub use claim_whitelist::*;
+pub use create_tier::*;
+pub use update_tier::*;
 pub use deposit::*;
 pub use withdraw::*;
 pub use transfer_hook::*;
+pub use close_vault::*;
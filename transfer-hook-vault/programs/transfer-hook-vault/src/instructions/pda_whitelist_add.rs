@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use crate::constants::*;
 use crate::error::VaultError;
+use crate::events::*;
+use crate::instructions::holder::sync_holder_frozen;
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -26,16 +29,49 @@ pub struct PdaWhitelistAdd<'info> {
     pub whitelist_entry: Account<'info, WhitelistEntryPda>,
     
     pub system_program: Program<'info, System>,
+    
+    /// Optional with `holder_token_account` and `token_program`: the user's
+    /// token account is thawed via the vault config's freeze authority
+    #[account(address = vault_config.mint @ VaultError::InvalidMint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        mut,
+        constraint = holder_token_account.mint == vault_config.mint @ VaultError::InvalidMint,
+        constraint = holder_token_account.owner == user @ VaultError::HolderMismatch,
+    )]
+    pub holder_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub token_program: Option<Interface<'info, TokenInterface>>,
 }
 
-pub fn handler(ctx: Context<PdaWhitelistAdd>, user: Pubkey, max_amount: u64) -> Result<()> {
+pub(crate) fn handler(ctx: Context<PdaWhitelistAdd>, user: Pubkey, max_amount: u64, tier_id: u8) -> Result<()> {
+    require!(tier_id <= MAX_TIERS, VaultError::InvalidTier);
+    
     let whitelist_entry = &mut ctx.accounts.whitelist_entry;
     
     whitelist_entry.user = user;
     whitelist_entry.max_amount = max_amount;
     whitelist_entry.vault_config = ctx.accounts.vault_config.key();
     whitelist_entry.bump = ctx.bumps.whitelist_entry;
+    whitelist_entry.tier_id = tier_id;
+    whitelist_entry.usage = TierUsage::default();
+    
+    sync_holder_frozen(
+        false,
+        &ctx.accounts.vault_config,
+        ctx.accounts.mint.as_ref(),
+        ctx.accounts.holder_token_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    )?;
     
-    msg!("Added {} to PDA whitelist", user);
+    emit!(WhitelistAdded {
+        vault_config: whitelist_entry.vault_config,
+        user,
+        max_amount,
+        tier_id,
+        pda_entry: true,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
     Ok(())
 }
@@ -18,7 +18,7 @@ pub struct PdaWhitelistCheck<'info> {
     pub whitelist_entry: Account<'info, WhitelistEntryPda>,
 }
 
-pub fn handler(ctx: Context<PdaWhitelistCheck>, _user: Pubkey) -> Result<()> {
+pub(crate) fn handler(ctx: Context<PdaWhitelistCheck>, _user: Pubkey) -> Result<()> {
     let entry = &ctx.accounts.whitelist_entry;
     msg!("User {} whitelisted, max: {}", entry.user, entry.max_amount);
     Ok(())
@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use crate::constants::*;
 use crate::error::VaultError;
+use crate::events::*;
+use crate::instructions::holder::sync_holder_frozen;
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -24,9 +27,36 @@ pub struct PdaWhitelistRemove<'info> {
         constraint = whitelist_entry.user == user @ VaultError::NotWhitelisted,
     )]
     pub whitelist_entry: Account<'info, WhitelistEntryPda>,
+    
+    /// Optional with `holder_token_account` and `token_program`: the user's
+    /// token account is frozen via the vault config's freeze authority
+    #[account(address = vault_config.mint @ VaultError::InvalidMint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        mut,
+        constraint = holder_token_account.mint == vault_config.mint @ VaultError::InvalidMint,
+        constraint = holder_token_account.owner == user @ VaultError::HolderMismatch,
+    )]
+    pub holder_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub token_program: Option<Interface<'info, TokenInterface>>,
 }
 
-pub fn handler(_ctx: Context<PdaWhitelistRemove>, user: Pubkey) -> Result<()> {
-    msg!("Removed {} from PDA whitelist", user);
+pub(crate) fn handler(ctx: Context<PdaWhitelistRemove>, user: Pubkey) -> Result<()> {
+    sync_holder_frozen(
+        true,
+        &ctx.accounts.vault_config,
+        ctx.accounts.mint.as_ref(),
+        ctx.accounts.holder_token_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    )?;
+    
+    emit!(WhitelistRemoved {
+        vault_config: ctx.accounts.vault_config.key(),
+        user,
+        pda_entry: true,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
     Ok(())
 }
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
+
+/// Change a PDA entry's limit and tier in place, keeping its usage counters
+#[derive(Accounts)]
+#[instruction(user: Pubkey)]
+pub struct PdaWhitelistUpdate<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [VAULT_CONFIG_SEED, vault_config.mint.as_ref()],
+        bump = vault_config.config_bump,
+        has_one = authority @ VaultError::UnauthorizedAuthority,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    #[account(
+        mut,
+        seeds = [WHITELIST_ENTRY_SEED, vault_config.key().as_ref(), user.as_ref()],
+        bump = whitelist_entry.bump,
+        constraint = whitelist_entry.user == user @ VaultError::NotWhitelisted,
+    )]
+    pub whitelist_entry: Account<'info, WhitelistEntryPda>,
+}
+
+pub(crate) fn handler(ctx: Context<PdaWhitelistUpdate>, user: Pubkey, max_amount: u64, tier_id: u8) -> Result<()> {
+    require!(tier_id <= MAX_TIERS, VaultError::InvalidTier);
+    
+    let whitelist_entry = &mut ctx.accounts.whitelist_entry;
+    whitelist_entry.max_amount = max_amount;
+    whitelist_entry.tier_id = tier_id;
+    
+    emit!(WhitelistUpdated {
+        vault_config: whitelist_entry.vault_config,
+        user,
+        max_amount,
+        tier_id,
+        pda_entry: true,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
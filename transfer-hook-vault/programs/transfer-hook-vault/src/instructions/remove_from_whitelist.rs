@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use crate::constants::*;
 use crate::error::VaultError;
+use crate::events::*;
+use crate::instructions::holder::sync_holder_frozen;
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -21,15 +24,43 @@ pub struct RemoveFromWhitelist<'info> {
         seeds = [WHITELIST_SEED, vault_config.mint.as_ref()],
         bump = vault_config.whitelist_bump,
     )]
-    pub whitelist: Account<'info, Whitelist>,
+    pub whitelist: AccountLoader<'info, Whitelist>,
+    
+    /// Optional with `holder_token_account` and `token_program`: the user's
+    /// token account is frozen via the vault config's freeze authority
+    #[account(address = vault_config.mint @ VaultError::InvalidMint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        mut,
+        constraint = holder_token_account.mint == vault_config.mint @ VaultError::InvalidMint,
+        constraint = holder_token_account.owner == user @ VaultError::HolderMismatch,
+    )]
+    pub holder_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub token_program: Option<Interface<'info, TokenInterface>>,
 }
 
-pub fn handler(ctx: Context<RemoveFromWhitelist>, user: Pubkey) -> Result<()> {
-    let whitelist = &mut ctx.accounts.whitelist;
+pub(crate) fn handler(ctx: Context<RemoveFromWhitelist>, user: Pubkey) -> Result<()> {
+    let whitelist_info = ctx.accounts.whitelist.to_account_info();
+    let mut data = whitelist_info.try_borrow_mut_data()?;
+    let (whitelist, slots) = Whitelist::from_data_mut(&mut data)?;
+    
+    require!(whitelist.remove_entry(slots, &user), VaultError::NotWhitelisted);
     
-    require!(whitelist.is_whitelisted(&user), VaultError::NotWhitelisted);
-    whitelist.entries.retain(|e| e.user != user);
+    sync_holder_frozen(
+        true,
+        &ctx.accounts.vault_config,
+        ctx.accounts.mint.as_ref(),
+        ctx.accounts.holder_token_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    )?;
     
-    msg!("Removed {} from whitelist", user);
+    emit!(WhitelistRemoved {
+        vault_config: ctx.accounts.vault_config.key(),
+        user,
+        pda_entry: false,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
     Ok(())
 }
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::{token_2022::Token2022, token_interface::Mint};
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct RemoveMetadataKey<'info> {
+    pub authority: Signer<'info>,
+    
+    /// Metadata update authority
+    #[account(
+        seeds = [VAULT_CONFIG_SEED, mint.key().as_ref()],
+        bump = vault_config.config_bump,
+        has_one = authority @ VaultError::UnauthorizedAuthority,
+        has_one = mint @ VaultError::InvalidMint,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// With `idempotent` a missing key is not an error
+pub(crate) fn handler(ctx: Context<RemoveMetadataKey>, key: String, idempotent: bool) -> Result<()> {
+    let mint_key = ctx.accounts.mint.key();
+    let vault_config = &ctx.accounts.vault_config;
+    let seeds = &[VAULT_CONFIG_SEED, mint_key.as_ref(), &[vault_config.config_bump]];
+    
+    invoke_signed(
+        &spl_token_metadata_interface::instruction::remove_key(
+            ctx.accounts.token_program.key,
+            &mint_key,
+            &vault_config.key(),
+            key.clone(),
+            idempotent,
+        ),
+        &[ctx.accounts.mint.to_account_info(), vault_config.to_account_info()],
+        &[&seeds[..]],
+    )?;
+    
+    emit!(TokenMetadataKeyRemoved {
+        vault_config: vault_config.key(),
+        mint: mint_key,
+        key,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
+
+/// Grow or shrink the whitelist account. Anchor's realloc tops up rent from
+/// the authority when growing and refunds the excess when shrinking. Growth
+/// per call is capped at MAX_PERMITTED_DATA_INCREASE (10KiB), checked before
+/// the realloc runs, so large whitelists resize over several calls.
+#[derive(Accounts)]
+#[instruction(capacity: u32)]
+pub struct ResizeWhitelist<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [VAULT_CONFIG_SEED, vault_config.mint.as_ref()],
+        bump = vault_config.config_bump,
+        has_one = authority @ VaultError::UnauthorizedAuthority,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    #[account(
+        mut,
+        seeds = [WHITELIST_SEED, vault_config.mint.as_ref()],
+        bump = vault_config.whitelist_bump,
+        constraint = Whitelist::space(capacity as usize).saturating_sub(whitelist.to_account_info().data_len())
+            <= MAX_PERMITTED_DATA_INCREASE @ VaultError::ResizeTooLarge,
+        realloc = Whitelist::space(capacity as usize),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    pub whitelist: AccountLoader<'info, Whitelist>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<ResizeWhitelist>, capacity: u32) -> Result<()> {
+    let mut whitelist = ctx.accounts.whitelist.load_mut()?;
+    
+    require!(
+        capacity >= whitelist.len,
+        VaultError::InvalidWhitelistCapacity
+    );
+    
+    let old_capacity = whitelist.capacity;
+    whitelist.capacity = capacity;
+    
+    emit!(WhitelistResized {
+        vault_config: ctx.accounts.vault_config.key(),
+        old_capacity,
+        new_capacity: capacity,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct SetMerkleRoot<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED, vault_config.mint.as_ref()],
+        bump = vault_config.config_bump,
+        has_one = authority @ VaultError::UnauthorizedAuthority,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+}
+
+/// Replace the allowlist root. All zeros disables Merkle mode; entries
+/// already claimed as PDAs are unaffected.
+pub(crate) fn handler(ctx: Context<SetMerkleRoot>, merkle_root: [u8; 32]) -> Result<()> {
+    ctx.accounts.vault_config.merkle_root = merkle_root;
+    
+    emit!(MerkleRootUpdated {
+        vault_config: ctx.accounts.vault_config.key(),
+        merkle_root,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
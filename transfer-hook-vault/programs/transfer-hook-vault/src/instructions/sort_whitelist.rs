@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
+
+/// One-off migration for whitelists filled before entries were kept sorted:
+/// sorts them in place so the hook's lookups are a binary search again.
+/// Harmless to re-run on a sorted whitelist.
+#[derive(Accounts)]
+pub struct SortWhitelist<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [VAULT_CONFIG_SEED, vault_config.mint.as_ref()],
+        bump = vault_config.config_bump,
+        has_one = authority @ VaultError::UnauthorizedAuthority,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    #[account(
+        mut,
+        seeds = [WHITELIST_SEED, vault_config.mint.as_ref()],
+        bump = vault_config.whitelist_bump,
+    )]
+    pub whitelist: AccountLoader<'info, Whitelist>,
+}
+
+pub(crate) fn handler(ctx: Context<SortWhitelist>) -> Result<()> {
+    let whitelist_info = ctx.accounts.whitelist.to_account_info();
+    let mut data = whitelist_info.try_borrow_mut_data()?;
+    let (whitelist, slots) = Whitelist::from_data_mut(&mut data)?;
+    
+    whitelist.sort_entries(slots);
+    // Inserts into an unsorted whitelist always scanned for the user, so a
+    // duplicate means the account was written some other way
+    require!(
+        Whitelist::is_sorted(&slots[..whitelist.len as usize]),
+        VaultError::DuplicateWhitelistEntry
+    );
+    
+    emit!(WhitelistSorted {
+        vault_config: ctx.accounts.vault_config.key(),
+        entries: whitelist.len,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
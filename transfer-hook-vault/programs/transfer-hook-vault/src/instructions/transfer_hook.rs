@@ -1,7 +1,15 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_2022::{
+    spl_token_2022::{
+        extension::{transfer_hook::TransferHookAccount, BaseStateWithExtensions, StateWithExtensions},
+        state::Account as TokenAccountState,
+    },
+    Token2022,
+};
 use anchor_spl::token_interface::TokenAccount;
 use crate::constants::*;
 use crate::error::VaultError;
+use crate::events::*;
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -19,36 +27,136 @@ pub struct TransferHook<'info> {
     pub source_authority: AccountInfo<'info>,
     
     /// CHECK: Extra metas
-    #[account(seeds = [EXTRA_METAS_SEED, mint.key().as_ref()], bump)]
+    #[account(seeds = [EXTRA_METAS_SEED, mint.key().as_ref()], bump = vault_config.extra_metas_bump)]
     pub extra_metas: AccountInfo<'info>,
     
-    #[account(seeds = [VAULT_SEED, mint.key().as_ref()], bump)]
+    #[account(seeds = [VAULT_SEED, mint.key().as_ref()], bump = vault_config.vault_bump)]
     pub vault: InterfaceAccount<'info, TokenAccount>,
     
-    #[account(seeds = [WHITELIST_SEED, mint.key().as_ref()], bump)]
-    pub whitelist: Account<'info, Whitelist>,
+    #[account(mut, seeds = [WHITELIST_SEED, mint.key().as_ref()], bump = vault_config.whitelist_bump)]
+    pub whitelist: AccountLoader<'info, Whitelist>,
+    
+    #[account(seeds = [VAULT_CONFIG_SEED, mint.key().as_ref()], bump = vault_config.config_bump)]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    /// CHECK: Source owner's PDA entry (claimed or added); may not exist
+    #[account(
+        mut,
+        seeds = [WHITELIST_ENTRY_SEED, vault_config.key().as_ref(), source_authority.key().as_ref()],
+        bump,
+    )]
+    pub whitelist_entry: AccountInfo<'info>,
+    
+    // remaining_accounts: Tier PDAs for tier ids 1..=MAX_TIERS, in order
 }
 
-pub fn handler(ctx: Context<TransferHook>, amount: u64) -> Result<()> {
-    let whitelist = &ctx.accounts.whitelist;
+pub(crate) fn handler(ctx: Context<TransferHook>, amount: u64) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+    let mint = ctx.accounts.mint.key();
+    let source = ctx.accounts.source.key();
     let destination = ctx.accounts.destination.key();
-    let vault = ctx.accounts.vault.key();
+    let source_authority = ctx.accounts.source_authority.key();
+    
+    if let Err(err) = validate_transfer(&ctx, amount, timestamp) {
+        emit!(TransferRejected {
+            mint,
+            source,
+            destination,
+            source_authority,
+            amount,
+            error_code: error_code(&err),
+            timestamp,
+        });
+        return Err(err);
+    }
+    
+    emit!(TransferValidated {
+        mint,
+        source,
+        destination,
+        source_authority,
+        amount,
+        timestamp,
+    });
+    Ok(())
+}
+
+fn validate_transfer(ctx: &Context<TransferHook>, amount: u64, timestamp: i64) -> Result<()> {
+    require_transferring(ctx)?;
     
     // Always allow transfers to vault (deposits)
-    if destination == vault {
-        msg!("Transfer to vault allowed");
+    if ctx.accounts.destination.key() == ctx.accounts.vault.key() {
         return Ok(());
     }
     
     let source_authority = ctx.accounts.source_authority.key();
-    require!(whitelist.is_whitelisted(&source_authority), VaultError::TransferHookValidationFailed);
+    let vault_config = ctx.accounts.vault_config.key();
+    let day = timestamp / SECONDS_PER_DAY;
+    let whitelist_info = ctx.accounts.whitelist.to_account_info();
+    let mut data = whitelist_info.try_borrow_mut_data()?;
+    let (whitelist, slots) = Whitelist::from_data_mut(&mut data)?;
     
-    let entry = whitelist.get_entry(&source_authority).unwrap();
-    require!(
-        entry.max_amount == 0 || amount <= entry.max_amount,
-        VaultError::AmountExceedsLimit
-    );
+    // Vec entries take precedence; otherwise the owner needs a PDA entry
+    match Whitelist::get_entry_mut(&mut slots[..whitelist.len as usize], &source_authority) {
+        Some(entry) => {
+            let tier = load_tier(ctx.remaining_accounts, &vault_config, entry.tier_id)?;
+            entry.record_transfer(tier.as_ref(), amount, day)?;
+        }
+        None => {
+            let entry_info = &ctx.accounts.whitelist_entry;
+            require!(entry_info.owner == &crate::ID, VaultError::TransferHookValidationFailed);
+            
+            let mut entry_data = entry_info.try_borrow_mut_data()?;
+            let mut entry = WhitelistEntryPda::try_deserialize(&mut &entry_data[..])?;
+            let tier = load_tier(ctx.remaining_accounts, &vault_config, entry.tier_id)?;
+            entry.record_transfer(tier.as_ref(), amount, day)?;
+            entry.try_serialize(&mut &mut entry_data[..])?;
+        }
+    }
     
-    msg!("Transfer validated for {}", source_authority);
     Ok(())
 }
+
+/// The hook records quota usage, so it must only run inside a Token-2022
+/// transfer out of `source_authority`'s own account; called directly, anyone
+/// could spend another user's limits. Token-2022 sets `transferring` on the
+/// source for the duration of the hook CPI.
+fn require_transferring(ctx: &Context<TransferHook>) -> Result<()> {
+    let source_info = &ctx.accounts.source;
+    require_keys_eq!(*source_info.owner, Token2022::id(), VaultError::NotTransferring);
+    
+    let data = source_info.try_borrow_data()?;
+    let source = StateWithExtensions::<TokenAccountState>::unpack(&data)?;
+    let transferring = source
+        .get_extension::<TransferHookAccount>()
+        .is_ok_and(|extension| bool::from(extension.transferring));
+    require!(transferring, VaultError::NotTransferring);
+    require_keys_eq!(source.base.mint, ctx.accounts.mint.key(), VaultError::InvalidMint);
+    require_keys_eq!(source.base.owner, ctx.accounts.source_authority.key(), VaultError::HolderMismatch);
+    Ok(())
+}
+
+fn error_code(err: &Error) -> u32 {
+    match err {
+        Error::AnchorError(e) => e.error_code_number,
+        Error::ProgramError(e) => u64::from(e.program_error.clone()) as u32,
+    }
+}
+
+/// The hook can't derive seeds from Vec entry data, so `initialize_extra_metas`
+/// lists every tier PDA and we index into them by id.
+fn load_tier(tiers: &[AccountInfo], vault_config: &Pubkey, tier_id: u8) -> Result<Option<Tier>> {
+    if tier_id == 0 {
+        return Ok(None);
+    }
+    
+    let info = tiers.get(tier_id as usize - 1).ok_or(VaultError::InvalidTier)?;
+    require!(info.owner == &crate::ID, VaultError::InvalidTier);
+    
+    let tier = Tier::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+    require!(
+        tier.vault_config == *vault_config && tier.tier_id == tier_id,
+        VaultError::InvalidTier
+    );
+    Ok(Some(tier))
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
+
+/// Change a tier's limits; every entry referencing it picks them up at once
+#[derive(Accounts)]
+#[instruction(tier_id: u8)]
+pub struct UpdateTier<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [VAULT_CONFIG_SEED, vault_config.mint.as_ref()],
+        bump = vault_config.config_bump,
+        has_one = authority @ VaultError::UnauthorizedAuthority,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    #[account(
+        mut,
+        seeds = [TIER_SEED, vault_config.key().as_ref(), &[tier_id]],
+        bump = tier.bump,
+    )]
+    pub tier: Account<'info, Tier>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<UpdateTier>,
+    tier_id: u8,
+    per_tx_limit: u64,
+    daily_limit: u64,
+    lifetime_limit: u64,
+) -> Result<()> {
+    let tier = &mut ctx.accounts.tier;
+    tier.per_tx_limit = per_tx_limit;
+    tier.daily_limit = daily_limit;
+    tier.lifetime_limit = lifetime_limit;
+    
+    emit!(TierUpdated {
+        vault_config: ctx.accounts.vault_config.key(),
+        tier_id,
+        per_tx_limit,
+        daily_limit,
+        lifetime_limit,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
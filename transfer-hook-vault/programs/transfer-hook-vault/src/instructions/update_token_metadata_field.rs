@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::{
+    token_2022::{
+        spl_token_2022::{
+            extension::{BaseStateWithExtensions, StateWithExtensions},
+            state::Mint as MintState,
+        },
+        Token2022,
+    },
+    token_interface::{token_metadata_update_field, Mint, TokenMetadataUpdateField},
+};
+use spl_token_metadata_interface::state::{Field, TokenMetadata};
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
+
+/// Which metadata field to set; `Key` adds or updates an additional entry
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum MetadataField {
+    Name,
+    Symbol,
+    Uri,
+    Key(String),
+}
+
+impl From<MetadataField> for Field {
+    fn from(field: MetadataField) -> Self {
+        match field {
+            MetadataField::Name => Field::Name,
+            MetadataField::Symbol => Field::Symbol,
+            MetadataField::Uri => Field::Uri,
+            MetadataField::Key(key) => Field::Key(key),
+        }
+    }
+}
+
+#[derive(Accounts)]
+pub struct UpdateTokenMetadataField<'info> {
+    /// Pays for any growth of the mint account
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// Metadata update authority
+    #[account(
+        seeds = [VAULT_CONFIG_SEED, mint.key().as_ref()],
+        bump = vault_config.config_bump,
+        has_one = authority @ VaultError::UnauthorizedAuthority,
+        has_one = mint @ VaultError::InvalidMint,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    pub token_program: Program<'info, Token2022>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<UpdateTokenMetadataField>, field: MetadataField, value: String) -> Result<()> {
+    let mint_info = ctx.accounts.mint.to_account_info();
+    
+    // Token-2022 reallocs the mint to fit the new value but doesn't fund it
+    let new_len = {
+        let data = mint_info.try_borrow_data()?;
+        let mint = StateWithExtensions::<MintState>::unpack(&data)?;
+        let mut metadata = mint
+            .get_variable_len_extension::<TokenMetadata>()
+            .map_err(|_| error!(VaultError::MetadataNotInitialized))?;
+        let old_size = metadata.tlv_size_of()?;
+        metadata.update(field.clone().into(), value.clone());
+        data.len() - old_size + metadata.tlv_size_of()?
+    };
+    let shortfall = Rent::get()?.minimum_balance(new_len).saturating_sub(mint_info.lamports());
+    if shortfall > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: mint_info.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    
+    let mint_key = ctx.accounts.mint.key();
+    let seeds = &[VAULT_CONFIG_SEED, mint_key.as_ref(), &[ctx.accounts.vault_config.config_bump]];
+    token_metadata_update_field(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TokenMetadataUpdateField {
+                token_program_id: ctx.accounts.token_program.to_account_info(),
+                metadata: mint_info,
+                update_authority: ctx.accounts.vault_config.to_account_info(),
+            },
+            &[&seeds[..]],
+        ),
+        field.clone().into(),
+        value.clone(),
+    )?;
+    
+    emit!(TokenMetadataUpdated {
+        vault_config: ctx.accounts.vault_config.key(),
+        mint: mint_key,
+        field,
+        value,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::VaultError;
+use crate::events::*;
+use crate::state::*;
+
+/// Change a Vec entry's limit and tier in place, keeping its usage counters
+#[derive(Accounts)]
+pub struct UpdateWhitelistEntry<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [VAULT_CONFIG_SEED, vault_config.mint.as_ref()],
+        bump = vault_config.config_bump,
+        has_one = authority @ VaultError::UnauthorizedAuthority,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+    
+    #[account(
+        mut,
+        seeds = [WHITELIST_SEED, vault_config.mint.as_ref()],
+        bump = vault_config.whitelist_bump,
+    )]
+    pub whitelist: AccountLoader<'info, Whitelist>,
+}
+
+pub(crate) fn handler(ctx: Context<UpdateWhitelistEntry>, user: Pubkey, max_amount: u64, tier_id: u8) -> Result<()> {
+    require!(tier_id <= MAX_TIERS, VaultError::InvalidTier);
+    
+    let whitelist_info = ctx.accounts.whitelist.to_account_info();
+    let mut data = whitelist_info.try_borrow_mut_data()?;
+    let (whitelist, slots) = Whitelist::from_data_mut(&mut data)?;
+    
+    let entry = Whitelist::get_entry_mut(&mut slots[..whitelist.len as usize], &user)
+        .ok_or(VaultError::NotWhitelisted)?;
+    entry.max_amount = max_amount;
+    entry.tier_id = tier_id;
+    
+    emit!(WhitelistUpdated {
+        vault_config: ctx.accounts.vault_config.key(),
+        user,
+        max_amount,
+        tier_id,
+        pda_entry: false,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -2,26 +2,36 @@ use anchor_lang::prelude::*;
 
 declare_id!("HookVau1t1111111111111111111111111111111111");
 
+#[cfg(not(target_os = "solana"))]
+pub mod client;
 pub mod constants;
 pub mod error;
+pub mod events;
 pub mod instructions;
+pub mod merkle;
 pub mod state;
 
 use instructions::*;
+use merkle::WhitelistProof;
 
 #[program]
 pub mod transfer_hook_vault {
     use super::*;
 
     // Setup
-    pub fn create_mint(ctx: Context<CreateMint>, decimals: u8) -> Result<()> {
-        instructions::create_mint::handler(ctx, decimals)
+    pub fn create_mint(
+        ctx: Context<CreateMint>,
+        decimals: u8,
+        default_frozen: bool,
+        metadata: Option<TokenMetadataArgs>,
+    ) -> Result<()> {
+        instructions::create_mint::handler(ctx, decimals, default_frozen, metadata)
     }
 
 
 
-    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
-        instructions::initialize::handler(ctx)
+    pub fn initialize(ctx: Context<Initialize>, capacity: u32) -> Result<()> {
+        instructions::initialize::handler(ctx, capacity)
     }
 
     pub fn initialize_extra_metas(ctx: Context<InitializeExtraMetas>) -> Result<()> {
@@ -32,41 +42,120 @@ pub mod transfer_hook_vault {
         instructions::mint_tokens::handler(ctx, amount)
     }
 
+    // Metadata
+    pub fn update_token_metadata_field(
+        ctx: Context<UpdateTokenMetadataField>,
+        field: MetadataField,
+        value: String,
+    ) -> Result<()> {
+        instructions::update_token_metadata_field::handler(ctx, field, value)
+    }
+
+    pub fn remove_metadata_key(ctx: Context<RemoveMetadataKey>, key: String, idempotent: bool) -> Result<()> {
+        instructions::remove_metadata_key::handler(ctx, key, idempotent)
+    }
+
+    // Compliance
+    pub fn freeze_account(ctx: Context<FreezeAccount>) -> Result<()> {
+        instructions::freeze_account::handler(ctx)
+    }
+
+    pub fn thaw_account(ctx: Context<ThawAccount>) -> Result<()> {
+        instructions::thaw_account::handler(ctx)
+    }
+
     // Vec Whitelist
-    pub fn add_to_whitelist(ctx: Context<AddToWhitelist>, user: Pubkey, max_amount: u64) -> Result<()> {
-        instructions::add_to_whitelist::handler(ctx, user, max_amount)
+    pub fn add_to_whitelist(ctx: Context<AddToWhitelist>, user: Pubkey, max_amount: u64, tier_id: u8) -> Result<()> {
+        instructions::add_to_whitelist::handler(ctx, user, max_amount, tier_id)
     }
 
     pub fn remove_from_whitelist(ctx: Context<RemoveFromWhitelist>, user: Pubkey) -> Result<()> {
         instructions::remove_from_whitelist::handler(ctx, user)
     }
 
+    pub fn update_whitelist_entry(
+        ctx: Context<UpdateWhitelistEntry>,
+        user: Pubkey,
+        max_amount: u64,
+        tier_id: u8,
+    ) -> Result<()> {
+        instructions::update_whitelist_entry::handler(ctx, user, max_amount, tier_id)
+    }
+
+    pub fn resize_whitelist(ctx: Context<ResizeWhitelist>, capacity: u32) -> Result<()> {
+        instructions::resize_whitelist::handler(ctx, capacity)
+    }
+
+    pub fn sort_whitelist(ctx: Context<SortWhitelist>) -> Result<()> {
+        instructions::sort_whitelist::handler(ctx)
+    }
+
     // PDA Whitelist (alternative)
-    pub fn pda_whitelist_add(ctx: Context<PdaWhitelistAdd>, user: Pubkey, max_amount: u64) -> Result<()> {
-        instructions::pda_whitelist_add::handler(ctx, user, max_amount)
+    pub fn pda_whitelist_add(ctx: Context<PdaWhitelistAdd>, user: Pubkey, max_amount: u64, tier_id: u8) -> Result<()> {
+        instructions::pda_whitelist_add::handler(ctx, user, max_amount, tier_id)
     }
 
     pub fn pda_whitelist_remove(ctx: Context<PdaWhitelistRemove>, user: Pubkey) -> Result<()> {
         instructions::pda_whitelist_remove::handler(ctx, user)
     }
 
+    pub fn pda_whitelist_update(ctx: Context<PdaWhitelistUpdate>, user: Pubkey, max_amount: u64, tier_id: u8) -> Result<()> {
+        instructions::pda_whitelist_update::handler(ctx, user, max_amount, tier_id)
+    }
+
     pub fn pda_whitelist_check(ctx: Context<PdaWhitelistCheck>, user: Pubkey) -> Result<()> {
         instructions::pda_whitelist_check::handler(ctx, user)
     }
 
+    // Merkle Whitelist
+    pub fn set_merkle_root(ctx: Context<SetMerkleRoot>, merkle_root: [u8; 32]) -> Result<()> {
+        instructions::set_merkle_root::handler(ctx, merkle_root)
+    }
+
+    pub fn claim_whitelist(ctx: Context<ClaimWhitelist>, max_amount: u64, proof: Vec<[u8; 32]>) -> Result<()> {
+        instructions::claim_whitelist::handler(ctx, max_amount, proof)
+    }
+
+    // Tiers
+    pub fn create_tier(
+        ctx: Context<CreateTier>,
+        tier_id: u8,
+        per_tx_limit: u64,
+        daily_limit: u64,
+        lifetime_limit: u64,
+    ) -> Result<()> {
+        instructions::create_tier::handler(ctx, tier_id, per_tx_limit, daily_limit, lifetime_limit)
+    }
+
+    pub fn update_tier(
+        ctx: Context<UpdateTier>,
+        tier_id: u8,
+        per_tx_limit: u64,
+        daily_limit: u64,
+        lifetime_limit: u64,
+    ) -> Result<()> {
+        instructions::update_tier::handler(ctx, tier_id, per_tx_limit, daily_limit, lifetime_limit)
+    }
+
     // Vault Operations
-    pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
-        instructions::deposit::handler(ctx, amount)
+    pub fn deposit<'info>(ctx: Context<'_, '_, '_, 'info, Deposit<'info>>, amount: u64, proof: Option<WhitelistProof>) -> Result<()> {
+        instructions::deposit::handler(ctx, amount, proof)
     }
 
-    pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
-        instructions::withdraw::handler(ctx, amount)
+    pub fn withdraw<'info>(ctx: Context<'_, '_, '_, 'info, Withdraw<'info>>, amount: u64, proof: Option<WhitelistProof>) -> Result<()> {
+        instructions::withdraw::handler(ctx, amount, proof)
     }
 
     // Transfer Hook
+    #[interface(spl_transfer_hook_interface::execute)]
     pub fn transfer_hook(ctx: Context<TransferHook>, amount: u64) -> Result<()> {
         instructions::transfer_hook::handler(ctx, amount)
     }
+
+    // Teardown
+    pub fn close_vault<'info>(ctx: Context<'_, '_, '_, 'info, CloseVault<'info>>) -> Result<()> {
+        instructions::close_vault::handler(ctx)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,67 @@
+//! Merkle allowlist. Leaves commit to `(user, max_amount)`; sibling pairs are
+//! hashed in sorted order so proofs carry no left/right flags.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak::hashv;
+
+// Domain separation keeps an inner node from being passed off as a leaf
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Proof that `(signer, max_amount)` is a leaf under `VaultConfig::merkle_root`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct WhitelistProof {
+    pub max_amount: u64,
+    pub proof: Vec<[u8; 32]>,
+}
+
+pub fn leaf_hash(user: &Pubkey, max_amount: u64) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, user.as_ref(), &max_amount.to_le_bytes()]).to_bytes()
+}
+
+fn node_hash(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[NODE_PREFIX, lo, hi]).to_bytes()
+}
+
+pub fn verify(root: &[u8; 32], leaf: [u8; 32], proof: &[[u8; 32]]) -> bool {
+    proof.iter().fold(leaf, |node, sibling| node_hash(&node, sibling)) == *root
+}
+
+fn next_level(level: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [a, b] => node_hash(a, b),
+            [a] => *a, // odd node is promoted unchanged
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+/// Root over `leaves`, for building the allowlist off-chain. An empty list
+/// yields the all-zero root, which leaves Merkle mode disabled.
+pub fn root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    let mut level = leaves.to_vec();
+    if level.is_empty() {
+        return [0; 32];
+    }
+    while level.len() > 1 {
+        level = next_level(&level);
+    }
+    level[0]
+}
+
+/// Sibling path for `leaves[index]`, for building proofs off-chain
+pub fn proof(leaves: &[[u8; 32]], mut index: usize) -> Vec<[u8; 32]> {
+    let mut level = leaves.to_vec();
+    let mut proof = Vec::new();
+    while level.len() > 1 {
+        if let Some(sibling) = level.get(index ^ 1) {
+            proof.push(*sibling);
+        }
+        level = next_level(&level);
+        index /= 2;
+    }
+    proof
+}
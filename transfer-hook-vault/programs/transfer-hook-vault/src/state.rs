@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
-use crate::constants::MAX_WHITELIST_ENTRIES;
+use anchor_lang::Discriminator;
+use crate::error::VaultError;
+use crate::merkle;
 
 #[account]
 #[derive(InitSpace)]
@@ -10,35 +12,155 @@ pub struct VaultConfig {
     pub vault_bump: u8,
     pub whitelist_bump: u8,
     pub extra_metas_bump: u8,
+    pub merkle_root: [u8; 32], // all zeros = Merkle mode disabled
 }
 
-#[account]
+impl VaultConfig {
+    pub fn verify_whitelist_proof(&self, user: &Pubkey, max_amount: u64, proof: &[[u8; 32]]) -> bool {
+        self.merkle_root != [0; 32]
+            && merkle::verify(&self.merkle_root, merkle::leaf_hash(user, max_amount), proof)
+    }
+}
+
+/// Zero-copy whitelist header. `capacity` entry slots follow it in the
+/// account data; the first `len` are populated and sorted by user.
+#[account(zero_copy)]
 pub struct Whitelist {
     pub authority: Pubkey,
-    pub entries: Vec<WhitelistEntry>,
+    pub capacity: u32, // entry slots the account is currently sized for
+    pub len: u32,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[zero_copy]
 pub struct WhitelistEntry {
     pub user: Pubkey,
     pub max_amount: u64, // 0 = unlimited
+    pub usage: TierUsage,
+    pub tier_id: u8, // 0 = no tier
+    pub _padding: [u8; 7],
+}
+
+impl WhitelistEntry {
+    pub fn new(user: Pubkey, max_amount: u64, tier_id: u8) -> Self {
+        Self { user, max_amount, usage: TierUsage::default(), tier_id, _padding: [0; 7] }
+    }
+
+    /// Enforce the entry's own limit and its tier's, recording `amount`
+    pub fn record_transfer(&mut self, tier: Option<&Tier>, amount: u64, day: i64) -> Result<()> {
+        require!(
+            self.max_amount == 0 || amount <= self.max_amount,
+            VaultError::AmountExceedsLimit
+        );
+        if self.tier_id != 0 {
+            let tier = tier.filter(|t| t.tier_id == self.tier_id).ok_or(VaultError::InvalidTier)?;
+            tier.record(&mut self.usage, amount, day)?;
+        }
+        Ok(())
+    }
 }
 
 impl Whitelist {
+    /// Byte offset of the first entry slot: discriminator, then the header
+    pub const ENTRIES_OFFSET: usize = 8 + std::mem::size_of::<Whitelist>();
+    pub const ENTRY_LEN: usize = std::mem::size_of::<WhitelistEntry>();
+
     pub fn space(max_entries: usize) -> usize {
-        8 + 32 + 4 + max_entries * (32 + 8)
+        Self::ENTRIES_OFFSET + max_entries * Self::ENTRY_LEN
     }
 
-    pub fn is_whitelisted(&self, user: &Pubkey) -> bool {
-        self.entries.iter().any(|e| e.user == *user)
+    pub fn can_add_entry(&self) -> bool {
+        self.len < self.capacity
     }
 
-    pub fn get_entry(&self, user: &Pubkey) -> Option<&WhitelistEntry> {
-        self.entries.iter().find(|e| e.user == *user)
+    /// Header and populated entries, borrowed straight from account data
+    pub fn from_data(data: &[u8]) -> Result<(&Whitelist, &[WhitelistEntry])> {
+        require!(
+            data.len() >= Self::ENTRIES_OFFSET && data[..8] == Self::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        
+        let (header, slots) = data[8..].split_at(Self::ENTRIES_OFFSET - 8);
+        let header: &Whitelist = bytemuck::try_from_bytes(header)
+            .map_err(|_| ErrorCode::AccountDidNotDeserialize)?;
+        let entries = slots
+            .get(..header.len as usize * Self::ENTRY_LEN)
+            .and_then(|bytes| bytemuck::try_cast_slice(bytes).ok())
+            .ok_or(ErrorCode::AccountDidNotDeserialize)?;
+        
+        Ok((header, entries))
     }
 
-    pub fn can_add_entry(&self) -> bool {
-        self.entries.len() < MAX_WHITELIST_ENTRIES
+    /// Header and all `capacity` entry slots, for inserting and removing
+    pub fn from_data_mut(data: &mut [u8]) -> Result<(&mut Whitelist, &mut [WhitelistEntry])> {
+        require!(
+            data.len() >= Self::ENTRIES_OFFSET && data[..8] == Self::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        
+        let (header, slots) = data[8..].split_at_mut(Self::ENTRIES_OFFSET - 8);
+        let header: &mut Whitelist = bytemuck::try_from_bytes_mut(header)
+            .map_err(|_| ErrorCode::AccountDidNotDeserialize)?;
+        let slots = slots
+            .get_mut(..header.capacity as usize * Self::ENTRY_LEN)
+            .and_then(|bytes| bytemuck::try_cast_slice_mut(bytes).ok())
+            .ok_or(ErrorCode::AccountDidNotDeserialize)?;
+        
+        Ok((header, slots))
+    }
+
+    /// Entries are kept sorted by user, so lookups are a binary search.
+    /// Whitelists filled before that stay in insertion order until
+    /// `sort_whitelist` runs, so a miss falls back to a linear scan rather
+    /// than reporting a listed user as absent. `Err` is the sorted insert
+    /// position.
+    pub fn find_entry(entries: &[WhitelistEntry], user: &Pubkey) -> std::result::Result<usize, usize> {
+        entries
+            .binary_search_by(|e| e.user.cmp(user))
+            .or_else(|i| entries.iter().position(|e| e.user == *user).ok_or(i))
+    }
+
+    pub fn get_entry<'a>(entries: &'a [WhitelistEntry], user: &Pubkey) -> Option<&'a WhitelistEntry> {
+        Self::find_entry(entries, user).ok().map(|i| &entries[i])
+    }
+
+    pub fn get_entry_mut<'a>(entries: &'a mut [WhitelistEntry], user: &Pubkey) -> Option<&'a mut WhitelistEntry> {
+        Self::find_entry(entries, user).ok().map(|i| &mut entries[i])
+    }
+
+    pub fn is_sorted(entries: &[WhitelistEntry]) -> bool {
+        entries.windows(2).all(|pair| pair[0].user < pair[1].user)
+    }
+
+    /// Inserts at the sorted position; returns false if the user is already present
+    pub fn insert_entry(&mut self, slots: &mut [WhitelistEntry], entry: WhitelistEntry) -> bool {
+        let len = self.len as usize;
+        match Self::find_entry(&slots[..len], &entry.user) {
+            Ok(_) => false,
+            Err(i) => {
+                slots.copy_within(i..len, i + 1);
+                slots[i] = entry;
+                self.len += 1;
+                true
+            }
+        }
+    }
+
+    /// Removes the user's entry; returns false if the user was not present
+    pub fn remove_entry(&mut self, slots: &mut [WhitelistEntry], user: &Pubkey) -> bool {
+        let len = self.len as usize;
+        match Self::find_entry(&slots[..len], user) {
+            Ok(i) => {
+                slots.copy_within(i + 1..len, i);
+                self.len -= 1;
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Sorts entries written in insertion order, restoring the binary search
+    pub fn sort_entries(&self, slots: &mut [WhitelistEntry]) {
+        slots[..self.len as usize].sort_unstable_by_key(|e| e.user);
     }
 }
 
@@ -49,4 +171,71 @@ pub struct WhitelistEntryPda {
     pub max_amount: u64,
     pub vault_config: Pubkey,
     pub bump: u8,
+    pub tier_id: u8, // 0 = no tier
+    pub usage: TierUsage,
+}
+
+impl WhitelistEntryPda {
+    /// Enforce the entry's own limit and its tier's, recording `amount`
+    pub fn record_transfer(&mut self, tier: Option<&Tier>, amount: u64, day: i64) -> Result<()> {
+        require!(
+            self.max_amount == 0 || amount <= self.max_amount,
+            VaultError::AmountExceedsLimit
+        );
+        if self.tier_id != 0 {
+            let tier = tier.filter(|t| t.tier_id == self.tier_id).ok_or(VaultError::InvalidTier)?;
+            tier.record(&mut self.usage, amount, day)?;
+        }
+        Ok(())
+    }
+}
+
+/// Limits shared by every entry that references `tier_id`
+/// Seeds: ["tier", vault_config, tier_id]
+#[account]
+#[derive(InitSpace)]
+pub struct Tier {
+    pub vault_config: Pubkey,
+    pub tier_id: u8,
+    pub per_tx_limit: u64, // 0 = unlimited, likewise for the others
+    pub daily_limit: u64,
+    pub lifetime_limit: u64,
+    pub bump: u8,
+}
+
+/// Running totals an entry accumulates against its tier
+#[zero_copy]
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Default)]
+pub struct TierUsage {
+    pub day: i64, // unix day that `daily_used` belongs to
+    pub daily_used: u64,
+    pub lifetime_used: u64,
+}
+
+impl Tier {
+    /// Check `amount` against every limit, then add it to `usage`
+    pub fn record(&self, usage: &mut TierUsage, amount: u64, day: i64) -> Result<()> {
+        require!(
+            self.per_tx_limit == 0 || amount <= self.per_tx_limit,
+            VaultError::AmountExceedsLimit
+        );
+        
+        let daily_used = if usage.day == day { usage.daily_used } else { 0 };
+        let daily_used = daily_used.checked_add(amount).ok_or(VaultError::DailyLimitExceeded)?;
+        require!(
+            self.daily_limit == 0 || daily_used <= self.daily_limit,
+            VaultError::DailyLimitExceeded
+        );
+        
+        let lifetime_used = usage.lifetime_used.checked_add(amount).ok_or(VaultError::LifetimeLimitExceeded)?;
+        require!(
+            self.lifetime_limit == 0 || lifetime_used <= self.lifetime_limit,
+            VaultError::LifetimeLimitExceeded
+        );
+        
+        usage.day = day;
+        usage.daily_used = daily_used;
+        usage.lifetime_used = lifetime_used;
+        Ok(())
+    }
 }
@@ -0,0 +1,293 @@
+//! LiteSVM harness shared by the end-to-end tests and compute-unit benchmarks.
+//!
+//! Build the program first (`anchor build` or `cargo build-sbf`); the harness
+//! looks for `transfer_hook_vault.so` in `$SBF_OUT_DIR` or `target/deploy`.
+//! The tests that need it are `#[ignore]`d; run them with
+//! `cargo test -- --ignored`, and they fail if the program isn't built.
+
+#![allow(dead_code)]
+
+use std::path::PathBuf;
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use anchor_lang::solana_program::{instruction::{AccountMeta, Instruction}, program_pack::Pack, pubkey::Pubkey, system_instruction};
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{
+        transfer_hook::TransferHookAccount,
+        BaseStateWithExtensions,
+        BaseStateWithExtensionsMut,
+        StateWithExtensions,
+        StateWithExtensionsMut,
+    },
+    state::{Account as TokenAccount, Mint},
+};
+use base64::{prelude::BASE64_STANDARD, Engine};
+use litesvm::LiteSVM;
+use solana_sdk::{
+    clock::Clock,
+    instruction::InstructionError,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+use spl_token_metadata_interface::state::TokenMetadata;
+use transfer_hook_vault::{
+    client::{ix, AccountFetcher},
+    instructions::TokenMetadataArgs,
+};
+
+pub const DECIMALS: u8 = 6;
+pub const ATA_PROGRAM: &str = "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+
+// LiteSVM speaks solana 2.0 types, the program crate 1.18; convert via bytes
+
+pub fn to_sdk(key: &Pubkey) -> solana_sdk::pubkey::Pubkey {
+    solana_sdk::pubkey::Pubkey::new_from_array(key.to_bytes())
+}
+
+pub fn from_sdk(key: &solana_sdk::pubkey::Pubkey) -> Pubkey {
+    Pubkey::new_from_array(key.to_bytes())
+}
+
+pub fn to_sdk_ix(ix: Instruction) -> solana_sdk::instruction::Instruction {
+    solana_sdk::instruction::Instruction {
+        program_id: to_sdk(&ix.program_id),
+        accounts: ix
+            .accounts
+            .iter()
+            .map(|meta| solana_sdk::instruction::AccountMeta {
+                pubkey: to_sdk(&meta.pubkey),
+                is_signer: meta.is_signer,
+                is_writable: meta.is_writable,
+            })
+            .collect(),
+        data: ix.data,
+    }
+}
+
+pub fn program_so() -> PathBuf {
+    let dir = std::env::var_os("SBF_OUT_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../target/deploy"));
+    let path = dir.join("transfer_hook_vault.so");
+    assert!(path.exists(), "{} not found; run `cargo build-sbf` first", path.display());
+    path
+}
+
+pub fn associated_token_address(wallet: &Pubkey, mint: &Pubkey) -> Pubkey {
+    let ata_program: Pubkey = ATA_PROGRAM.parse().unwrap();
+    Pubkey::find_program_address(&[wallet.as_ref(), spl_token_2022::ID.as_ref(), mint.as_ref()], &ata_program).0
+}
+
+pub fn custom(code: impl Into<u32>) -> InstructionError {
+    InstructionError::Custom(code.into())
+}
+
+pub struct Env {
+    pub svm: LiteSVM,
+    pub authority: Keypair,
+    pub mint: Pubkey,
+    /// Log lines of the last transaction `send` ran, successful or not
+    pub logs: Vec<String>,
+}
+
+impl Env {
+    /// Bare SVM with the program loaded and a funded authority
+    pub fn new() -> Self {
+        let mut svm = LiteSVM::new();
+        svm.add_program_from_file(to_sdk(&transfer_hook_vault::ID), program_so()).unwrap();
+        let authority = Keypair::new();
+        svm.airdrop(&authority.pubkey(), 100_000_000_000).unwrap();
+        Self { svm, authority, mint: Pubkey::default(), logs: Vec::new() }
+    }
+    
+    /// Mint created by the program with its own transfer hook, vault and
+    /// extra metas initialized
+    pub fn with_hook_mint() -> Self {
+        Self::with_hook_mint_capacity(10)
+    }
+    
+    pub fn with_hook_mint_capacity(capacity: u32) -> Self {
+        Self::hook_mint(capacity, false, None)
+    }
+    
+    /// Hook mint whose new token accounts start frozen
+    pub fn with_default_frozen_hook_mint() -> Self {
+        Self::hook_mint(10, true, None)
+    }
+    
+    /// Hook mint carrying embedded token metadata
+    pub fn with_metadata_hook_mint(metadata: TokenMetadataArgs) -> Self {
+        Self::hook_mint(10, false, Some(metadata))
+    }
+    
+    fn hook_mint(capacity: u32, default_frozen: bool, metadata: Option<TokenMetadataArgs>) -> Self {
+        let mut env = Self::new();
+        let mint = Keypair::new();
+        env.mint = from_sdk(&mint.pubkey());
+        let authority = env.authority();
+        env.send(
+            vec![
+                ix::create_mint(&authority, &env.mint, DECIMALS, default_frozen, metadata),
+                ix::initialize(&authority, &env.mint, capacity),
+                ix::initialize_extra_metas(&authority, &authority, &env.mint),
+            ],
+            &[&mint],
+        )
+        .unwrap();
+        env
+    }
+    
+    /// Plain Token-2022 mint without a hook, so the program's own deposit
+    /// and withdraw transfers don't re-enter it
+    pub fn with_plain_mint() -> Self {
+        Self::with_plain_mint_capacity(10)
+    }
+    
+    pub fn with_plain_mint_capacity(capacity: u32) -> Self {
+        let mut env = Self::new();
+        let mint = Keypair::new();
+        env.mint = from_sdk(&mint.pubkey());
+        let authority = env.authority();
+        let lamports = env.svm.minimum_balance_for_rent_exemption(Mint::LEN);
+        env.send(
+            vec![
+                system_instruction::create_account(&authority, &env.mint, lamports, Mint::LEN as u64, &spl_token_2022::ID),
+                spl_token_2022::instruction::initialize_mint2(&spl_token_2022::ID, &env.mint, &authority, None, DECIMALS)
+                    .unwrap(),
+                ix::initialize(&authority, &env.mint, capacity),
+            ],
+            &[&mint],
+        )
+        .unwrap();
+        env
+    }
+    
+    pub fn authority(&self) -> Pubkey {
+        from_sdk(&self.authority.pubkey())
+    }
+    
+    /// Sends with the authority as fee payer and returns the compute units
+    /// consumed, then expires the blockhash so identical follow-up
+    /// transactions get distinct signatures
+    pub fn send(&mut self, ixs: Vec<Instruction>, signers: &[&Keypair]) -> Result<u64, TransactionError> {
+        let ixs: Vec<_> = ixs.into_iter().map(to_sdk_ix).collect();
+        let mut all_signers = vec![&self.authority];
+        all_signers.extend_from_slice(signers);
+        let tx = Transaction::new_signed_with_payer(
+            &ixs,
+            Some(&self.authority.pubkey()),
+            &all_signers,
+            self.svm.latest_blockhash(),
+        );
+        let result = match self.svm.send_transaction(tx) {
+            Ok(meta) => {
+                self.logs = meta.logs;
+                Ok(meta.compute_units_consumed)
+            }
+            Err(failed) => {
+                self.logs = failed.meta.logs;
+                Err(failed.err)
+            }
+        };
+        self.svm.expire_blockhash();
+        result
+    }
+    
+    /// Events of type `E` decoded from the `Program data:` lines of the
+    /// last transaction, in emission order
+    pub fn events<E: AnchorDeserialize + Discriminator>(&self) -> Vec<E> {
+        self.logs
+            .iter()
+            .filter_map(|line| line.strip_prefix("Program data: "))
+            .filter_map(|data| BASE64_STANDARD.decode(data).ok())
+            .filter(|data| data.starts_with(&E::DISCRIMINATOR))
+            .map(|data| E::try_from_slice(&data[8..]).unwrap())
+            .collect()
+    }
+    
+    /// New funded wallet with an associated token account
+    pub fn user(&mut self) -> (Keypair, Pubkey) {
+        let user = Keypair::new();
+        self.svm.airdrop(&user.pubkey(), 1_000_000_000).unwrap();
+        let wallet = from_sdk(&user.pubkey());
+        let ata = self.token_account(&wallet);
+        (user, ata)
+    }
+    
+    pub fn token_account(&mut self, wallet: &Pubkey) -> Pubkey {
+        let ata = associated_token_address(wallet, &self.mint);
+        let create = Instruction {
+            program_id: ATA_PROGRAM.parse().unwrap(),
+            accounts: vec![
+                AccountMeta::new(self.authority(), true),
+                AccountMeta::new(ata, false),
+                AccountMeta::new_readonly(*wallet, false),
+                AccountMeta::new_readonly(self.mint, false),
+                AccountMeta::new_readonly(anchor_lang::system_program::ID, false),
+                AccountMeta::new_readonly(spl_token_2022::ID, false),
+            ],
+            // CreateIdempotent
+            data: vec![1],
+        };
+        self.send(vec![create], &[]).unwrap();
+        ata
+    }
+    
+    pub fn mint_to(&mut self, destination: &Pubkey, amount: u64) {
+        let authority = self.authority();
+        self.send(vec![ix::mint_tokens(&authority, &self.mint, destination, amount)], &[]).unwrap();
+    }
+    
+    pub fn is_frozen(&self, token_account: &Pubkey) -> bool {
+        let account = self.svm.get_account(&to_sdk(token_account)).unwrap();
+        StateWithExtensions::<TokenAccount>::unpack(&account.data).unwrap().base.is_frozen()
+    }
+    
+    /// The mint's embedded metadata, and whether the mint is still rent exempt
+    pub fn token_metadata(&self) -> (TokenMetadata, bool) {
+        let account = self.svm.get_account(&to_sdk(&self.mint)).unwrap();
+        let mint = StateWithExtensions::<Mint>::unpack(&account.data).unwrap();
+        let rent_exempt = account.lamports >= self.svm.minimum_balance_for_rent_exemption(account.data.len());
+        (mint.get_variable_len_extension::<TokenMetadata>().unwrap(), rent_exempt)
+    }
+    
+    /// Sets the flag Token-2022 raises on the source around the hook CPI, so
+    /// the hook can be called on its own
+    pub fn set_transferring(&mut self, token_account: &Pubkey, transferring: bool) {
+        let address = to_sdk(token_account);
+        let mut account = self.svm.get_account(&address).unwrap();
+        let mut state = StateWithExtensionsMut::<TokenAccount>::unpack(&mut account.data).unwrap();
+        state.get_extension_mut::<TransferHookAccount>().unwrap().transferring = transferring.into();
+        self.svm.set_account(address, account).unwrap();
+    }
+    
+    pub fn balance(&self, token_account: &Pubkey) -> u64 {
+        let account = self.svm.get_account(&to_sdk(token_account)).unwrap();
+        StateWithExtensions::<TokenAccount>::unpack(&account.data).unwrap().base.amount
+    }
+    
+    pub fn transfer(&mut self, owner: &Keypair, source: &Pubkey, destination: &Pubkey, amount: u64) -> Result<u64, TransactionError> {
+        let transfer = ix::transfer_checked_with_hook(
+            source,
+            &self.mint,
+            destination,
+            &from_sdk(&owner.pubkey()),
+            amount,
+            DECIMALS,
+        )
+        .unwrap();
+        self.send(vec![transfer], &[owner])
+    }
+    
+    pub fn warp_days(&mut self, days: i64) {
+        let mut clock: Clock = self.svm.get_sysvar();
+        clock.unix_timestamp += days * transfer_hook_vault::constants::SECONDS_PER_DAY;
+        self.svm.set_sysvar(&clock);
+    }
+}
+
+impl AccountFetcher for Env {
+    fn get_account_data(&self, address: &Pubkey) -> Option<Vec<u8>> {
+        self.svm.get_account(&to_sdk(address)).map(|account| account.data)
+    }
+}
@@ -0,0 +1,183 @@
+//! Compute-unit benchmarks for the transfer hook and the vault instructions,
+//! run in LiteSVM; see `common` for how the program is loaded.
+//!
+//! `cargo test --test compute_units -- --ignored --nocapture` prints the report. A
+//! scenario fails when it costs more than `CU_TOLERANCE_PCT` percent
+//! (default 5) over its entry in `compute_units.baseline`; run with
+//! `CU_BASELINE_UPDATE=1` to record the current build as the new baseline.
+
+mod common;
+
+use std::collections::BTreeMap;
+use anchor_lang::solana_program::pubkey::Pubkey;
+use common::*;
+use solana_sdk::signature::{Keypair, Signer};
+use transfer_hook_vault::{client::ix, constants::MAX_WHITELIST_ENTRIES};
+
+const BASELINE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/compute_units.baseline");
+const DEFAULT_TOLERANCE_PCT: u64 = 5;
+/// add_to_whitelist instructions per transaction while filling the Vec
+const ADDS_PER_TX: usize = 8;
+
+/// Hook mint whose Vec whitelist holds `entries` users; the sender is one
+/// of them, or else has a PDA entry
+struct HookBench {
+    env: Env,
+    sender: Keypair,
+    source: Pubkey,
+    destination: Pubkey,
+}
+
+impl HookBench {
+    /// `tier_id` applies to the Vec entries and is created with a daily limit
+    fn new(entries: usize, sender_in_vec: bool, tier_id: u8) -> Self {
+        let mut env = Env::with_hook_mint_capacity(MAX_WHITELIST_ENTRIES as u32);
+        let (sender, source) = env.user();
+        let (_, destination) = env.user();
+        env.mint_to(&source, 1_000_000);
+
+        let sender_key = from_sdk(&sender.pubkey());
+        let authority = env.authority();
+        if tier_id != 0 {
+            env.send(vec![ix::create_tier(&authority, &env.mint, tier_id, 0, 1_000_000, 0)], &[]).unwrap();
+        }
+        let fillers = entries - usize::from(sender_in_vec);
+        let mut users: Vec<Pubkey> = (0..fillers).map(|_| Pubkey::new_unique()).collect();
+        if sender_in_vec {
+            users.push(sender_key);
+        } else {
+            env.send(vec![ix::pda_whitelist_add(&authority, &env.mint, &sender_key, 0, 0, None)], &[]).unwrap();
+        }
+        fill_whitelist(&mut env, &users, tier_id);
+        Self { env, sender, source, destination }
+    }
+
+    /// The hook called on its own, so only this program's cost is counted;
+    /// the source is flagged as Token-2022 does mid-transfer
+    fn hook(&mut self) -> u64 {
+        let hook = ix::transfer_hook(
+            &self.source,
+            &self.env.mint,
+            &self.destination,
+            &from_sdk(&self.sender.pubkey()),
+            100,
+        );
+        self.env.set_transferring(&self.source, true);
+        let units = self.env.send(vec![hook], &[]).unwrap();
+        self.env.set_transferring(&self.source, false);
+        units
+    }
+
+    /// A full Token-2022 `transfer_checked`, hook included
+    fn transfer(&mut self) -> u64 {
+        let (source, destination) = (self.source, self.destination);
+        self.env.transfer(&self.sender, &source, &destination, 100).unwrap()
+    }
+}
+
+fn fill_whitelist(env: &mut Env, users: &[Pubkey], tier_id: u8) {
+    let authority = env.authority();
+    for batch in users.chunks(ADDS_PER_TX) {
+        let adds = batch
+            .iter()
+            .map(|user| ix::add_to_whitelist(&authority, &env.mint, user, 0, tier_id, None))
+            .collect();
+        env.send(adds, &[]).unwrap();
+    }
+}
+
+fn measure() -> BTreeMap<String, u64> {
+    let mut results = BTreeMap::new();
+
+    for entries in [1, 50, MAX_WHITELIST_ENTRIES] {
+        let mut bench = HookBench::new(entries, true, 0);
+        results.insert(format!("hook/vec-{entries}"), bench.hook());
+        results.insert(format!("transfer/vec-{entries}"), bench.transfer());
+    }
+
+    // PDA lookup on its own, and behind a Vec miss at near-full capacity
+    for vec_entries in [0, MAX_WHITELIST_ENTRIES - 1] {
+        let mut bench = HookBench::new(vec_entries, false, 0);
+        results.insert(format!("hook/pda-vec-{vec_entries}"), bench.hook());
+        results.insert(format!("transfer/pda-vec-{vec_entries}"), bench.transfer());
+    }
+
+    // Tier limits add a tier account read and usage bookkeeping
+    let mut bench = HookBench::new(50, true, 1);
+    results.insert("hook/vec-50-tier".into(), bench.hook());
+
+    // Program deposit and withdraw can only be measured on a mint without
+    // the hook (see e2e_tests), so these are the vault's own costs
+    for entries in [1, MAX_WHITELIST_ENTRIES] {
+        let mut env = Env::with_plain_mint_capacity(MAX_WHITELIST_ENTRIES as u32);
+        let (user, token_account) = env.user();
+        let user_key = from_sdk(&user.pubkey());
+        env.mint_to(&token_account, 1_000_000);
+        let users: Vec<Pubkey> = (1..entries).map(|_| Pubkey::new_unique()).chain([user_key]).collect();
+        fill_whitelist(&mut env, &users, 0);
+
+        let deposit = ix::deposit(&user_key, &env.mint, &token_account, 1_000, 0, None);
+        results.insert(format!("deposit/vec-{entries}"), env.send(vec![deposit], &[&user]).unwrap());
+        let withdraw = ix::withdraw(&user_key, &env.mint, &token_account, 1_000, 0, None);
+        results.insert(format!("withdraw/vec-{entries}"), env.send(vec![withdraw], &[&user]).unwrap());
+    }
+
+    results
+}
+
+fn read_baseline() -> BTreeMap<String, u64> {
+    let Ok(text) = std::fs::read_to_string(BASELINE) else { return BTreeMap::new() };
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let (name, units) = line.split_once(char::is_whitespace).expect("`<scenario> <units>` per line");
+            (name.to_string(), units.trim().parse().expect("compute units must be an integer"))
+        })
+        .collect()
+}
+
+fn write_baseline(results: &BTreeMap<String, u64>) {
+    let mut text = String::from("# scenario compute-units; regenerate with CU_BASELINE_UPDATE=1\n");
+    for (name, units) in results {
+        text.push_str(&format!("{name} {units}\n"));
+    }
+    std::fs::write(BASELINE, text).unwrap();
+}
+
+#[test]
+#[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+fn compute_units_within_baseline() {
+    let results = measure();
+    let baseline = read_baseline();
+    let tolerance: u64 = std::env::var("CU_TOLERANCE_PCT")
+        .ok()
+        .map(|pct| pct.parse().expect("CU_TOLERANCE_PCT must be an integer"))
+        .unwrap_or(DEFAULT_TOLERANCE_PCT);
+
+    println!("{:<24} {:>8} {:>9} {:>8}", "scenario", "CU", "baseline", "delta");
+    let mut regressions = Vec::new();
+    for (name, &units) in &results {
+        match baseline.get(name) {
+            Some(&base) => {
+                let delta = (units as f64 - base as f64) / base as f64 * 100.0;
+                println!("{name:<24} {units:>8} {base:>9} {delta:>+7.1}%");
+                if units * 100 > base * (100 + tolerance) {
+                    regressions.push(format!("{name}: {units} CU vs baseline {base}"));
+                }
+            }
+            None => println!("{name:<24} {units:>8} {:>9} {:>8}", "-", "-"),
+        }
+    }
+
+    if std::env::var_os("CU_BASELINE_UPDATE").is_some() {
+        write_baseline(&results);
+        println!("baseline written to {BASELINE}");
+        return;
+    }
+    assert!(
+        regressions.is_empty(),
+        "compute units regressed more than {tolerance}%:\n{}",
+        regressions.join("\n")
+    );
+}
@@ -0,0 +1,695 @@
+//! End-to-end tests that run the compiled program in LiteSVM against the
+//! bundled Token-2022 program; see `common` for how the program is loaded.
+
+mod common;
+
+use anchor_lang::solana_program::pubkey::Pubkey;
+use anchor_spl::token_2022::spl_token_2022::{error::TokenError, extension::StateWithExtensions, state::Mint};
+use common::*;
+use solana_sdk::{
+    instruction::InstructionError,
+    signature::Signer,
+    transaction::TransactionError,
+};
+use transfer_hook_vault::{
+    client::{ix, pda::*},
+    error::VaultError,
+    instructions::{MetadataField, TokenMetadataArgs},
+};
+
+fn assert_custom<T: std::fmt::Debug>(result: Result<T, TransactionError>, code: VaultError) {
+    match result {
+        Err(TransactionError::InstructionError(_, err)) => assert_eq!(err, custom(code)),
+        other => panic!("expected {code:?}, got {other:?}"),
+    }
+}
+
+#[cfg(test)]
+mod test_hook_mint {
+    use super::*;
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn whitelisted_transfer_passes_the_hook() {
+        let mut env = Env::with_hook_mint();
+        let (alice, alice_ata) = env.user();
+        let (_, bob_ata) = env.user();
+        let authority = env.authority();
+        env.mint_to(&alice_ata, 1_000);
+        env.send(vec![ix::add_to_whitelist(&authority, &env.mint, &from_sdk(&alice.pubkey()), 500, 0, None)], &[])
+            .unwrap();
+    
+        env.transfer(&alice, &alice_ata, &bob_ata, 400).unwrap();
+        assert_eq!(env.balance(&alice_ata), 600);
+        assert_eq!(env.balance(&bob_ata), 400);
+    }
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn non_whitelisted_transfer_is_rejected() {
+        let mut env = Env::with_hook_mint();
+        let (alice, alice_ata) = env.user();
+        let (_, bob_ata) = env.user();
+        env.mint_to(&alice_ata, 1_000);
+    
+        assert_custom(env.transfer(&alice, &alice_ata, &bob_ata, 1), VaultError::TransferHookValidationFailed);
+        assert_eq!(env.balance(&alice_ata), 1_000);
+    }
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn transfer_over_limit_is_rejected() {
+        let mut env = Env::with_hook_mint();
+        let (alice, alice_ata) = env.user();
+        let (_, bob_ata) = env.user();
+        let authority = env.authority();
+        env.mint_to(&alice_ata, 1_000);
+        env.send(vec![ix::add_to_whitelist(&authority, &env.mint, &from_sdk(&alice.pubkey()), 500, 0, None)], &[])
+            .unwrap();
+    
+        assert_custom(env.transfer(&alice, &alice_ata, &bob_ata, 501), VaultError::AmountExceedsLimit);
+    }
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn removal_revokes_transfers() {
+        let mut env = Env::with_hook_mint();
+        let (alice, alice_ata) = env.user();
+        let (_, bob_ata) = env.user();
+        let authority = env.authority();
+        let user = from_sdk(&alice.pubkey());
+        env.mint_to(&alice_ata, 1_000);
+        env.send(vec![ix::add_to_whitelist(&authority, &env.mint, &user, 0, 0, None)], &[]).unwrap();
+        env.transfer(&alice, &alice_ata, &bob_ata, 100).unwrap();
+    
+        env.send(vec![ix::remove_from_whitelist(&authority, &env.mint, &user, None)], &[]).unwrap();
+        assert_custom(env.transfer(&alice, &alice_ata, &bob_ata, 100), VaultError::TransferHookValidationFailed);
+    }
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn pda_entry_passes_the_hook() {
+        let mut env = Env::with_hook_mint();
+        let (carol, carol_ata) = env.user();
+        let (_, bob_ata) = env.user();
+        let authority = env.authority();
+        env.mint_to(&carol_ata, 1_000);
+        env.send(vec![ix::pda_whitelist_add(&authority, &env.mint, &from_sdk(&carol.pubkey()), 0, 0, None)], &[])
+            .unwrap();
+    
+        env.transfer(&carol, &carol_ata, &bob_ata, 250).unwrap();
+        assert_eq!(env.balance(&bob_ata), 250);
+    }
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn transfers_into_the_vault_are_always_allowed() {
+        let mut env = Env::with_hook_mint();
+        let (alice, alice_ata) = env.user();
+        let vault = derive_vault(&env.mint).0;
+        env.mint_to(&alice_ata, 1_000);
+    
+        env.transfer(&alice, &alice_ata, &vault, 700).unwrap();
+        assert_eq!(env.balance(&vault), 700);
+    }
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn tier_daily_limit_resets_next_day() {
+        let mut env = Env::with_hook_mint();
+        let (alice, alice_ata) = env.user();
+        let (_, bob_ata) = env.user();
+        let authority = env.authority();
+        env.mint_to(&alice_ata, 1_000);
+        env.send(
+            vec![
+                ix::create_tier(&authority, &env.mint, 1, 0, 300, 0),
+                ix::add_to_whitelist(&authority, &env.mint, &from_sdk(&alice.pubkey()), 0, 1, None),
+            ],
+            &[],
+        )
+        .unwrap();
+    
+        env.transfer(&alice, &alice_ata, &bob_ata, 200).unwrap();
+        assert_custom(env.transfer(&alice, &alice_ata, &bob_ata, 200), VaultError::DailyLimitExceeded);
+    
+        env.warp_days(1);
+        env.transfer(&alice, &alice_ata, &bob_ata, 200).unwrap();
+        assert_eq!(env.balance(&bob_ata), 400);
+    }
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn direct_hook_calls_cannot_spend_a_users_quota() {
+        let mut env = Env::with_hook_mint();
+        let (alice, alice_ata) = env.user();
+        let (_, mallory_ata) = env.user();
+        let authority = env.authority();
+        let alice_key = from_sdk(&alice.pubkey());
+        env.mint_to(&alice_ata, 1_000);
+        env.send(
+            vec![
+                ix::create_tier(&authority, &env.mint, 1, 0, 300, 0),
+                ix::add_to_whitelist(&authority, &env.mint, &alice_key, 0, 1, None),
+            ],
+            &[],
+        )
+        .unwrap();
+    
+        let spend = ix::transfer_hook(&alice_ata, &env.mint, &mallory_ata, &alice_key, 300);
+        assert_custom(env.send(vec![spend], &[]), VaultError::NotTransferring);
+        // Even mid-transfer, the source must belong to the authority named
+        env.set_transferring(&mallory_ata, true);
+        let spend = ix::transfer_hook(&mallory_ata, &env.mint, &alice_ata, &alice_key, 300);
+        assert_custom(env.send(vec![spend], &[]), VaultError::HolderMismatch);
+        env.set_transferring(&mallory_ata, false);
+    
+        env.transfer(&alice, &alice_ata, &mallory_ata, 300).unwrap();
+    }
+    
+    /// The hook would call back into this program from inside its own
+    /// transfer CPI, which the runtime forbids; hook mints are deposited by
+    /// transferring straight to the vault instead.
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn program_deposit_cannot_reenter_the_hook() {
+        let mut env = Env::with_hook_mint();
+        let (alice, alice_ata) = env.user();
+        let authority = env.authority();
+        let user = from_sdk(&alice.pubkey());
+        env.mint_to(&alice_ata, 1_000);
+        env.send(vec![ix::add_to_whitelist(&authority, &env.mint, &user, 0, 0, None)], &[]).unwrap();
+    
+        let mut deposit = ix::deposit(&user, &env.mint, &alice_ata, 100, 0, None);
+        deposit.accounts.extend(ix::transfer_hook_accounts(&env.mint, &user));
+        assert_eq!(
+            env.send(vec![deposit], &[&alice]),
+            Err(TransactionError::InstructionError(0, InstructionError::ReentrancyNotAllowed))
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_freeze {
+    use super::*;
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn vault_config_is_the_freeze_authority() {
+        let env = Env::with_hook_mint();
+        let account = env.svm.get_account(&to_sdk(&env.mint)).unwrap();
+        let mint = StateWithExtensions::<Mint>::unpack(&account.data).unwrap();
+    
+        assert_eq!(Option::<Pubkey>::from(mint.base.freeze_authority), Some(derive_vault_config(&env.mint).0));
+    }
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn frozen_account_cannot_transfer_until_thawed() {
+        let mut env = Env::with_hook_mint();
+        let (alice, alice_ata) = env.user();
+        let (_, bob_ata) = env.user();
+        let authority = env.authority();
+        env.mint_to(&alice_ata, 1_000);
+        env.send(vec![ix::add_to_whitelist(&authority, &env.mint, &from_sdk(&alice.pubkey()), 0, 0, None)], &[])
+            .unwrap();
+    
+        env.send(vec![ix::freeze_account(&authority, &env.mint, &alice_ata)], &[]).unwrap();
+        assert_eq!(
+            env.transfer(&alice, &alice_ata, &bob_ata, 100).unwrap_err(),
+            TransactionError::InstructionError(0, custom(TokenError::AccountFrozen as u32))
+        );
+    
+        env.send(vec![ix::thaw_account(&authority, &env.mint, &alice_ata)], &[]).unwrap();
+        env.transfer(&alice, &alice_ata, &bob_ata, 100).unwrap();
+        assert_eq!(env.balance(&bob_ata), 100);
+    }
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn only_the_authority_can_freeze() {
+        let mut env = Env::with_hook_mint();
+        let (mallory, _) = env.user();
+        let (_, bob_ata) = env.user();
+        let freeze = ix::freeze_account(&from_sdk(&mallory.pubkey()), &env.mint, &bob_ata);
+    
+        assert_custom(env.send(vec![freeze], &[&mallory]), VaultError::UnauthorizedAuthority);
+    }
+}
+
+#[cfg(test)]
+mod test_metadata {
+    use super::*;
+    
+    fn metadata_env() -> Env {
+        Env::with_metadata_hook_mint(TokenMetadataArgs {
+            name: "Vault".to_string(),
+            symbol: "VLT".to_string(),
+            uri: "https://example.com/vlt.json".to_string(),
+        })
+    }
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn create_mint_embeds_metadata() {
+        let env = metadata_env();
+        let (metadata, rent_exempt) = env.token_metadata();
+    
+        assert_eq!((metadata.name.as_str(), metadata.symbol.as_str()), ("Vault", "VLT"));
+        assert_eq!(metadata.mint, env.mint);
+        assert_eq!(Option::<Pubkey>::from(metadata.update_authority), Some(derive_vault_config(&env.mint).0));
+        assert!(rent_exempt);
+    }
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn updates_grow_the_mint_and_keep_it_rent_exempt() {
+        let mut env = metadata_env();
+        let authority = env.authority();
+        let name = ix::update_token_metadata_field(&authority, &env.mint, MetadataField::Name, "Vault Token Series A".into());
+        let key = ix::update_token_metadata_field(
+            &authority,
+            &env.mint,
+            MetadataField::Key("jurisdiction".into()),
+            "EU".into(),
+        );
+        env.send(vec![name, key], &[]).unwrap();
+    
+        let (metadata, rent_exempt) = env.token_metadata();
+        assert_eq!(metadata.name, "Vault Token Series A");
+        assert_eq!(metadata.additional_metadata, vec![("jurisdiction".to_string(), "EU".to_string())]);
+        assert!(rent_exempt);
+    }
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn remove_metadata_key_is_optionally_idempotent() {
+        let mut env = metadata_env();
+        let authority = env.authority();
+        let set = ix::update_token_metadata_field(&authority, &env.mint, MetadataField::Key("region".into()), "EU".into());
+        env.send(vec![set, ix::remove_metadata_key(&authority, &env.mint, "region".into(), false)], &[]).unwrap();
+        assert!(env.token_metadata().0.additional_metadata.is_empty());
+    
+        assert!(env.send(vec![ix::remove_metadata_key(&authority, &env.mint, "region".into(), false)], &[]).is_err());
+        env.send(vec![ix::remove_metadata_key(&authority, &env.mint, "region".into(), true)], &[]).unwrap();
+    }
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn only_the_authority_can_update_metadata() {
+        let mut env = metadata_env();
+        let (mallory, _) = env.user();
+        let update = ix::update_token_metadata_field(&from_sdk(&mallory.pubkey()), &env.mint, MetadataField::Name, "x".into());
+    
+        assert_custom(env.send(vec![update], &[&mallory]), VaultError::UnauthorizedAuthority);
+    }
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn update_without_metadata_is_rejected() {
+        let mut env = Env::with_hook_mint();
+        let authority = env.authority();
+        let update = ix::update_token_metadata_field(&authority, &env.mint, MetadataField::Name, "x".into());
+    
+        assert_custom(env.send(vec![update], &[]), VaultError::MetadataNotInitialized);
+    }
+}
+
+#[cfg(test)]
+mod test_default_frozen {
+    use super::*;
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn whitelisting_thaws_the_holder() {
+        let mut env = Env::with_default_frozen_hook_mint();
+        let (alice, alice_ata) = env.user();
+        let authority = env.authority();
+        let vault = derive_vault(&env.mint).0;
+        assert!(env.is_frozen(&alice_ata));
+        assert!(!env.is_frozen(&vault));
+    
+        let add = ix::add_to_whitelist(&authority, &env.mint, &from_sdk(&alice.pubkey()), 0, 0, Some(&alice_ata));
+        env.send(vec![add], &[]).unwrap();
+        assert!(!env.is_frozen(&alice_ata));
+        env.mint_to(&alice_ata, 1_000);
+        env.transfer(&alice, &alice_ata, &vault, 400).unwrap();
+        assert_eq!(env.balance(&vault), 400);
+    }
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn pda_removal_refreezes_the_holder() {
+        let mut env = Env::with_default_frozen_hook_mint();
+        let (carol, carol_ata) = env.user();
+        let authority = env.authority();
+        let user = from_sdk(&carol.pubkey());
+    
+        env.send(vec![ix::pda_whitelist_add(&authority, &env.mint, &user, 0, 0, Some(&carol_ata))], &[]).unwrap();
+        assert!(!env.is_frozen(&carol_ata));
+        env.send(vec![ix::pda_whitelist_remove(&authority, &env.mint, &user, Some(&carol_ata))], &[]).unwrap();
+        assert!(env.is_frozen(&carol_ata));
+    }
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn holder_account_must_belong_to_the_user() {
+        let mut env = Env::with_default_frozen_hook_mint();
+        let (alice, _) = env.user();
+        let (_, mallory_ata) = env.user();
+        let authority = env.authority();
+    
+        let add = ix::add_to_whitelist(&authority, &env.mint, &from_sdk(&alice.pubkey()), 0, 0, Some(&mallory_ata));
+        assert_custom(env.send(vec![add], &[]), VaultError::HolderMismatch);
+        assert!(env.is_frozen(&mallory_ata));
+    }
+}
+
+#[cfg(test)]
+mod test_initialize {
+    use super::*;
+    use solana_sdk::signature::Keypair;
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn prefunded_vault_address_does_not_block_initialize() {
+        let mut env = Env::new();
+        let authority = env.authority();
+        let mint = Keypair::new();
+        env.mint = from_sdk(&mint.pubkey());
+        env.send(vec![ix::create_mint(&authority, &env.mint, DECIMALS, false, None)], &[&mint]).unwrap();
+    
+        let vault = derive_vault(&env.mint).0;
+        env.svm.airdrop(&to_sdk(&vault), 1).unwrap();
+        env.send(vec![ix::initialize(&authority, &env.mint, 10)], &[]).unwrap();
+    
+        let account = env.svm.get_account(&to_sdk(&vault)).unwrap();
+        assert_eq!(from_sdk(&account.owner), anchor_spl::token_2022::ID);
+        assert!(account.lamports >= env.svm.minimum_balance_for_rent_exemption(account.data.len()));
+        assert_eq!(env.balance(&vault), 0);
+    }
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn only_the_mint_authority_can_initialize() {
+        let mut env = Env::new();
+        let authority = env.authority();
+        let mint = Keypair::new();
+        env.mint = from_sdk(&mint.pubkey());
+        env.send(vec![ix::create_mint(&authority, &env.mint, DECIMALS, false, None)], &[&mint]).unwrap();
+        let mallory = Keypair::new();
+        env.svm.airdrop(&mallory.pubkey(), 1_000_000_000).unwrap();
+    
+        let front_run = ix::initialize(&from_sdk(&mallory.pubkey()), &env.mint, 10);
+        assert_custom(env.send(vec![front_run], &[&mallory]), VaultError::NotMintAuthority);
+        env.send(vec![ix::initialize(&authority, &env.mint, 10)], &[]).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod test_plain_mint {
+    use super::*;
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn deposit_and_withdraw_move_tokens() {
+        let mut env = Env::with_plain_mint();
+        let (alice, alice_ata) = env.user();
+        let authority = env.authority();
+        let user = from_sdk(&alice.pubkey());
+        let vault = derive_vault(&env.mint).0;
+        env.mint_to(&alice_ata, 1_000);
+        env.send(vec![ix::add_to_whitelist(&authority, &env.mint, &user, 0, 0, None)], &[]).unwrap();
+    
+        env.send(vec![ix::deposit(&user, &env.mint, &alice_ata, 600, 0, None)], &[&alice]).unwrap();
+        assert_eq!(env.balance(&alice_ata), 400);
+        assert_eq!(env.balance(&vault), 600);
+    
+        env.send(vec![ix::withdraw(&user, &env.mint, &alice_ata, 250, 0, None)], &[&alice]).unwrap();
+        assert_eq!(env.balance(&alice_ata), 650);
+        assert_eq!(env.balance(&vault), 350);
+    }
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn non_whitelisted_deposit_is_rejected() {
+        let mut env = Env::with_plain_mint();
+        let (alice, alice_ata) = env.user();
+        let user = from_sdk(&alice.pubkey());
+        env.mint_to(&alice_ata, 1_000);
+    
+        assert_custom(
+            env.send(vec![ix::deposit(&user, &env.mint, &alice_ata, 100, 0, None)], &[&alice]),
+            VaultError::NotWhitelisted,
+        );
+    }
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn withdraw_over_limit_is_rejected() {
+        let mut env = Env::with_plain_mint();
+        let (alice, alice_ata) = env.user();
+        let authority = env.authority();
+        let user = from_sdk(&alice.pubkey());
+        env.mint_to(&alice_ata, 1_000);
+        env.send(vec![ix::add_to_whitelist(&authority, &env.mint, &user, 500, 0, None)], &[]).unwrap();
+        env.send(vec![ix::deposit(&user, &env.mint, &alice_ata, 500, 0, None)], &[&alice]).unwrap();
+    
+        assert_custom(
+            env.send(vec![ix::withdraw(&user, &env.mint, &alice_ata, 501, 0, None)], &[&alice]),
+            VaultError::AmountExceedsLimit,
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_close_vault {
+    use super::*;
+    
+    fn exists(env: &Env, address: &Pubkey) -> bool {
+        env.svm.get_account(&to_sdk(address)).is_some_and(|account| account.lamports > 0)
+    }
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn closes_every_program_account_to_the_authority() {
+        let mut env = Env::with_hook_mint();
+        let authority = env.authority();
+        let carol = Pubkey::new_unique();
+        let vault_config = derive_vault_config(&env.mint).0;
+        let entry = derive_whitelist_entry_pda(&vault_config, &carol).0;
+        let tier = derive_tier(&vault_config, 1).0;
+        env.send(
+            vec![
+                ix::create_tier(&authority, &env.mint, 1, 0, 300, 0),
+                ix::pda_whitelist_add(&authority, &env.mint, &carol, 0, 1, None),
+            ],
+            &[],
+        )
+        .unwrap();
+        let accounts = [
+            vault_config,
+            derive_whitelist(&env.mint).0,
+            derive_extra_metas(&env.mint).0,
+            derive_vault(&env.mint).0,
+            entry,
+            tier,
+        ];
+        let before = env.svm.get_balance(&to_sdk(&authority)).unwrap();
+    
+        env.send(vec![ix::close_vault(&authority, &env.mint, &[entry, tier])], &[]).unwrap();
+    
+        assert!(accounts.iter().all(|address| !exists(&env, address)));
+        assert!(env.svm.get_balance(&to_sdk(&authority)).unwrap() > before);
+    }
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn vault_holding_tokens_cannot_close() {
+        let mut env = Env::with_plain_mint();
+        let (alice, alice_ata) = env.user();
+        let authority = env.authority();
+        let user = from_sdk(&alice.pubkey());
+        env.mint_to(&alice_ata, 1_000);
+        env.send(vec![ix::add_to_whitelist(&authority, &env.mint, &user, 0, 0, None)], &[]).unwrap();
+        env.send(vec![ix::deposit(&user, &env.mint, &alice_ata, 100, 0, None)], &[&alice]).unwrap();
+    
+        assert_custom(env.send(vec![ix::close_vault(&authority, &env.mint, &[])], &[]), VaultError::VaultNotEmpty);
+    
+        env.send(vec![ix::withdraw(&user, &env.mint, &alice_ata, 100, 0, None)], &[&alice]).unwrap();
+        env.send(vec![ix::close_vault(&authority, &env.mint, &[])], &[]).unwrap();
+        assert!(!exists(&env, &derive_vault_config(&env.mint).0));
+    }
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn accounts_outside_the_vault_are_rejected() {
+        let mut env = Env::with_hook_mint();
+        let (_, alice_ata) = env.user();
+        let authority = env.authority();
+    
+        assert_custom(
+            env.send(vec![ix::close_vault(&authority, &env.mint, &[alice_ata])], &[]),
+            VaultError::ForeignAccount,
+        );
+    }
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn only_the_authority_can_close() {
+        let mut env = Env::with_hook_mint();
+        let (mallory, _) = env.user();
+        let close = ix::close_vault(&from_sdk(&mallory.pubkey()), &env.mint, &[]);
+    
+        assert_custom(env.send(vec![close], &[&mallory]), VaultError::UnauthorizedAuthority);
+    }
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn mint_authorities_pass_to_the_authority() {
+        let mut env = Env::with_metadata_hook_mint(TokenMetadataArgs {
+            name: "Vault".to_string(),
+            symbol: "VLT".to_string(),
+            uri: "https://example.com/vlt.json".to_string(),
+        });
+        let authority = env.authority();
+    
+        env.send(vec![ix::close_vault(&authority, &env.mint, &[])], &[]).unwrap();
+    
+        let account = env.svm.get_account(&to_sdk(&env.mint)).unwrap();
+        let mint = StateWithExtensions::<Mint>::unpack(&account.data).unwrap();
+        assert_eq!(Option::<Pubkey>::from(mint.base.freeze_authority), Some(authority));
+        assert_eq!(Option::<Pubkey>::from(env.token_metadata().0.update_authority), Some(authority));
+    }
+}
+
+#[cfg(test)]
+mod test_resize_whitelist {
+    use super::*;
+    use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+    use transfer_hook_vault::{constants::MAX_WHITELIST_ENTRIES, state::Whitelist};
+    
+    const STEP: u32 = (MAX_PERMITTED_DATA_INCREASE / Whitelist::ENTRY_LEN) as u32;
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn growth_is_capped_per_call() {
+        let mut env = Env::with_hook_mint();
+        let authority = env.authority();
+        let capacity = MAX_WHITELIST_ENTRIES as u32;
+    
+        assert_custom(
+            env.send(vec![ix::resize_whitelist(&authority, &env.mint, capacity + STEP + 1)], &[]),
+            VaultError::ResizeTooLarge,
+        );
+    
+        env.send(vec![ix::resize_whitelist(&authority, &env.mint, capacity + STEP)], &[]).unwrap();
+        env.send(vec![ix::resize_whitelist(&authority, &env.mint, capacity + 2 * STEP)], &[]).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod test_events {
+    use super::*;
+    use transfer_hook_vault::events::*;
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn whitelisting_and_transfers_log_their_events() {
+        let mut env = Env::with_hook_mint();
+        let (alice, alice_ata) = env.user();
+        let (_, bob_ata) = env.user();
+        let authority = env.authority();
+        let alice_key = from_sdk(&alice.pubkey());
+        env.mint_to(&alice_ata, 1_000);
+    
+        env.send(vec![ix::add_to_whitelist(&authority, &env.mint, &alice_key, 500, 0, None)], &[]).unwrap();
+        let added = env.events::<WhitelistAdded>();
+        assert_eq!(added.len(), 1);
+        assert_eq!(added[0].user, alice_key);
+        assert_eq!(added[0].max_amount, 500);
+        assert!(!added[0].pda_entry);
+    
+        env.transfer(&alice, &alice_ata, &bob_ata, 400).unwrap();
+        let validated = env.events::<TransferValidated>();
+        assert_eq!(validated.len(), 1);
+        assert_eq!(validated[0].source_authority, alice_key);
+        assert_eq!(validated[0].amount, 400);
+        assert!(env.events::<TransferRejected>().is_empty());
+    }
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn hook_rejection_logs_transfer_rejected() {
+        let mut env = Env::with_hook_mint();
+        let (alice, alice_ata) = env.user();
+        let (_, bob_ata) = env.user();
+        env.mint_to(&alice_ata, 1_000);
+    
+        assert!(env.transfer(&alice, &alice_ata, &bob_ata, 7).is_err());
+        let rejected = env.events::<TransferRejected>();
+        assert_eq!(rejected.len(), 1);
+        assert_eq!(rejected[0].source, alice_ata);
+        assert_eq!(rejected[0].destination, bob_ata);
+        assert_eq!(rejected[0].source_authority, from_sdk(&alice.pubkey()));
+        assert_eq!(rejected[0].amount, 7);
+        assert_eq!(rejected[0].error_code, u32::from(VaultError::TransferHookValidationFailed));
+        assert!(env.events::<TransferValidated>().is_empty());
+    }
+}
+
+#[cfg(test)]
+mod test_update_entry {
+    use super::*;
+    use transfer_hook_vault::client::{fetch_whitelist, fetch_whitelist_entry_pda};
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn vec_update_keeps_usage() {
+        let mut env = Env::with_hook_mint();
+        let (alice, alice_ata) = env.user();
+        let (_, bob_ata) = env.user();
+        let authority = env.authority();
+        let user = from_sdk(&alice.pubkey());
+        env.mint_to(&alice_ata, 1_000);
+        env.send(vec![ix::add_to_whitelist(&authority, &env.mint, &user, 500, 0, None)], &[]).unwrap();
+        env.transfer(&alice, &alice_ata, &bob_ata, 400).unwrap();
+    
+        env.send(vec![ix::update_whitelist_entry(&authority, &env.mint, &user, 600, 0)], &[]).unwrap();
+        let (_, entries) = fetch_whitelist(&env, &env.mint).unwrap().unwrap();
+        assert_eq!(entries[0].max_amount, 600);
+        assert_eq!(entries[0].usage.lifetime_used, 400);
+        env.transfer(&alice, &alice_ata, &bob_ata, 600).unwrap();
+    }
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn pda_update_keeps_usage() {
+        let mut env = Env::with_hook_mint();
+        let (alice, alice_ata) = env.user();
+        let (_, bob_ata) = env.user();
+        let authority = env.authority();
+        let user = from_sdk(&alice.pubkey());
+        env.mint_to(&alice_ata, 1_000);
+        env.send(vec![ix::pda_whitelist_add(&authority, &env.mint, &user, 500, 0, None)], &[]).unwrap();
+        env.transfer(&alice, &alice_ata, &bob_ata, 400).unwrap();
+    
+        env.send(vec![ix::pda_whitelist_update(&authority, &env.mint, &user, 600, 0)], &[]).unwrap();
+        let entry = fetch_whitelist_entry_pda(&env, &env.mint, &user).unwrap().unwrap();
+        assert_eq!(entry.max_amount, 600);
+        assert_eq!(entry.usage.lifetime_used, 400);
+    }
+    
+    #[test]
+    #[ignore = "needs transfer_hook_vault.so; run `cargo build-sbf` first"]
+    fn updating_a_missing_entry_fails() {
+        let mut env = Env::with_hook_mint();
+        let authority = env.authority();
+        let user = Pubkey::new_unique();
+    
+        assert_custom(
+            env.send(vec![ix::update_whitelist_entry(&authority, &env.mint, &user, 1, 0)], &[]),
+            VaultError::NotWhitelisted,
+        );
+    }
+}
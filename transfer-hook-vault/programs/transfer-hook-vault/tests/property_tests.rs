@@ -0,0 +1,352 @@
+//! Property tests: random operation sequences run against the whitelist and
+//! limit logic and checked against a simple model
+
+use std::collections::BTreeMap;
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use proptest::prelude::*;
+use transfer_hook_vault::{
+    error::VaultError,
+    state::{Tier, TierUsage, Whitelist, WhitelistEntry},
+};
+
+const USERS: usize = 8;
+const INITIAL_BALANCE: u64 = 1_000;
+
+fn user(index: usize) -> Pubkey {
+    Pubkey::new_from_array([index as u8 + 1; 32])
+}
+
+#[derive(Clone, Debug)]
+enum Op {
+    Add { user: usize, max_amount: u64, tier_id: u8 },
+    Remove { user: usize },
+    /// Remove then re-add with new limits, as the admin CLI applies updates
+    Update { user: usize, max_amount: u64, tier_id: u8 },
+    Deposit { user: usize, amount: u64 },
+    Withdraw { user: usize, amount: u64 },
+    NextDay,
+}
+
+/// Small amounts and limits so boundaries are hit often
+fn amount() -> impl Strategy<Value = u64> {
+    prop_oneof![0u64..=300, Just(u64::MAX)]
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    let user = 0..USERS;
+    prop_oneof![
+        1 => (user.clone(), 0u64..=300, 0u8..=3).prop_map(|(user, max_amount, tier_id)| Op::Add { user, max_amount, tier_id }),
+        1 => user.clone().prop_map(|user| Op::Remove { user }),
+        1 => (user.clone(), 0u64..=300, 0u8..=3).prop_map(|(user, max_amount, tier_id)| Op::Update { user, max_amount, tier_id }),
+        3 => (user.clone(), amount()).prop_map(|(user, amount)| Op::Deposit { user, amount }),
+        3 => (user, amount()).prop_map(|(user, amount)| Op::Withdraw { user, amount }),
+        1 => Just(Op::NextDay),
+    ]
+}
+
+/// Per-tx, daily and lifetime limits
+fn tier_limits() -> impl Strategy<Value = (u64, u64, u64)> {
+    (0u64..=300, 0u64..=600, 0u64..=1_200)
+}
+
+fn tier(tier_id: u8, (per_tx_limit, daily_limit, lifetime_limit): (u64, u64, u64)) -> Tier {
+    Tier { vault_config: Pubkey::default(), tier_id, per_tx_limit, daily_limit, lifetime_limit, bump: 0 }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Usage {
+    day: i64,
+    daily_used: u64,
+    lifetime_used: u64,
+}
+
+impl From<TierUsage> for Usage {
+    fn from(usage: TierUsage) -> Self {
+        Self { day: usage.day, daily_used: usage.daily_used, lifetime_used: usage.lifetime_used }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct ModelEntry {
+    max_amount: u64,
+    tier_id: u8,
+    usage: Usage,
+}
+
+/// Reference implementation, written independently of the program's helpers
+#[derive(Default)]
+struct Model {
+    capacity: usize,
+    tiers: Vec<Tier>,
+    entries: BTreeMap<Pubkey, ModelEntry>,
+    balances: [u64; USERS],
+    vault: u64,
+    day: i64,
+}
+
+impl Model {
+    fn add(&mut self, user: Pubkey, max_amount: u64, tier_id: u8) -> Result<()> {
+        if self.entries.len() >= self.capacity {
+            return Err(VaultError::WhitelistFull.into());
+        }
+        if self.entries.contains_key(&user) {
+            return Err(VaultError::AlreadyWhitelisted.into());
+        }
+        self.entries.insert(user, ModelEntry { max_amount, tier_id, usage: TierUsage::default().into() });
+        Ok(())
+    }
+
+    fn remove(&mut self, user: &Pubkey) -> Result<()> {
+        self.entries.remove(user).map(|_| ()).ok_or(VaultError::NotWhitelisted.into())
+    }
+
+    fn update(&mut self, user: Pubkey, max_amount: u64, tier_id: u8) -> Result<()> {
+        self.remove(&user)?;
+        self.add(user, max_amount, tier_id)
+    }
+
+    /// Whether `amount` passes the entry's and its tier's limits
+    fn check_limits(&self, user: &Pubkey, amount: u64) -> Result<Usage> {
+        let entry = self.entries.get(user).ok_or(VaultError::NotWhitelisted)?;
+        if entry.max_amount != 0 && amount > entry.max_amount {
+            return Err(VaultError::AmountExceedsLimit.into());
+        }
+        if entry.tier_id == 0 {
+            return Ok(entry.usage);
+        }
+        let tier = self.tiers.get(entry.tier_id as usize - 1).ok_or(VaultError::InvalidTier)?;
+        if tier.per_tx_limit != 0 && amount > tier.per_tx_limit {
+            return Err(VaultError::AmountExceedsLimit.into());
+        }
+        let daily = if entry.usage.day == self.day { entry.usage.daily_used as u128 } else { 0 } + amount as u128;
+        if tier.daily_limit != 0 && daily > tier.daily_limit as u128 || daily > u64::MAX as u128 {
+            return Err(VaultError::DailyLimitExceeded.into());
+        }
+        let lifetime = entry.usage.lifetime_used as u128 + amount as u128;
+        if tier.lifetime_limit != 0 && lifetime > tier.lifetime_limit as u128 || lifetime > u64::MAX as u128 {
+            return Err(VaultError::LifetimeLimitExceeded.into());
+        }
+        Ok(Usage { day: self.day, daily_used: daily as u64, lifetime_used: lifetime as u64 })
+    }
+
+    fn transfer(&mut self, user: usize, amount: u64, deposit: bool) -> Result<()> {
+        let usage = self.check_limits(&self::user(user), amount)?;
+        let (from, to) = if deposit {
+            (&mut self.balances[user], &mut self.vault)
+        } else {
+            (&mut self.vault, &mut self.balances[user])
+        };
+        if *from < amount {
+            return Err(ProgramError::InsufficientFunds.into());
+        }
+        *from -= amount;
+        *to += amount;
+        let entry = self.entries.get_mut(&self::user(user)).unwrap();
+        if entry.tier_id != 0 {
+            entry.usage = usage;
+        }
+        Ok(())
+    }
+}
+
+/// The program's side: a zero-copy whitelist buffer driven the way the
+/// handlers drive it, plus token balances standing in for Token-2022
+struct Program {
+    data: Vec<u64>,
+    tiers: Vec<Tier>,
+    balances: [u64; USERS],
+    vault: u64,
+    day: i64,
+}
+
+impl Program {
+    fn new(capacity: usize, tiers: Vec<Tier>) -> Self {
+        let mut program = Self {
+            data: vec![0; Whitelist::space(capacity).div_ceil(8)],
+            tiers,
+            balances: [INITIAL_BALANCE; USERS],
+            vault: 0,
+            day: 0,
+        };
+        let bytes = program.bytes_mut();
+        bytes[..8].copy_from_slice(&Whitelist::DISCRIMINATOR);
+        Whitelist::from_data_mut(bytes).unwrap().0.capacity = capacity as u32;
+        program
+    }
+
+    fn bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(&self.data)
+    }
+
+    fn bytes_mut(&mut self) -> &mut [u8] {
+        bytemuck::cast_slice_mut(&mut self.data)
+    }
+
+    fn entries(&self) -> Vec<WhitelistEntry> {
+        Whitelist::from_data(self.bytes()).unwrap().1.to_vec()
+    }
+
+    /// Mirrors `add_to_whitelist`
+    fn add(&mut self, user: Pubkey, max_amount: u64, tier_id: u8) -> Result<()> {
+        let (whitelist, slots) = Whitelist::from_data_mut(self.bytes_mut())?;
+        require!(whitelist.can_add_entry(), VaultError::WhitelistFull);
+        require!(
+            whitelist.insert_entry(slots, WhitelistEntry::new(user, max_amount, tier_id)),
+            VaultError::AlreadyWhitelisted
+        );
+        Ok(())
+    }
+
+    /// Mirrors `remove_from_whitelist`
+    fn remove(&mut self, user: &Pubkey) -> Result<()> {
+        let (whitelist, slots) = Whitelist::from_data_mut(self.bytes_mut())?;
+        require!(whitelist.remove_entry(slots, user), VaultError::NotWhitelisted);
+        Ok(())
+    }
+
+    /// Runs `f` as one transaction: any error rolls the whitelist back
+    fn atomic(&mut self, f: impl FnOnce(&mut Self) -> Result<()>) -> Result<()> {
+        let snapshot = (self.data.clone(), self.balances, self.vault);
+        let result = f(self);
+        if result.is_err() {
+            (self.data, self.balances, self.vault) = snapshot;
+        }
+        result
+    }
+
+    /// Mirrors the Vec-entry path of `deposit` and `withdraw`: limits are
+    /// recorded first, then the token transfer runs
+    fn transfer(&mut self, user: usize, amount: u64, deposit: bool) -> Result<()> {
+        let key = self::user(user);
+        let day = self.day;
+        let tiers = self.tiers.clone();
+        let before = self.data.clone();
+
+        let (whitelist, slots) = Whitelist::from_data_mut(self.bytes_mut())?;
+        let entry = Whitelist::get_entry_mut(&mut slots[..whitelist.len as usize], &key)
+            .ok_or(VaultError::NotWhitelisted)?;
+        let tier = tiers.get((entry.tier_id as usize).wrapping_sub(1));
+        let result = entry.record_transfer(tier, amount, day);
+        if result.is_err() {
+            assert_eq!(self.data, before, "rejected transfer modified the whitelist");
+        }
+        result?;
+
+        let (from, to) = if deposit {
+            (&mut self.balances[user], &mut self.vault)
+        } else {
+            (&mut self.vault, &mut self.balances[user])
+        };
+        if *from < amount {
+            return Err(ProgramError::InsufficientFunds.into());
+        }
+        *from -= amount;
+        *to += amount;
+        Ok(())
+    }
+
+    fn apply(&mut self, op: &Op) -> Result<()> {
+        match *op {
+            Op::Add { user, max_amount, tier_id } => self.atomic(|p| p.add(self::user(user), max_amount, tier_id)),
+            Op::Remove { user } => self.atomic(|p| p.remove(&self::user(user))),
+            Op::Update { user, max_amount, tier_id } => self.atomic(|p| {
+                p.remove(&self::user(user))?;
+                p.add(self::user(user), max_amount, tier_id)
+            }),
+            Op::Deposit { user, amount } => self.atomic(|p| p.transfer(user, amount, true)),
+            Op::Withdraw { user, amount } => self.atomic(|p| p.transfer(user, amount, false)),
+            Op::NextDay => {
+                self.day += 1;
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Model {
+    fn apply(&mut self, op: &Op) -> Result<()> {
+        match *op {
+            Op::Add { user, max_amount, tier_id } => self.add(self::user(user), max_amount, tier_id),
+            Op::Remove { user } => self.remove(&self::user(user)),
+            Op::Update { user, max_amount, tier_id } => self.update(self::user(user), max_amount, tier_id),
+            Op::Deposit { user, amount } => self.transfer(user, amount, true),
+            Op::Withdraw { user, amount } => self.transfer(user, amount, false),
+            Op::NextDay => {
+                self.day += 1;
+                Ok(())
+            }
+        }
+    }
+}
+
+fn check_invariants(program: &Program, model: &Model) -> std::result::Result<(), TestCaseError> {
+    let (header, entries) = Whitelist::from_data(program.bytes()).unwrap();
+    prop_assert!(header.len <= header.capacity, "len {} over capacity {}", header.len, header.capacity);
+    prop_assert!(
+        entries.windows(2).all(|pair| pair[0].user < pair[1].user),
+        "entries unsorted or duplicated"
+    );
+
+    let actual: BTreeMap<Pubkey, ModelEntry> = program
+        .entries()
+        .into_iter()
+        .map(|e| (e.user, ModelEntry { max_amount: e.max_amount, tier_id: e.tier_id, usage: e.usage.into() }))
+        .collect();
+    prop_assert_eq!(&actual, &model.entries);
+
+    for entry in actual.values().filter(|e| e.tier_id != 0) {
+        if let Some(tier) = program.tiers.get(entry.tier_id as usize - 1) {
+            prop_assert!(tier.lifetime_limit == 0 || entry.usage.lifetime_used <= tier.lifetime_limit);
+            prop_assert!(tier.daily_limit == 0 || entry.usage.daily_used <= tier.daily_limit);
+        }
+    }
+
+    let total: u64 = program.balances.iter().sum::<u64>() + program.vault;
+    prop_assert_eq!(total, INITIAL_BALANCE * USERS as u64, "tokens created or destroyed");
+    prop_assert_eq!(program.balances, model.balances);
+    prop_assert_eq!(program.vault, model.vault);
+    Ok(())
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(512))]
+
+    #[test]
+    fn whitelist_state_machine_matches_model(
+        capacity in 1usize..=6,
+        limits in prop::array::uniform2(tier_limits()),
+        ops in prop::collection::vec(op(), 1..80),
+    ) {
+        // Tier ids 1 and 2 exist; 3 can be assigned but was never created
+        let tiers = vec![tier(1, limits[0]), tier(2, limits[1])];
+        let mut program = Program::new(capacity, tiers.clone());
+        let mut model = Model { capacity, tiers, balances: [INITIAL_BALANCE; USERS], ..Model::default() };
+
+        for op in &ops {
+            let expected = model.apply(op);
+            let actual = program.apply(op);
+            prop_assert_eq!(&actual, &expected, "{:?}", op);
+            check_invariants(&program, &model)?;
+        }
+    }
+
+    /// Exactly `max_amount` passes and one more fails, for the entry limit
+    /// and for every tier limit
+    #[test]
+    fn limits_are_inclusive(limit in 1u64..u64::MAX, which in 0usize..4) {
+        let mut tier = tier(1, (0, 0, 0));
+        let (max_amount, tier_id) = match which {
+            0 => (limit, 0),
+            1 => { tier.per_tx_limit = limit; (0, 1) }
+            2 => { tier.daily_limit = limit; (0, 1) }
+            _ => { tier.lifetime_limit = limit; (0, 1) }
+        };
+
+        let mut entry = WhitelistEntry::new(user(0), max_amount, tier_id);
+        prop_assert!(entry.record_transfer(Some(&tier), limit, 0).is_ok());
+
+        let mut entry = WhitelistEntry::new(user(0), max_amount, tier_id);
+        prop_assert!(entry.record_transfer(Some(&tier), limit + 1, 0).is_err());
+    }
+}
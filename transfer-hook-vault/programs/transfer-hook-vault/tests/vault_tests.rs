@@ -1,50 +1,100 @@
 //! Tests for Transfer Hook Vault
 
 use std::str::FromStr;
-use anchor_lang::Space;
-use solana_program::pubkey::Pubkey;
+use anchor_lang::{Discriminator, Space};
+use solana_program::{entrypoint::MAX_PERMITTED_DATA_INCREASE, pubkey::Pubkey};
 use transfer_hook_vault::{
+    client::pda::*,
     constants::*,
-    state::{Whitelist, WhitelistEntry, VaultConfig, WhitelistEntryPda},
+    events::*,
+    merkle,
+    state::{Tier, TierUsage, Whitelist, WhitelistEntry, VaultConfig, WhitelistEntryPda},
 };
 
 fn program_id() -> Pubkey {
     Pubkey::from_str("HookVau1t1111111111111111111111111111111111").unwrap()
 }
 
-fn derive_vault_config(mint: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(&[VAULT_CONFIG_SEED, mint.as_ref()], &program_id())
+/// Whitelist account data in an 8-byte aligned buffer, laid out as on-chain
+struct WhitelistAccount {
+    data: Vec<u64>,
 }
 
-fn derive_vault(mint: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(&[VAULT_SEED, mint.as_ref()], &program_id())
-}
-
-fn derive_whitelist(mint: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(&[WHITELIST_SEED, mint.as_ref()], &program_id())
-}
-
-fn derive_extra_metas(mint: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(&[EXTRA_METAS_SEED, mint.as_ref()], &program_id())
-}
-
-fn derive_whitelist_entry_pda(vault_config: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(
-        &[WHITELIST_ENTRY_SEED, vault_config.as_ref(), user.as_ref()],
-        &program_id(),
-    )
+impl WhitelistAccount {
+    fn new(authority: Pubkey, capacity: usize) -> Self {
+        let mut account = Self { data: vec![0; Whitelist::space(capacity).div_ceil(8)] };
+        let bytes = account.bytes_mut();
+        bytes[..8].copy_from_slice(&Whitelist::DISCRIMINATOR);
+        let (header, _) = Whitelist::from_data_mut(bytes).unwrap();
+        header.authority = authority;
+        header.capacity = capacity as u32;
+        account
+    }
+    
+    fn bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(&self.data)
+    }
+    
+    fn bytes_mut(&mut self) -> &mut [u8] {
+        bytemuck::cast_slice_mut(&mut self.data)
+    }
+    
+    fn header(&self) -> Whitelist {
+        *Whitelist::from_data(self.bytes()).unwrap().0
+    }
+    
+    fn entries(&self) -> Vec<WhitelistEntry> {
+        Whitelist::from_data(self.bytes()).unwrap().1.to_vec()
+    }
+    
+    fn len(&self) -> usize {
+        self.header().len as usize
+    }
+    
+    fn can_add_entry(&self) -> bool {
+        self.header().can_add_entry()
+    }
+    
+    /// Mirrors `add_to_whitelist`: capacity is checked before inserting
+    fn insert(&mut self, user: Pubkey, max_amount: u64) -> bool {
+        let (header, slots) = Whitelist::from_data_mut(self.bytes_mut()).unwrap();
+        assert!(header.can_add_entry(), "whitelist full");
+        header.insert_entry(slots, WhitelistEntry::new(user, max_amount, 0))
+    }
+    
+    fn remove(&mut self, user: &Pubkey) -> bool {
+        let (header, slots) = Whitelist::from_data_mut(self.bytes_mut()).unwrap();
+        header.remove_entry(slots, user)
+    }
+    
+    fn get_entry(&self, user: &Pubkey) -> Option<WhitelistEntry> {
+        let (_, entries) = Whitelist::from_data(self.bytes()).unwrap();
+        Whitelist::get_entry(entries, user).copied()
+    }
+    
+    fn is_whitelisted(&self, user: &Pubkey) -> bool {
+        self.get_entry(user).is_some()
+    }
+    
+    /// Mirrors `resize_whitelist`: realloc the data, then record the capacity
+    fn resize(&mut self, capacity: usize) {
+        self.data.resize(Whitelist::space(capacity).div_ceil(8), 0);
+        let (header, _) = Whitelist::from_data_mut(self.bytes_mut()).unwrap();
+        assert!(capacity as u32 >= header.len);
+        header.capacity = capacity as u32;
+    }
 }
 
 #[cfg(test)]
 mod test_initialize_vault {
     use super::*;
-
+    
     #[test]
     fn vault_config_space_is_correct() {
         let expected = 8 + VaultConfig::INIT_SPACE;
         assert!(expected < 200);
     }
-
+    
     #[test]
     fn pda_derivation_is_deterministic() {
         let mint = Pubkey::new_unique();
@@ -53,7 +103,7 @@ mod test_initialize_vault {
         assert_eq!(config1, config2);
         assert_eq!(bump1, bump2);
     }
-
+    
     #[test]
     fn different_mints_give_different_pdas() {
         let mint1 = Pubkey::new_unique();
@@ -62,7 +112,7 @@ mod test_initialize_vault {
         let (config2, _) = derive_vault_config(&mint2);
         assert_ne!(config1, config2);
     }
-
+    
     #[test]
     fn all_pdas_are_unique() {
         let mint = Pubkey::new_unique();
@@ -81,117 +131,114 @@ mod test_initialize_vault {
 #[cfg(test)]
 mod test_add_whitelist {
     use super::*;
-
-    fn create_empty_whitelist() -> Whitelist {
-        Whitelist { authority: Pubkey::new_unique(), entries: Vec::new() }
+    
+    fn create_empty_whitelist() -> WhitelistAccount {
+        WhitelistAccount::new(Pubkey::new_unique(), MAX_WHITELIST_ENTRIES)
     }
-
+    
     #[test]
     fn can_add_user_to_empty_whitelist() {
         let mut whitelist = create_empty_whitelist();
         let user = Pubkey::new_unique();
         
         assert!(!whitelist.is_whitelisted(&user));
-        whitelist.entries.push(WhitelistEntry { user, max_amount: 1_000_000_000 });
+        whitelist.insert(user, 1_000_000_000);
         assert!(whitelist.is_whitelisted(&user));
     }
-
+    
     #[test]
     fn whitelist_entry_stores_max_amount() {
         let mut whitelist = create_empty_whitelist();
         let user = Pubkey::new_unique();
         let max_amount = 5_000_000_000u64;
         
-        whitelist.entries.push(WhitelistEntry { user, max_amount });
+        whitelist.insert(user, max_amount);
         let entry = whitelist.get_entry(&user).unwrap();
         assert_eq!(entry.max_amount, max_amount);
     }
-
+    
     #[test]
     fn unlimited_amount_is_zero() {
         let mut whitelist = create_empty_whitelist();
         let user = Pubkey::new_unique();
         
-        whitelist.entries.push(WhitelistEntry { user, max_amount: 0 });
+        whitelist.insert(user, 0);
         let entry = whitelist.get_entry(&user).unwrap();
         assert_eq!(entry.max_amount, 0);
     }
-
+    
     #[test]
     fn can_add_multiple_users() {
         let mut whitelist = create_empty_whitelist();
         let users: Vec<Pubkey> = (0..5).map(|_| Pubkey::new_unique()).collect();
         
         for (i, user) in users.iter().enumerate() {
-            whitelist.entries.push(WhitelistEntry {
-                user: *user,
-                max_amount: (i as u64 + 1) * 1_000_000_000,
-            });
+            whitelist.insert(*user, (i as u64 + 1) * 1_000_000_000);
         }
         
         for user in &users {
             assert!(whitelist.is_whitelisted(user));
         }
-        assert_eq!(whitelist.entries.len(), 5);
+        assert_eq!(whitelist.len(), 5);
     }
-
+    
     #[test]
     fn can_check_capacity() {
         let mut whitelist = create_empty_whitelist();
         assert!(whitelist.can_add_entry());
         
         for _ in 0..MAX_WHITELIST_ENTRIES {
-            whitelist.entries.push(WhitelistEntry { user: Pubkey::new_unique(), max_amount: 0 });
+            whitelist.insert(Pubkey::new_unique(), 0);
         }
         
         assert!(!whitelist.can_add_entry());
-        assert_eq!(whitelist.entries.len(), MAX_WHITELIST_ENTRIES);
+        assert_eq!(whitelist.len(), MAX_WHITELIST_ENTRIES);
     }
 }
 
 #[cfg(test)]
 mod test_whitelisted_deposit {
     use super::*;
-
+    
     #[test]
     fn whitelisted_user_passes_check() {
-        let mut whitelist = Whitelist { authority: Pubkey::new_unique(), entries: Vec::new() };
+        let mut whitelist = WhitelistAccount::new(Pubkey::new_unique(), MAX_WHITELIST_ENTRIES);
         let depositor = Pubkey::new_unique();
-        whitelist.entries.push(WhitelistEntry { user: depositor, max_amount: 0 });
+        whitelist.insert(depositor, 0);
         assert!(whitelist.is_whitelisted(&depositor));
     }
-
+    
     #[test]
     fn amount_within_limit_passes() {
-        let mut whitelist = Whitelist { authority: Pubkey::new_unique(), entries: Vec::new() };
+        let mut whitelist = WhitelistAccount::new(Pubkey::new_unique(), MAX_WHITELIST_ENTRIES);
         let depositor = Pubkey::new_unique();
         let max_amount = 1_000_000_000u64;
         let deposit_amount = 500_000_000u64;
         
-        whitelist.entries.push(WhitelistEntry { user: depositor, max_amount });
+        whitelist.insert(depositor, max_amount);
         let entry = whitelist.get_entry(&depositor).unwrap();
         let is_within_limit = entry.max_amount == 0 || deposit_amount <= entry.max_amount;
         assert!(is_within_limit);
     }
-
+    
     #[test]
     fn amount_exceeding_limit_fails() {
-        let mut whitelist = Whitelist { authority: Pubkey::new_unique(), entries: Vec::new() };
+        let mut whitelist = WhitelistAccount::new(Pubkey::new_unique(), MAX_WHITELIST_ENTRIES);
         let depositor = Pubkey::new_unique();
         let max_amount = 1_000_000_000u64;
         let deposit_amount = 2_000_000_000u64;
         
-        whitelist.entries.push(WhitelistEntry { user: depositor, max_amount });
+        whitelist.insert(depositor, max_amount);
         let entry = whitelist.get_entry(&depositor).unwrap();
         let exceeds_limit = entry.max_amount > 0 && deposit_amount > entry.max_amount;
         assert!(exceeds_limit);
     }
-
+    
     #[test]
     fn unlimited_allows_any_amount() {
-        let mut whitelist = Whitelist { authority: Pubkey::new_unique(), entries: Vec::new() };
+        let mut whitelist = WhitelistAccount::new(Pubkey::new_unique(), MAX_WHITELIST_ENTRIES);
         let depositor = Pubkey::new_unique();
-        whitelist.entries.push(WhitelistEntry { user: depositor, max_amount: 0 });
+        whitelist.insert(depositor, 0);
         
         let huge_amount = u64::MAX;
         let entry = whitelist.get_entry(&depositor).unwrap();
@@ -203,33 +250,33 @@ mod test_whitelisted_deposit {
 #[cfg(test)]
 mod test_nonwhitelisted_blocked {
     use super::*;
-
+    
     #[test]
     fn non_whitelisted_user_is_blocked() {
-        let whitelist = Whitelist { authority: Pubkey::new_unique(), entries: Vec::new() };
+        let whitelist = WhitelistAccount::new(Pubkey::new_unique(), MAX_WHITELIST_ENTRIES);
         let random_user = Pubkey::new_unique();
         assert!(!whitelist.is_whitelisted(&random_user));
     }
-
+    
     #[test]
     fn removed_user_is_blocked() {
-        let mut whitelist = Whitelist { authority: Pubkey::new_unique(), entries: Vec::new() };
+        let mut whitelist = WhitelistAccount::new(Pubkey::new_unique(), MAX_WHITELIST_ENTRIES);
         let user = Pubkey::new_unique();
         
-        whitelist.entries.push(WhitelistEntry { user, max_amount: 0 });
+        whitelist.insert(user, 0);
         assert!(whitelist.is_whitelisted(&user));
         
-        whitelist.entries.retain(|e| e.user != user);
+        whitelist.remove(&user);
         assert!(!whitelist.is_whitelisted(&user));
     }
-
+    
     #[test]
     fn similar_pubkey_is_not_matched() {
-        let mut whitelist = Whitelist { authority: Pubkey::new_unique(), entries: Vec::new() };
+        let mut whitelist = WhitelistAccount::new(Pubkey::new_unique(), MAX_WHITELIST_ENTRIES);
         let whitelisted_user = Pubkey::new_unique();
         let similar_user = Pubkey::new_unique();
         
-        whitelist.entries.push(WhitelistEntry { user: whitelisted_user, max_amount: 0 });
+        whitelist.insert(whitelisted_user, 0);
         assert!(whitelist.is_whitelisted(&whitelisted_user));
         assert!(!whitelist.is_whitelisted(&similar_user));
     }
@@ -238,22 +285,22 @@ mod test_nonwhitelisted_blocked {
 #[cfg(test)]
 mod test_whitelisted_withdraw {
     use super::*;
-
+    
     #[test]
     fn whitelisted_user_can_withdraw() {
-        let mut whitelist = Whitelist { authority: Pubkey::new_unique(), entries: Vec::new() };
+        let mut whitelist = WhitelistAccount::new(Pubkey::new_unique(), MAX_WHITELIST_ENTRIES);
         let withdrawer = Pubkey::new_unique();
-        whitelist.entries.push(WhitelistEntry { user: withdrawer, max_amount: 0 });
+        whitelist.insert(withdrawer, 0);
         assert!(whitelist.is_whitelisted(&withdrawer));
     }
-
+    
     #[test]
     fn withdraw_amount_limit_enforced() {
-        let mut whitelist = Whitelist { authority: Pubkey::new_unique(), entries: Vec::new() };
+        let mut whitelist = WhitelistAccount::new(Pubkey::new_unique(), MAX_WHITELIST_ENTRIES);
         let withdrawer = Pubkey::new_unique();
         let max_amount = 1_000_000_000u64;
         
-        whitelist.entries.push(WhitelistEntry { user: withdrawer, max_amount });
+        whitelist.insert(withdrawer, max_amount);
         let entry = whitelist.get_entry(&withdrawer).unwrap();
         
         assert!(500_000_000u64 <= entry.max_amount);
@@ -264,53 +311,66 @@ mod test_whitelisted_withdraw {
 #[cfg(test)]
 mod test_transfer_hook_fires {
     use super::*;
-
+    
     #[test]
     fn transfer_to_whitelisted_allowed() {
-        let mut whitelist = Whitelist { authority: Pubkey::new_unique(), entries: Vec::new() };
+        let mut whitelist = WhitelistAccount::new(Pubkey::new_unique(), MAX_WHITELIST_ENTRIES);
         let recipient = Pubkey::new_unique();
-        whitelist.entries.push(WhitelistEntry { user: recipient, max_amount: 0 });
+        whitelist.insert(recipient, 0);
         assert!(whitelist.is_whitelisted(&recipient));
     }
-
+    
     #[test]
     fn transfer_to_non_whitelisted_blocked() {
-        let whitelist = Whitelist { authority: Pubkey::new_unique(), entries: Vec::new() };
+        let whitelist = WhitelistAccount::new(Pubkey::new_unique(), MAX_WHITELIST_ENTRIES);
         let non_whitelisted_recipient = Pubkey::new_unique();
         assert!(!whitelist.is_whitelisted(&non_whitelisted_recipient));
     }
-
+    
     #[test]
     fn transfer_to_vault_always_allowed() {
         let mint = Pubkey::new_unique();
         let (vault_pda, _) = derive_vault(&mint);
         assert_ne!(vault_pda, Pubkey::default());
     }
-
+    
     #[test]
     fn hook_amount_validation() {
-        let mut whitelist = Whitelist { authority: Pubkey::new_unique(), entries: Vec::new() };
+        let mut whitelist = WhitelistAccount::new(Pubkey::new_unique(), MAX_WHITELIST_ENTRIES);
         let recipient = Pubkey::new_unique();
         let max_amount = 1_000_000_000u64;
         
-        whitelist.entries.push(WhitelistEntry { user: recipient, max_amount });
+        whitelist.insert(recipient, max_amount);
         let entry = whitelist.get_entry(&recipient).unwrap();
         
         assert!(entry.max_amount == 0 || 500_000_000u64 <= entry.max_amount);
         assert!(entry.max_amount > 0 && 2_000_000_000u64 > entry.max_amount);
     }
+    
+    #[test]
+    fn hook_answers_spl_execute() {
+        use anchor_lang::InstructionData;
+        use spl_transfer_hook_interface::instruction::{ExecuteInstruction, TransferHookInstruction};
+        use spl_discriminator::SplDiscriminate;
+        
+        // Token-2022 invokes the hook with the interface's Execute discriminator,
+        // not Anchor's sighash of `transfer_hook`
+        let data = transfer_hook_vault::instruction::TransferHook { amount: 42 }.data();
+        assert_eq!(&data[..8], ExecuteInstruction::SPL_DISCRIMINATOR_SLICE);
+        assert_eq!(data, TransferHookInstruction::Execute { amount: 42 }.pack());
+    }
 }
 
 #[cfg(test)]
 mod test_pda_whitelist {
     use super::*;
-
+    
     #[test]
     fn pda_whitelist_entry_space_is_correct() {
         let expected = 8 + WhitelistEntryPda::INIT_SPACE;
-        assert!(expected < 100);
+        assert!(expected < 128); // includes tier usage counters
     }
-
+    
     #[test]
     fn pda_derivation_is_unique_per_user() {
         let vault_config = Pubkey::new_unique();
@@ -321,7 +381,7 @@ mod test_pda_whitelist {
         let (pda2, _) = derive_whitelist_entry_pda(&vault_config, &user2);
         assert_ne!(pda1, pda2);
     }
-
+    
     #[test]
     fn pda_derivation_is_deterministic() {
         let vault_config = Pubkey::new_unique();
@@ -332,7 +392,7 @@ mod test_pda_whitelist {
         assert_eq!(pda1, pda2);
         assert_eq!(bump1, bump2);
     }
-
+    
     #[test]
     fn different_vault_configs_give_different_pdas() {
         let vault_config1 = Pubkey::new_unique();
@@ -343,7 +403,7 @@ mod test_pda_whitelist {
         let (pda2, _) = derive_whitelist_entry_pda(&vault_config2, &user);
         assert_ne!(pda1, pda2);
     }
-
+    
     #[test]
     fn pda_entry_structure_is_correct() {
         let entry = WhitelistEntryPda {
@@ -351,6 +411,8 @@ mod test_pda_whitelist {
             max_amount: 1_000_000_000,
             vault_config: Pubkey::new_unique(),
             bump: 255,
+            tier_id: 0,
+            usage: TierUsage::default(),
         };
         
         assert_ne!(entry.user, Pubkey::default());
@@ -370,6 +432,8 @@ mod test_pda_whitelist {
                 max_amount: (i as u64 + 1) * 1_000_000_000,
                 vault_config,
                 bump,
+                tier_id: 0,
+                usage: TierUsage::default(),
             }
         }).collect();
         
@@ -389,7 +453,7 @@ mod test_pda_whitelist {
 #[cfg(test)]
 mod test_vault_simulation {
     use super::*;
-
+    
     #[test]
     fn simulate_full_vault_workflow() {
         let authority = Pubkey::new_unique();
@@ -409,12 +473,13 @@ mod test_vault_simulation {
             vault_bump,
             whitelist_bump,
             extra_metas_bump: 0,
+            merkle_root: [0; 32],
         };
         assert_eq!(vault_config.authority, authority);
         
-        let mut whitelist = Whitelist { authority, entries: Vec::new() };
-        whitelist.entries.push(WhitelistEntry { user: user1, max_amount: 1_000_000_000 });
-        whitelist.entries.push(WhitelistEntry { user: user2, max_amount: 0 });
+        let mut whitelist = WhitelistAccount::new(authority, MAX_WHITELIST_ENTRIES);
+        whitelist.insert(user1, 1_000_000_000);
+        whitelist.insert(user2, 0);
         
         // Test deposits
         assert!(whitelist.is_whitelisted(&user1));
@@ -433,24 +498,26 @@ mod test_vault_simulation {
         assert!(entry2.max_amount == 0);
         
         // Remove user1
-        whitelist.entries.retain(|e| e.user != user1);
+        whitelist.remove(&user1);
         assert!(!whitelist.is_whitelisted(&user1));
         assert!(whitelist.is_whitelisted(&user2));
     }
-
+    
     #[test]
     fn simulate_whitelist_capacity() {
         let authority = Pubkey::new_unique();
-        let mut whitelist = Whitelist { authority, entries: Vec::new() };
+        let mut whitelist = WhitelistAccount::new(authority, MAX_WHITELIST_ENTRIES);
         
+        let mut last_user = Pubkey::default();
         for i in 0..MAX_WHITELIST_ENTRIES {
             assert!(whitelist.can_add_entry(), "Should be able to add entry {}", i);
-            whitelist.entries.push(WhitelistEntry { user: Pubkey::new_unique(), max_amount: 0 });
+            last_user = Pubkey::new_unique();
+            whitelist.insert(last_user, 0);
         }
         
         assert!(!whitelist.can_add_entry());
         
-        whitelist.entries.pop();
+        whitelist.remove(&last_user);
         assert!(whitelist.can_add_entry());
     }
     
@@ -461,15 +528,17 @@ mod test_vault_simulation {
         let users: Vec<Pubkey> = (0..50).map(|_| Pubkey::new_unique()).collect();
         
         // Vec approach
-        let mut vec_whitelist = Whitelist { authority, entries: Vec::new() };
+        let mut vec_whitelist = WhitelistAccount::new(authority, MAX_WHITELIST_ENTRIES);
         for user in &users {
-            vec_whitelist.entries.push(WhitelistEntry { user: *user, max_amount: 1_000_000_000 });
+            vec_whitelist.insert(*user, 1_000_000_000);
         }
         
         // PDA approach
         let pda_entries: Vec<WhitelistEntryPda> = users.iter().map(|u| {
             let (_, bump) = derive_whitelist_entry_pda(&vault_config, u);
-            WhitelistEntryPda { user: *u, max_amount: 1_000_000_000, vault_config, bump }
+            WhitelistEntryPda {
+                user: *u, max_amount: 1_000_000_000, vault_config, bump, tier_id: 0, usage: TierUsage::default(),
+            }
         }).collect();
         
         for user in &users {
@@ -487,7 +556,7 @@ mod test_vault_simulation {
 #[cfg(test)]
 mod test_e2e_scenarios {
     use super::*;
-
+    
     #[test]
     fn scenario_new_vault_deployment() {
         let authority = Pubkey::new_unique();
@@ -498,7 +567,7 @@ mod test_e2e_scenarios {
         let (whitelist_pda, whitelist_bump) = derive_whitelist(&mint);
         let (extra_metas_pda, _) = derive_extra_metas(&mint);
         
-        let pdas = vec![vault_config_pda, vault_pda, whitelist_pda, extra_metas_pda];
+        let pdas = [vault_config_pda, vault_pda, whitelist_pda, extra_metas_pda];
         for i in 0..pdas.len() {
             for j in (i+1)..pdas.len() {
                 assert_ne!(pdas[i], pdas[j]);
@@ -507,10 +576,11 @@ mod test_e2e_scenarios {
         
         let config = VaultConfig {
             authority, mint, config_bump, vault_bump, whitelist_bump, extra_metas_bump: 0,
+            merkle_root: [0; 32],
         };
         assert_eq!(config.authority, authority);
     }
-
+    
     #[test]
     fn scenario_multi_user_access() {
         let authority = Pubkey::new_unique();
@@ -520,21 +590,17 @@ mod test_e2e_scenarios {
         let limited_user = Pubkey::new_unique();
         let blocked_user = Pubkey::new_unique();
         
-        let whitelist = Whitelist {
-            authority,
-            entries: vec![
-                WhitelistEntry { user: vip_user, max_amount: 0 },
-                WhitelistEntry { user: regular_user, max_amount: 10_000_000_000 },
-                WhitelistEntry { user: limited_user, max_amount: 1_000_000_000 },
-            ],
-        };
+        let mut whitelist = WhitelistAccount::new(authority, MAX_WHITELIST_ENTRIES);
+        whitelist.insert(vip_user, 0);
+        whitelist.insert(regular_user, 10_000_000_000);
+        whitelist.insert(limited_user, 1_000_000_000);
         
         assert!(whitelist.is_whitelisted(&vip_user));
         assert!(whitelist.is_whitelisted(&regular_user));
         assert!(whitelist.is_whitelisted(&limited_user));
         assert!(!whitelist.is_whitelisted(&blocked_user));
     }
-
+    
     #[test]
     fn scenario_transfer_hook_enforcement() {
         let authority = Pubkey::new_unique();
@@ -543,10 +609,8 @@ mod test_e2e_scenarios {
         
         let sender = Pubkey::new_unique();
         
-        let whitelist = Whitelist {
-            authority,
-            entries: vec![WhitelistEntry { user: sender, max_amount: 0 }],
-        };
+        let mut whitelist = WhitelistAccount::new(authority, MAX_WHITELIST_ENTRIES);
+        whitelist.insert(sender, 0);
         
         // Sender is whitelisted
         assert!(whitelist.is_whitelisted(&sender));
@@ -563,21 +627,21 @@ mod test_e2e_scenarios {
 #[cfg(test)]
 mod test_space_calculations {
     use super::*;
-
+    
     #[test]
     fn whitelist_space_correct() {
         let space = Whitelist::space(MAX_WHITELIST_ENTRIES);
-        let expected = 8 + 32 + 4 + MAX_WHITELIST_ENTRIES * (32 + 8);
+        let expected = 8 + 32 + 4 + 4 + MAX_WHITELIST_ENTRIES * (32 + 8 + 24 + 8);
         assert_eq!(space, expected);
         assert!(space < 10240);
     }
-
+    
     #[test]
     fn single_entry_space() {
         let space = Whitelist::space(1);
-        let expected = 8 + 32 + 4 + 1 * (32 + 8);
+        let expected = 8 + 32 + 4 + 4 + (32 + 8 + 24 + 8);
         assert_eq!(space, expected);
-        assert_eq!(space, 84);
+        assert_eq!(space, 120);
     }
     
     #[test]
@@ -593,3 +657,620 @@ mod test_space_calculations {
         assert!(vec_100_users < pda_100_users);
     }
 }
+
+#[cfg(test)]
+mod test_resize_whitelist {
+    use super::*;
+    
+    #[test]
+    fn capacity_bounds_can_add_entry() {
+        let mut whitelist = WhitelistAccount::new(Pubkey::new_unique(), 2);
+        
+        whitelist.insert(Pubkey::new_unique(), 0);
+        assert!(whitelist.can_add_entry());
+        
+        whitelist.insert(Pubkey::new_unique(), 0);
+        assert!(!whitelist.can_add_entry());
+    }
+    
+    #[test]
+    fn growing_capacity_allows_more_entries() {
+        let mut whitelist = WhitelistAccount::new(Pubkey::new_unique(), 1);
+        whitelist.insert(Pubkey::new_unique(), 0);
+        assert!(!whitelist.can_add_entry());
+        
+        whitelist.resize(500);
+        assert!(whitelist.can_add_entry());
+    }
+    
+    #[test]
+    fn empty_whitelist_can_start_at_zero_capacity() {
+        let whitelist = WhitelistAccount::new(Pubkey::new_unique(), 0);
+        assert!(!whitelist.can_add_entry());
+        assert_eq!(Whitelist::space(0), 48);
+    }
+    
+    #[test]
+    fn space_scales_per_entry() {
+        assert_eq!(Whitelist::space(11) - Whitelist::space(10), Whitelist::ENTRY_LEN);
+        // Growing from the initial capacity to 1000 entries exceeds one call's
+        // realloc limit, so it has to be split across several resize calls
+        let growth = Whitelist::space(1000) - Whitelist::space(MAX_WHITELIST_ENTRIES);
+        assert!(growth > MAX_PERMITTED_DATA_INCREASE);
+    }
+}
+
+#[cfg(test)]
+mod test_zero_copy_whitelist {
+    use super::*;
+    
+    #[test]
+    fn layout_matches_space() {
+        assert_eq!(Whitelist::ENTRIES_OFFSET, 8 + 32 + 4 + 4);
+        assert_eq!(Whitelist::ENTRY_LEN, 32 + 8 + 24 + 8); // user, max, usage, tier + padding
+        assert_eq!(Whitelist::ENTRIES_OFFSET % 8, 0); // entry slots stay u64-aligned
+    }
+    
+    #[test]
+    fn header_is_read_from_data() {
+        let authority = Pubkey::new_unique();
+        let whitelist = WhitelistAccount::new(authority, 7);
+        let header = whitelist.header();
+        
+        assert_eq!(header.authority, authority);
+        assert_eq!(header.capacity, 7);
+        assert_eq!(header.len, 0);
+    }
+    
+    #[test]
+    fn insert_keeps_entries_sorted() {
+        let mut whitelist = WhitelistAccount::new(Pubkey::new_unique(), 10);
+        let mut users: Vec<Pubkey> = (0..8).map(|_| Pubkey::new_unique()).collect();
+        users.reverse();
+        
+        for user in &users {
+            assert!(whitelist.insert(*user, 0));
+        }
+        
+        let entries = whitelist.entries();
+        assert!(entries.windows(2).all(|w| w[0].user < w[1].user));
+        for user in &users {
+            assert!(whitelist.is_whitelisted(user));
+        }
+    }
+    
+    #[test]
+    fn duplicate_insert_is_rejected() {
+        let mut whitelist = WhitelistAccount::new(Pubkey::new_unique(), 10);
+        let user = Pubkey::new_unique();
+        
+        assert!(whitelist.insert(user, 1));
+        assert!(!whitelist.insert(user, 2));
+        assert_eq!(whitelist.len(), 1);
+        assert_eq!(whitelist.get_entry(&user).unwrap().max_amount, 1);
+    }
+    
+    #[test]
+    fn remove_entry_keeps_order() {
+        let mut whitelist = WhitelistAccount::new(Pubkey::new_unique(), 10);
+        let users: Vec<Pubkey> = (0..5).map(|_| Pubkey::new_unique()).collect();
+        for (i, user) in users.iter().enumerate().rev() {
+            whitelist.insert(*user, i as u64);
+        }
+        
+        assert!(whitelist.remove(&users[2]));
+        assert!(!whitelist.remove(&users[2]));
+        assert!(!whitelist.is_whitelisted(&users[2]));
+        
+        let entries = whitelist.entries();
+        assert!(entries.windows(2).all(|w| w[0].user < w[1].user));
+        assert_eq!(entries.len(), 4);
+        assert_eq!(whitelist.get_entry(&users[4]).unwrap().max_amount, 4);
+    }
+    
+    #[test]
+    fn unsorted_whitelist_is_found_until_sorted() {
+        let mut whitelist = WhitelistAccount::new(Pubkey::new_unique(), 10);
+        let mut users: Vec<Pubkey> = (0..6).map(|_| Pubkey::new_unique()).collect();
+        users.sort();
+        users.reverse();
+        // Written in insertion order, as before entries were kept sorted
+        let (header, slots) = Whitelist::from_data_mut(whitelist.bytes_mut()).unwrap();
+        for (slot, user) in slots.iter_mut().zip(&users) {
+            *slot = WhitelistEntry::new(*user, 1, 0);
+        }
+        header.len = users.len() as u32;
+        
+        assert!(users.iter().all(|user| whitelist.is_whitelisted(user)));
+        assert!(!whitelist.insert(users[3], 2), "duplicates are still caught");
+        
+        let (header, slots) = Whitelist::from_data_mut(whitelist.bytes_mut()).unwrap();
+        header.sort_entries(slots);
+        assert!(Whitelist::is_sorted(&whitelist.entries()));
+        assert!(users.iter().all(|user| whitelist.is_whitelisted(user)));
+    }
+    
+    #[test]
+    fn wrong_discriminator_is_rejected() {
+        let mut whitelist = WhitelistAccount::new(Pubkey::new_unique(), 1);
+        whitelist.bytes_mut()[0] ^= 0xff;
+        
+        assert!(Whitelist::from_data(whitelist.bytes()).is_err());
+    }
+    
+    #[test]
+    fn truncated_data_is_rejected() {
+        let mut whitelist = WhitelistAccount::new(Pubkey::new_unique(), 4);
+        whitelist.insert(Pubkey::new_unique(), 0);
+        whitelist.insert(Pubkey::new_unique(), 0);
+        
+        let bytes = whitelist.bytes();
+        assert!(Whitelist::from_data(&bytes[..Whitelist::space(1)]).is_err());
+    }
+    
+    #[test]
+    fn thousands_of_entries_fit_one_account() {
+        let capacity = 5_000;
+        let mut whitelist = WhitelistAccount::new(Pubkey::new_unique(), capacity);
+        let users: Vec<Pubkey> = (0..capacity).map(|_| Pubkey::new_unique()).collect();
+        
+        for user in users.iter().rev() {
+            whitelist.insert(*user, 0);
+        }
+        
+        assert!(!whitelist.can_add_entry());
+        assert!(users.iter().all(|u| whitelist.is_whitelisted(u)));
+        assert!(Whitelist::space(capacity) < 10 * 1024 * 1024);
+    }
+}
+
+#[cfg(test)]
+mod test_merkle_whitelist {
+    use super::*;
+    
+    fn allowlist(count: usize) -> (Vec<(Pubkey, u64)>, Vec<[u8; 32]>) {
+        let members: Vec<(Pubkey, u64)> = (0..count)
+            .map(|i| (Pubkey::new_unique(), i as u64 * 1_000))
+            .collect();
+        let leaves = members.iter().map(|(u, m)| merkle::leaf_hash(u, *m)).collect();
+        (members, leaves)
+    }
+    
+    fn config_with_root(merkle_root: [u8; 32]) -> VaultConfig {
+        VaultConfig {
+            authority: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            config_bump: 255,
+            vault_bump: 255,
+            whitelist_bump: 255,
+            extra_metas_bump: 255,
+            merkle_root,
+        }
+    }
+    
+    #[test]
+    fn every_member_proves_membership() {
+        for count in [1, 2, 3, 7, 64, 257] {
+            let (members, leaves) = allowlist(count);
+            let config = config_with_root(merkle::root(&leaves));
+            
+            for (i, (user, max_amount)) in members.iter().enumerate() {
+                let proof = merkle::proof(&leaves, i);
+                assert!(config.verify_whitelist_proof(user, *max_amount, &proof), "leaf {} of {}", i, count);
+            }
+        }
+    }
+    
+    #[test]
+    fn proof_is_logarithmic() {
+        let (_, leaves) = allowlist(1_000);
+        assert!(merkle::proof(&leaves, 500).len() <= 10);
+    }
+    
+    #[test]
+    fn wrong_amount_is_rejected() {
+        let (members, leaves) = allowlist(16);
+        let config = config_with_root(merkle::root(&leaves));
+        let (user, max_amount) = members[3];
+        
+        let proof = merkle::proof(&leaves, 3);
+        assert!(!config.verify_whitelist_proof(&user, max_amount + 1, &proof));
+    }
+    
+    #[test]
+    fn non_member_is_rejected() {
+        let (_, leaves) = allowlist(16);
+        let config = config_with_root(merkle::root(&leaves));
+        
+        let proof = merkle::proof(&leaves, 3);
+        assert!(!config.verify_whitelist_proof(&Pubkey::new_unique(), 3_000, &proof));
+    }
+    
+    #[test]
+    fn tampered_proof_is_rejected() {
+        let (members, leaves) = allowlist(16);
+        let config = config_with_root(merkle::root(&leaves));
+        let (user, max_amount) = members[5];
+        
+        let mut proof = merkle::proof(&leaves, 5);
+        proof[1][0] ^= 1;
+        assert!(!config.verify_whitelist_proof(&user, max_amount, &proof));
+    }
+    
+    #[test]
+    fn zero_root_disables_merkle_mode() {
+        let user = Pubkey::new_unique();
+        let leaves = vec![merkle::leaf_hash(&user, 0)];
+        assert_eq!(merkle::root(&[]), [0; 32]);
+        
+        let config = config_with_root([0; 32]);
+        assert!(!config.verify_whitelist_proof(&user, 0, &merkle::proof(&leaves, 0)));
+    }
+}
+
+#[cfg(test)]
+mod test_tiers {
+    use super::*;
+    
+    fn tier(tier_id: u8, per_tx_limit: u64, daily_limit: u64, lifetime_limit: u64) -> Tier {
+        Tier { vault_config: Pubkey::new_unique(), tier_id, per_tx_limit, daily_limit, lifetime_limit, bump: 255 }
+    }
+    
+    #[test]
+    fn tier_pdas_are_unique_per_id_and_vault() {
+        let vault_config = Pubkey::new_unique();
+        let derive = |config: &Pubkey, id: u8| {
+            Pubkey::find_program_address(&[TIER_SEED, config.as_ref(), &[id]], &program_id()).0
+        };
+        
+        assert_ne!(derive(&vault_config, 1), derive(&vault_config, 2));
+        assert_ne!(derive(&vault_config, 1), derive(&Pubkey::new_unique(), 1));
+    }
+    
+    #[test]
+    fn per_tx_limit_enforced() {
+        let retail = tier(1, 100, 0, 0);
+        let mut usage = TierUsage::default();
+        
+        assert!(retail.record(&mut usage, 100, 0).is_ok());
+        assert!(retail.record(&mut usage, 101, 0).is_err());
+        assert_eq!(usage.lifetime_used, 100);
+    }
+    
+    #[test]
+    fn daily_limit_resets_next_day() {
+        let pro = tier(2, 0, 1_000, 0);
+        let mut usage = TierUsage::default();
+        
+        assert!(pro.record(&mut usage, 600, 10).is_ok());
+        assert!(pro.record(&mut usage, 400, 10).is_ok());
+        assert!(pro.record(&mut usage, 1, 10).is_err());
+        
+        assert!(pro.record(&mut usage, 1_000, 11).is_ok());
+        assert_eq!(usage.daily_used, 1_000);
+        assert_eq!(usage.lifetime_used, 2_000);
+    }
+    
+    #[test]
+    fn lifetime_limit_enforced_across_days() {
+        let institutional = tier(3, 0, 0, 1_500);
+        let mut usage = TierUsage::default();
+        
+        assert!(institutional.record(&mut usage, 1_000, 1).is_ok());
+        assert!(institutional.record(&mut usage, 1_000, 2).is_err());
+        assert!(institutional.record(&mut usage, 500, 2).is_ok());
+    }
+    
+    #[test]
+    fn failed_transfer_does_not_count() {
+        let limited = tier(1, 0, 100, 0);
+        let mut usage = TierUsage::default();
+        
+        assert!(limited.record(&mut usage, 80, 5).is_ok());
+        assert!(limited.record(&mut usage, 30, 5).is_err());
+        assert_eq!(usage.daily_used, 80);
+        assert!(limited.record(&mut usage, 20, 5).is_ok());
+    }
+    
+    #[test]
+    fn entry_without_tier_uses_own_limit() {
+        let mut entry = WhitelistEntry::new(Pubkey::new_unique(), 50, 0);
+        
+        assert!(entry.record_transfer(None, 50, 0).is_ok());
+        assert!(entry.record_transfer(None, 51, 0).is_err());
+        assert_eq!(entry.usage.lifetime_used, 0);
+    }
+    
+    #[test]
+    fn entry_requires_its_tier() {
+        let mut entry = WhitelistEntry::new(Pubkey::new_unique(), 0, 2);
+        let wrong_tier = tier(1, 0, 0, 0);
+        let right_tier = tier(2, 0, 0, 0);
+        
+        assert!(entry.record_transfer(None, 10, 0).is_err());
+        assert!(entry.record_transfer(Some(&wrong_tier), 10, 0).is_err());
+        assert!(entry.record_transfer(Some(&right_tier), 10, 0).is_ok());
+        assert_eq!(entry.usage.lifetime_used, 10);
+    }
+    
+    #[test]
+    fn tier_change_applies_to_every_entry() {
+        let mut pro = tier(2, 0, 100, 0);
+        let mut alice = WhitelistEntry::new(Pubkey::new_unique(), 0, 2);
+        let mut bob = WhitelistEntryPda {
+            user: Pubkey::new_unique(),
+            max_amount: 0,
+            vault_config: pro.vault_config,
+            bump: 255,
+            tier_id: 2,
+            usage: TierUsage::default(),
+        };
+        
+        assert!(alice.record_transfer(Some(&pro), 150, 0).is_err());
+        assert!(bob.record_transfer(Some(&pro), 150, 0).is_err());
+        
+        pro.daily_limit = 200;
+        assert!(alice.record_transfer(Some(&pro), 150, 0).is_ok());
+        assert!(bob.record_transfer(Some(&pro), 150, 0).is_ok());
+    }
+}
+
+#[cfg(test)]
+mod test_events {
+    use super::*;
+    use anchor_lang::{AnchorDeserialize, Event};
+    
+    #[test]
+    fn event_data_is_discriminator_then_borsh() {
+        let event = Deposited {
+            vault_config: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            depositor: Pubkey::new_unique(),
+            amount: 1_000,
+            timestamp: 1_700_000_000,
+        };
+        let data = event.data();
+        
+        assert_eq!(&data[..8], Deposited::DISCRIMINATOR);
+        let decoded = Deposited::try_from_slice(&data[8..]).unwrap();
+        assert_eq!(decoded.depositor, event.depositor);
+        assert_eq!(decoded.amount, 1_000);
+    }
+    
+    #[test]
+    fn validated_and_rejected_are_distinct() {
+        assert_ne!(TransferValidated::DISCRIMINATOR, TransferRejected::DISCRIMINATOR);
+        assert_ne!(WhitelistAdded::DISCRIMINATOR, WhitelistRemoved::DISCRIMINATOR);
+    }
+    
+    #[test]
+    fn rejection_carries_error_code() {
+        let event = TransferRejected {
+            mint: Pubkey::new_unique(),
+            source: Pubkey::new_unique(),
+            destination: Pubkey::new_unique(),
+            source_authority: Pubkey::new_unique(),
+            amount: 5,
+            error_code: 6004,
+            timestamp: 0,
+        };
+        let decoded = TransferRejected::try_from_slice(&event.data()[8..]).unwrap();
+        
+        assert_eq!(decoded.error_code, 6004);
+        assert_eq!(decoded.source_authority, event.source_authority);
+    }
+}
+
+#[cfg(test)]
+mod test_client {
+    use super::*;
+    use std::collections::HashMap;
+    use anchor_lang::{AccountSerialize, InstructionData};
+    use transfer_hook_vault::{client, instruction};
+    
+    fn serialize<T: AccountSerialize>(account: &T) -> Vec<u8> {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        data
+    }
+    
+    #[test]
+    fn pdas_use_program_id() {
+        let mint = Pubkey::new_unique();
+        let expected = Pubkey::find_program_address(&[VAULT_CONFIG_SEED, mint.as_ref()], &program_id());
+        
+        assert_eq!(client::derive_vault_config(&mint), expected);
+    }
+    
+    #[test]
+    fn deposit_builder_targets_derived_accounts() {
+        let mint = Pubkey::new_unique();
+        let depositor = Pubkey::new_unique();
+        let token_account = Pubkey::new_unique();
+        let ix = client::deposit(&depositor, &mint, &token_account, 500, 0, None);
+        
+        assert_eq!(ix.program_id, program_id());
+        assert_eq!(ix.data, instruction::Deposit { amount: 500, proof: None }.data());
+        assert!(ix.accounts[0].is_signer && ix.accounts[0].pubkey == depositor);
+        assert_eq!(ix.accounts[1].pubkey, derive_vault_config(&mint).0);
+        assert!(ix.accounts[2].is_writable && ix.accounts[2].pubkey == derive_whitelist(&mint).0);
+        // Anchor passes the program id in place of an omitted optional account
+        assert_eq!(ix.accounts[3].pubkey, program_id());
+        assert!(ix.accounts.iter().any(|m| m.pubkey == derive_vault(&mint).0 && m.is_writable));
+    }
+    
+    #[test]
+    fn deposit_builder_includes_tier() {
+        let mint = Pubkey::new_unique();
+        let ix = client::deposit(&Pubkey::new_unique(), &mint, &Pubkey::new_unique(), 1, 2, None);
+        
+        assert_eq!(ix.accounts[3].pubkey, derive_tier(&derive_vault_config(&mint).0, 2).0);
+    }
+    
+    #[test]
+    fn transfer_with_hook_appends_extra_accounts() {
+        let mint = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let ix = client::transfer_checked_with_hook(
+            &Pubkey::new_unique(), &mint, &Pubkey::new_unique(), &owner, 10, 6,
+        ).unwrap();
+        let vault_config = derive_vault_config(&mint).0;
+        let keys: Vec<Pubkey> = ix.accounts.iter().map(|m| m.pubkey).collect();
+        
+        assert_eq!(keys.len(), 4 + 4 + MAX_TIERS as usize + 2);
+        assert_eq!(keys[4], derive_vault(&mint).0);
+        assert_eq!(keys[7], derive_whitelist_entry_pda(&vault_config, &owner).0);
+        assert_eq!(keys[8], derive_tier(&vault_config, 1).0);
+        assert_eq!(keys[keys.len() - 2], program_id());
+        assert_eq!(keys[keys.len() - 1], derive_extra_metas(&mint).0);
+    }
+    
+    #[test]
+    fn whitelist_builders_pass_holder_accounts_together() {
+        let mint = Pubkey::new_unique();
+        let user = Pubkey::new_unique();
+        let holder = Pubkey::new_unique();
+        let without = client::add_to_whitelist(&Pubkey::new_unique(), &mint, &user, 0, 0, None);
+        let with = client::pda_whitelist_remove(&Pubkey::new_unique(), &mint, &user, Some(&holder));
+        
+        // Anchor passes the program id in place of each omitted optional account
+        assert!(without.accounts[3..].iter().all(|m| m.pubkey == program_id()));
+        let keys: Vec<Pubkey> = with.accounts[3..].iter().map(|m| m.pubkey).collect();
+        assert_eq!(keys, vec![mint, holder, anchor_spl::token_2022::ID]);
+        assert!(with.accounts[4].is_writable);
+    }
+    
+    #[test]
+    fn freeze_builder_signs_with_vault_config() {
+        let mint = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let token_account = Pubkey::new_unique();
+        let freeze = client::freeze_account(&authority, &mint, &token_account);
+        let thaw = client::thaw_account(&authority, &mint, &token_account);
+        
+        assert_eq!(freeze.data, instruction::FreezeAccount {}.data());
+        assert_eq!(thaw.data, instruction::ThawAccount {}.data());
+        for ix in [&freeze, &thaw] {
+            assert!(ix.accounts[0].is_signer && ix.accounts[0].pubkey == authority);
+            assert_eq!(ix.accounts[1].pubkey, derive_vault_config(&mint).0);
+            assert!(ix.accounts[3].is_writable && ix.accounts[3].pubkey == token_account);
+        }
+    }
+    
+    #[test]
+    fn metadata_builders_target_the_mint() {
+        use transfer_hook_vault::instructions::{MetadataField, TokenMetadataArgs};
+        let mint = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let create = client::create_mint(&authority, &mint, 6, false, Some(TokenMetadataArgs::default()));
+        let update = client::update_token_metadata_field(&authority, &mint, MetadataField::Uri, "https://x".into());
+        let remove = client::remove_metadata_key(&authority, &mint, "region".into(), true);
+        
+        assert_eq!(create.accounts[2].pubkey, derive_vault_config(&mint).0);
+        assert_eq!(
+            remove.data,
+            instruction::RemoveMetadataKey { key: "region".into(), idempotent: true }.data()
+        );
+        for ix in [&update, &remove] {
+            assert!(ix.accounts[0].is_signer && ix.accounts[0].pubkey == authority);
+            assert_eq!(ix.accounts[1].pubkey, derive_vault_config(&mint).0);
+            assert!(ix.accounts[2].is_writable && ix.accounts[2].pubkey == mint);
+        }
+    }
+    
+    #[test]
+    fn close_vault_builder_appends_closable_accounts() {
+        let mint = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let vault_config = derive_vault_config(&mint).0;
+        let entry = derive_whitelist_entry_pda(&vault_config, &Pubkey::new_unique()).0;
+        let tier = derive_tier(&vault_config, 1).0;
+        let ix = client::close_vault(&authority, &mint, &[entry, tier]);
+        
+        assert!(ix.accounts[0].is_signer && ix.accounts[0].is_writable);
+        assert_eq!(ix.accounts[2].pubkey, mint);
+        assert_eq!(ix.accounts[4].pubkey, derive_extra_metas(&mint).0);
+        let closable: Vec<_> = ix.accounts[7..].iter().map(|meta| (meta.pubkey, meta.is_writable)).collect();
+        assert_eq!(closable, vec![(entry, true), (tier, true)]);
+    }
+    
+    #[test]
+    fn hook_builder_matches_resolved_accounts() {
+        let mint = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let (source, destination) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let hook = client::transfer_hook(&source, &mint, &destination, &owner, 10);
+        let transfer = client::transfer_checked_with_hook(&source, &mint, &destination, &owner, 10, 6).unwrap();
+        
+        let hook_keys: Vec<Pubkey> = hook.accounts.iter().map(|m| m.pubkey).collect();
+        for meta in &transfer.accounts[..transfer.accounts.len() - 1] {
+            if meta.pubkey != program_id() {
+                assert!(hook_keys.contains(&meta.pubkey));
+            }
+        }
+    }
+    
+    #[test]
+    fn decode_whitelist_from_unaligned_data() {
+        let mut account = WhitelistAccount::new(Pubkey::new_unique(), 4);
+        let users: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        for (i, user) in users.iter().enumerate() {
+            account.insert(*user, i as u64 * 100);
+        }
+        let mut shifted = vec![0u8];
+        shifted.extend_from_slice(account.bytes());
+        
+        let (header, entries) = client::decode_whitelist(&shifted[1..]).unwrap();
+        assert_eq!(header.len, 3);
+        assert_eq!(entries.len(), 3);
+        assert!(entries.windows(2).all(|w| w[0].user < w[1].user));
+    }
+    
+    #[test]
+    fn decode_whitelist_rejects_wrong_discriminator() {
+        let mut account = WhitelistAccount::new(Pubkey::new_unique(), 1);
+        account.bytes_mut()[0] ^= 1;
+        
+        assert!(client::decode_whitelist(account.bytes()).is_err());
+    }
+    
+    #[test]
+    fn fetch_helpers_read_from_any_fetcher() {
+        let mint = Pubkey::new_unique();
+        let (vault_config, config_bump) = derive_vault_config(&mint);
+        let vec_user = Pubkey::new_unique();
+        let pda_user = Pubkey::new_unique();
+        
+        let mut whitelist = WhitelistAccount::new(Pubkey::new_unique(), 2);
+        whitelist.insert(vec_user, 0);
+        let config = VaultConfig {
+            authority: Pubkey::new_unique(),
+            mint,
+            config_bump,
+            vault_bump: 0,
+            whitelist_bump: 0,
+            extra_metas_bump: 0,
+            merkle_root: [0; 32],
+        };
+        let entry = WhitelistEntryPda {
+            user: pda_user,
+            max_amount: 7,
+            vault_config,
+            bump: 0,
+            tier_id: 0,
+            usage: TierUsage::default(),
+        };
+        
+        let mut bank: HashMap<Pubkey, Vec<u8>> = HashMap::new();
+        bank.insert(vault_config, serialize(&config));
+        bank.insert(derive_whitelist(&mint).0, whitelist.bytes().to_vec());
+        bank.insert(derive_whitelist_entry_pda(&vault_config, &pda_user).0, serialize(&entry));
+        
+        assert_eq!(client::fetch_vault_config(&bank, &mint).unwrap().unwrap().mint, mint);
+        assert_eq!(client::fetch_whitelist_entry_pda(&bank, &mint, &pda_user).unwrap().unwrap().max_amount, 7);
+        assert!(client::fetch_tier(&bank, &mint, 1).unwrap().is_none());
+        assert!(client::is_whitelisted(&bank, &mint, &vec_user).unwrap());
+        assert!(client::is_whitelisted(&bank, &mint, &pda_user).unwrap());
+        assert!(!client::is_whitelisted(&bank, &mint, &Pubkey::new_unique()).unwrap());
+    }
+}
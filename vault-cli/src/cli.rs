@@ -0,0 +1,853 @@
+use std::path::PathBuf;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use base64::{prelude::BASE64_STANDARD, Engine};
+use pda_token_vault::instructions::{ConfidentialTransferArgs, ExtensionArgs, TokenMetadataArgs};
+use solana_sdk::pubkey::Pubkey;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use transfer_hook_vault::constants::MAX_WHITELIST_ENTRIES;
+use crate::reconcile::Format;
+
+#[derive(Parser, Debug)]
+#[command(name = "vault-cli", version, about = "Operate a deployed vault from the command line")]
+pub struct Cli {
+    /// RPC endpoint
+    #[arg(long, short = 'u', global = true, env = "VAULT_RPC_URL", default_value = "http://127.0.0.1:8899")]
+    pub url: String,
+
+    /// Fee payer and vault authority
+    #[arg(long, short = 'k', global = true, env = "VAULT_KEYPAIR", default_value = "~/.config/solana/id.json")]
+    pub keypair: String,
+
+    #[arg(long, short = 'o', global = true, value_enum, default_value_t = OutputFormat::Table)]
+    pub output: OutputFormat,
+
+    /// Which vault program to operate on
+    #[arg(long, short = 'p', global = true, value_enum, default_value_t = Program::Hook)]
+    pub program: Program,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    Table,
+    Json,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Program {
+    /// transfer-hook-vault
+    Hook,
+    /// pda-token-vault
+    Pda,
+}
+
+/// Where a whitelist entry lives
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Backend {
+    /// The hook vault's single whitelist account
+    Vec,
+    /// One PDA per user
+    Pda,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Create a Token-2022 mint (transfer hook for `hook`, configurable extensions for `pda`)
+    CreateMint(CreateMintArgs),
+
+    /// Initialize the vault config for a mint
+    Init {
+        #[arg(long)]
+        mint: Pubkey,
+
+        /// Whitelist entry slots (hook only)
+        #[arg(long, default_value_t = MAX_WHITELIST_ENTRIES as u32)]
+        capacity: u32,
+    },
+
+    /// Write the transfer hook's extra account metas (hook only)
+    InitExtraMetas {
+        #[arg(long)]
+        mint: Pubkey,
+    },
+
+    /// Manage whitelist entries
+    #[command(subcommand)]
+    Whitelist(WhitelistCommand),
+
+    /// Deposit from the signer's token account into the vault
+    Deposit(TransferArgs),
+
+    /// Withdraw from the vault into the signer's token account
+    Withdraw(TransferArgs),
+
+    /// Mint tokens as the mint authority
+    Mint {
+        #[arg(long)]
+        mint: Pubkey,
+
+        #[arg(long)]
+        amount: u64,
+
+        /// Token account to mint to; defaults to the signer's associated account
+        #[arg(long)]
+        destination: Option<Pubkey>,
+    },
+
+    /// Freeze a holder's token account (the vault config is the freeze authority)
+    Freeze(HolderArgs),
+
+    /// Thaw a frozen token account
+    Thaw(HolderArgs),
+
+    /// Force tokens out of a holder's account via the permanent delegate (pda only)
+    Clawback(ClawbackArgs),
+
+    /// Hand the clawback role to another key (pda only)
+    SetClawbackAuthority {
+        #[arg(long)]
+        mint: Pubkey,
+
+        #[arg(long)]
+        new_authority: Pubkey,
+    },
+
+    /// Edit the mint's embedded token metadata
+    #[command(subcommand)]
+    Metadata(MetadataCommand),
+
+    /// Issue a soulbound credential token to every user whitelisted from now on (pda only)
+    CreateCredentialMint {
+        #[arg(long)]
+        mint: Pubkey,
+    },
+
+    /// Approve a whitelisted holder's account for confidential transfers,
+    /// for mints created without `--auto-approve` (pda only)
+    ApproveConfidential {
+        #[arg(long)]
+        mint: Pubkey,
+
+        #[arg(long)]
+        owner: Pubkey,
+
+        /// Defaults to the owner's associated account
+        #[arg(long)]
+        token_account: Option<Pubkey>,
+    },
+
+    /// Move part of the vault's public balance into its confidential
+    /// balance (pda only; the vault must already be configured)
+    ConfidentialDeposit {
+        #[arg(long)]
+        mint: Pubkey,
+
+        #[arg(long)]
+        amount: u64,
+    },
+
+    /// Start the registry of a group mint's member vaults (pda only)
+    CreateRegistry {
+        #[arg(long)]
+        mint: Pubkey,
+    },
+
+    /// List the member mints and vaults of a group mint (pda only)
+    ListGroup {
+        #[arg(long)]
+        mint: Pubkey,
+    },
+
+    /// Manage a vault that holds several mints under one whitelist (pda only)
+    #[command(subcommand)]
+    MultiVault(MultiVaultCommand),
+
+    /// Make withdrawals wait out a cooling-off window the guardian can veto in (pda only)
+    #[command(subcommand)]
+    Timelock(TimelockCommand),
+
+    /// Reserve vault tokens for a beneficiary and release them on a schedule (pda only)
+    #[command(subcommand)]
+    Vesting(VestingCommand),
+
+    /// Require M-of-N approvers to sign off on large withdrawals (pda only)
+    #[command(subcommand)]
+    Approval(ApprovalCommand),
+
+    /// Change an interest-bearing mint's annual rate (pda only)
+    SetInterestRate {
+        #[arg(long)]
+        mint: Pubkey,
+
+        /// Basis points; negative rates shrink displayed balances
+        #[arg(long, allow_negative_numbers = true)]
+        rate: i16,
+    },
+
+    /// Remove every whitelist entry, then close the empty vault and return
+    /// its rent, along with any open withdrawal tickets, proposals and
+    /// released vesting schedules on the PDA vault; closes the mint too if
+    /// it was created with `--enable-mint-close-authority`
+    CloseVault {
+        #[arg(long)]
+        mint: Pubkey,
+
+        /// Entry removals per transaction
+        #[arg(long, default_value_t = 5)]
+        batch_size: usize,
+    },
+
+    /// Print the vault config and whitelist summary
+    ShowConfig {
+        #[arg(long)]
+        mint: Pubkey,
+    },
+}
+
+#[derive(Args, Debug)]
+pub struct CreateMintArgs {
+    #[arg(long, default_value_t = 9)]
+    pub decimals: u8,
+
+    /// Keypair for the new mint; a fresh one is generated if omitted
+    #[arg(long)]
+    pub mint_keypair: Option<String>,
+
+    #[arg(long)]
+    pub enable_transfer_fee: bool,
+
+    /// Transfer fee in basis points (100 = 1%)
+    #[arg(long, default_value_t = 0)]
+    pub transfer_fee_basis_points: u16,
+
+    #[arg(long, default_value_t = 0)]
+    pub max_fee: u64,
+
+    /// Make the vault config the permanent delegate, enabling `clawback`
+    #[arg(long)]
+    pub enable_permanent_delegate: bool,
+
+    /// New token accounts start frozen until the holder is whitelisted (both programs)
+    #[arg(long)]
+    pub enable_default_frozen: bool,
+
+    /// Make the mint interest-bearing at this annual rate in basis points
+    #[arg(long, allow_negative_numbers = true)]
+    pub interest_rate: Option<i16>,
+
+    /// Let `close-vault` close the mint once its supply is zero
+    #[arg(long)]
+    pub enable_mint_close_authority: bool,
+
+    /// Allow confidential transfers, with the vault config as their authority
+    #[arg(long)]
+    pub confidential: bool,
+
+    /// Configured accounts can transfer confidentially without approval
+    #[arg(long, requires = "confidential")]
+    pub auto_approve: bool,
+
+    /// Base64 ElGamal key that can decrypt every confidential amount
+    #[arg(long, requires = "confidential", value_parser = parse_elgamal_pubkey)]
+    pub auditor_elgamal_pubkey: Option<[u8; 32]>,
+
+    /// Make the mint a token group of up to this many member mints
+    #[arg(long)]
+    pub group_max_size: Option<u32>,
+
+    /// Join this group mint; its vault and registry must already exist
+    #[arg(long)]
+    pub group_mint: Option<Pubkey>,
+
+    /// Token name stored in the mint's metadata (both programs)
+    #[arg(long, requires_all = ["symbol", "uri"])]
+    pub name: Option<String>,
+
+    #[arg(long, requires = "name")]
+    pub symbol: Option<String>,
+
+    #[arg(long, requires = "name")]
+    pub uri: Option<String>,
+}
+
+impl CreateMintArgs {
+    pub fn extension_args(&self) -> ExtensionArgs {
+        ExtensionArgs {
+            enable_transfer_fee: self.enable_transfer_fee,
+            transfer_fee_basis_points: self.transfer_fee_basis_points,
+            max_fee: self.max_fee,
+            enable_permanent_delegate: self.enable_permanent_delegate,
+            enable_default_frozen: self.enable_default_frozen,
+            enable_interest_bearing: self.interest_rate.is_some(),
+            interest_rate: self.interest_rate.unwrap_or_default(),
+            enable_mint_close_authority: self.enable_mint_close_authority,
+            confidential_transfer: self.confidential.then_some(ConfidentialTransferArgs {
+                auto_approve_new_accounts: self.auto_approve,
+                auditor_elgamal_pubkey: self.auditor_elgamal_pubkey,
+            }),
+            group_max_size: self.group_max_size,
+            metadata: self.metadata(),
+        }
+    }
+
+    /// `--name/--symbol/--uri`, which clap requires together
+    pub fn metadata(&self) -> Option<TokenMetadataArgs> {
+        Some(TokenMetadataArgs {
+            name: self.name.clone()?,
+            symbol: self.symbol.clone()?,
+            uri: self.uri.clone()?,
+        })
+    }
+
+    /// Whether any flag only the PDA vault's mint supports is set
+    pub fn has_extensions(&self) -> bool {
+        self.enable_transfer_fee
+            || self.enable_permanent_delegate
+            || self.interest_rate.is_some()
+            || self.enable_mint_close_authority
+            || self.confidential
+            || self.group_max_size.is_some()
+            || self.group_mint.is_some()
+    }
+}
+
+#[derive(Subcommand, Debug)]
+pub enum MetadataCommand {
+    /// Set `name`, `symbol`, `uri` or any other key to a value
+    Set {
+        #[arg(long)]
+        mint: Pubkey,
+
+        #[arg(long)]
+        field: String,
+
+        #[arg(long)]
+        value: String,
+    },
+
+    /// Remove an additional metadata key
+    Remove {
+        #[arg(long)]
+        mint: Pubkey,
+
+        #[arg(long)]
+        key: String,
+
+        /// Succeed even if the key is absent
+        #[arg(long)]
+        idempotent: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum WhitelistCommand {
+    /// Whitelist a user
+    Add {
+        #[arg(long)]
+        mint: Pubkey,
+
+        #[arg(long)]
+        user: Pubkey,
+
+        /// Per-transfer cap; 0 = unlimited
+        #[arg(long, default_value_t = 0)]
+        max_amount: u64,
+
+        /// Tier id (hook only); 0 = none
+        #[arg(long, default_value_t = 0)]
+        tier: u8,
+
+        /// Defaults to `vec` for the hook vault and `pda` for the PDA vault
+        #[arg(long, value_enum)]
+        backend: Option<Backend>,
+
+        /// Also thaw the user's associated token account (for default-frozen mints)
+        #[arg(long)]
+        thaw: bool,
+    },
+
+    /// Remove a user's entry
+    Remove {
+        #[arg(long)]
+        mint: Pubkey,
+
+        #[arg(long)]
+        user: Pubkey,
+
+        #[arg(long, value_enum)]
+        backend: Option<Backend>,
+
+        /// Also freeze the user's associated token account
+        #[arg(long)]
+        freeze: bool,
+    },
+
+    /// List entries from every backend the program supports
+    List {
+        #[arg(long)]
+        mint: Pubkey,
+    },
+
+    /// Show whether a user is whitelisted and under which backend
+    Check {
+        #[arg(long)]
+        mint: Pubkey,
+
+        #[arg(long)]
+        user: Pubkey,
+    },
+
+    /// Reconcile a backend with a CSV or JSON snapshot of `wallet,limit[,tier]`
+    Import {
+        #[arg(long)]
+        mint: Pubkey,
+
+        #[arg(long)]
+        file: PathBuf,
+
+        /// Defaults to the file extension
+        #[arg(long, value_enum)]
+        format: Option<Format>,
+
+        #[arg(long, value_enum)]
+        backend: Option<Backend>,
+
+        /// Print the changes without sending them
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Changes per transaction
+        #[arg(long, default_value_t = 5)]
+        batch_size: usize,
+    },
+
+    /// Change the Vec whitelist's slot count (hook only); growth beyond one
+    /// call's realloc limit is sent as several resizes
+    Resize {
+        #[arg(long)]
+        mint: Pubkey,
+
+        #[arg(long)]
+        capacity: u32,
+    },
+
+    /// Sort a Vec whitelist filled before entries were kept in order (hook
+    /// only), so the transfer hook can binary-search it
+    Sort {
+        #[arg(long)]
+        mint: Pubkey,
+    },
+
+    /// Snapshot a backend in the format `import` reads
+    Export {
+        #[arg(long)]
+        mint: Pubkey,
+
+        /// Defaults to stdout
+        #[arg(long)]
+        file: Option<PathBuf>,
+
+        /// Defaults to the file extension, or CSV on stdout
+        #[arg(long, value_enum)]
+        format: Option<Format>,
+
+        #[arg(long, value_enum)]
+        backend: Option<Backend>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ApprovalCommand {
+    /// Replace the approver set; a quorum of 0 turns approvals off
+    Set {
+        #[arg(long)]
+        mint: Pubkey,
+
+        /// Repeat for each approver
+        #[arg(long = "approver")]
+        approvers: Vec<Pubkey>,
+
+        /// Approvals a proposal needs
+        #[arg(long)]
+        quorum: u8,
+
+        /// Largest withdrawal that skips approval, in base units
+        #[arg(long)]
+        threshold: u64,
+    },
+
+    /// Propose a withdrawal above the threshold into the signer's token account
+    Propose {
+        #[arg(long)]
+        mint: Pubkey,
+
+        #[arg(long)]
+        amount: u64,
+
+        /// Defaults to the signer's associated account
+        #[arg(long)]
+        token_account: Option<Pubkey>,
+    },
+
+    /// Approve a user's proposal; the approval reaching the quorum pays it
+    /// out if the withdrawal delay has passed
+    Approve {
+        #[arg(long)]
+        mint: Pubkey,
+
+        #[arg(long)]
+        withdrawer: Pubkey,
+    },
+
+    /// Cancel the signer's open proposal
+    Cancel {
+        #[arg(long)]
+        mint: Pubkey,
+    },
+
+    /// Pay out the signer's approved proposal once its delay has passed
+    Execute {
+        #[arg(long)]
+        mint: Pubkey,
+    },
+
+    /// Guardian only: cancel a user's open proposal
+    Veto {
+        #[arg(long)]
+        mint: Pubkey,
+
+        #[arg(long)]
+        withdrawer: Pubkey,
+    },
+
+    /// Show a user's open proposal
+    Show {
+        #[arg(long)]
+        mint: Pubkey,
+
+        /// Defaults to the signer
+        #[arg(long)]
+        withdrawer: Option<Pubkey>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum VestingCommand {
+    /// Reserve `total` of the vault's balance, vesting linearly over `duration`
+    Create {
+        #[arg(long)]
+        mint: Pubkey,
+
+        #[arg(long)]
+        beneficiary: Pubkey,
+
+        /// Tells apart a beneficiary's schedules
+        #[arg(long, default_value_t = 0)]
+        schedule_id: u64,
+
+        #[arg(long)]
+        total: u64,
+
+        /// Unix timestamp; defaults to now
+        #[arg(long)]
+        start: Option<i64>,
+
+        /// Seconds after start before anything vests
+        #[arg(long, default_value_t = 0)]
+        cliff: i64,
+
+        /// Seconds from start until everything has vested
+        #[arg(long)]
+        duration: i64,
+    },
+
+    /// Release the signer's vested tokens
+    Withdraw {
+        #[arg(long)]
+        mint: Pubkey,
+
+        #[arg(long, default_value_t = 0)]
+        schedule_id: u64,
+
+        /// Defaults to the signer's associated account
+        #[arg(long)]
+        token_account: Option<Pubkey>,
+    },
+
+    /// Show a beneficiary's schedule and what it can release now
+    Show {
+        #[arg(long)]
+        mint: Pubkey,
+
+        /// Defaults to the signer
+        #[arg(long)]
+        beneficiary: Option<Pubkey>,
+
+        #[arg(long, default_value_t = 0)]
+        schedule_id: u64,
+    },
+
+    /// Close a fully released schedule, returning its rent to the authority
+    Close {
+        #[arg(long)]
+        mint: Pubkey,
+
+        #[arg(long)]
+        beneficiary: Pubkey,
+
+        #[arg(long, default_value_t = 0)]
+        schedule_id: u64,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum TimelockCommand {
+    /// Set the delay and guardian; a delay of 0 re-enables instant withdrawals
+    Set {
+        #[arg(long)]
+        mint: Pubkey,
+
+        /// Seconds between request and claim
+        #[arg(long)]
+        delay: i64,
+
+        /// Defaults to the signer
+        #[arg(long)]
+        guardian: Option<Pubkey>,
+    },
+
+    /// Request a withdrawal, claimable once the delay has passed
+    Request {
+        #[arg(long)]
+        mint: Pubkey,
+
+        #[arg(long)]
+        amount: u64,
+    },
+
+    /// Claim the signer's unlocked withdrawal
+    Claim {
+        #[arg(long)]
+        mint: Pubkey,
+
+        /// Defaults to the signer's associated account
+        #[arg(long)]
+        token_account: Option<Pubkey>,
+    },
+
+    /// Cancel the signer's pending withdrawal
+    Cancel {
+        #[arg(long)]
+        mint: Pubkey,
+    },
+
+    /// Cancel a user's pending withdrawal as the guardian
+    Veto {
+        #[arg(long)]
+        mint: Pubkey,
+
+        #[arg(long)]
+        user: Pubkey,
+    },
+
+    /// Show a user's pending withdrawal
+    Show {
+        #[arg(long)]
+        mint: Pubkey,
+
+        /// Defaults to the signer
+        #[arg(long)]
+        user: Option<Pubkey>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum MultiVaultCommand {
+    /// Create a multi-mint vault owned by the signer
+    Create {
+        #[command(flatten)]
+        vault: MultiVaultArgs,
+    },
+
+    /// Accept a mint, Token-2022 or legacy Token, with its own withdrawal cap
+    AddMint {
+        #[command(flatten)]
+        vault: MultiVaultArgs,
+
+        #[arg(long)]
+        mint: Pubkey,
+
+        /// Per-withdrawal cap for this mint; 0 = unlimited
+        #[arg(long, default_value_t = 0)]
+        max_amount: u64,
+    },
+
+    /// Change a mint's withdrawal cap
+    SetLimit {
+        #[command(flatten)]
+        vault: MultiVaultArgs,
+
+        #[arg(long)]
+        mint: Pubkey,
+
+        #[arg(long)]
+        max_amount: u64,
+    },
+
+    /// Let a user withdraw any of the vault's mints
+    AddMember {
+        #[command(flatten)]
+        vault: MultiVaultArgs,
+
+        #[arg(long)]
+        user: Pubkey,
+    },
+
+    /// Remove a user's membership
+    RemoveMember {
+        #[command(flatten)]
+        vault: MultiVaultArgs,
+
+        #[arg(long)]
+        user: Pubkey,
+    },
+
+    /// Deposit one of the vault's mints from the signer's token account
+    Deposit {
+        #[command(flatten)]
+        vault: MultiVaultArgs,
+
+        #[command(flatten)]
+        transfer: MultiVaultTransferArgs,
+    },
+
+    /// Withdraw one of the vault's mints into the signer's token account
+    Withdraw {
+        #[command(flatten)]
+        vault: MultiVaultArgs,
+
+        #[command(flatten)]
+        transfer: MultiVaultTransferArgs,
+    },
+
+    /// List the vault's mints and their caps
+    Show {
+        #[command(flatten)]
+        vault: MultiVaultArgs,
+    },
+}
+
+/// Which multi-mint vault: an authority can own several, told apart by id
+#[derive(Args, Debug)]
+pub struct MultiVaultArgs {
+    #[arg(long, default_value_t = 0)]
+    pub vault_id: u64,
+
+    /// Defaults to the signer
+    #[arg(long)]
+    pub authority: Option<Pubkey>,
+}
+
+impl MultiVaultArgs {
+    pub fn authority(&self, signer: &Pubkey) -> Pubkey {
+        self.authority.unwrap_or(*signer)
+    }
+}
+
+#[derive(Args, Debug)]
+pub struct MultiVaultTransferArgs {
+    #[arg(long)]
+    pub mint: Pubkey,
+
+    /// Amount in base units
+    #[arg(long)]
+    pub amount: u64,
+
+    /// Signer's token account; defaults to its associated account
+    #[arg(long)]
+    pub token_account: Option<Pubkey>,
+}
+
+#[derive(Args, Debug)]
+pub struct TransferArgs {
+    #[arg(long)]
+    pub mint: Pubkey,
+
+    /// Amount in base units
+    #[arg(long)]
+    pub amount: u64,
+
+    /// Signer's token account; defaults to its associated account
+    #[arg(long)]
+    pub token_account: Option<Pubkey>,
+
+    /// Tier of the signer's whitelist entry (hook only)
+    #[arg(long, default_value_t = 0)]
+    pub tier: u8,
+}
+
+/// A token account, given directly or as its owner's associated account
+#[derive(Args, Debug)]
+pub struct HolderArgs {
+    #[arg(long)]
+    pub mint: Pubkey,
+
+    #[arg(long, required_unless_present = "token_account", conflicts_with = "token_account")]
+    pub owner: Option<Pubkey>,
+
+    #[arg(long)]
+    pub token_account: Option<Pubkey>,
+}
+
+#[derive(Args, Debug)]
+pub struct ClawbackArgs {
+    #[command(flatten)]
+    pub holder: HolderArgs,
+
+    /// Amount in base units
+    #[arg(long)]
+    pub amount: u64,
+
+    /// Non-zero code recorded in the event, e.g. a case category
+    #[arg(long, value_parser = clap::value_parser!(u16).range(1..))]
+    pub reason: u16,
+
+    /// Recovery token account; defaults to the vault
+    #[arg(long)]
+    pub destination: Option<Pubkey>,
+}
+
+impl HolderArgs {
+    /// `token_program` owns the mint and so the owner's associated account
+    pub fn token_account(&self, token_program: &Pubkey) -> Pubkey {
+        match (self.token_account, self.owner) {
+            (Some(account), _) => account,
+            (None, Some(owner)) => get_associated_token_address_with_program_id(&owner, &self.mint, token_program),
+            (None, None) => unreachable!("clap requires --owner or --token-account"),
+        }
+    }
+}
+
+/// ElGamal public keys print as base64, e.g. in `spl-token display`
+fn parse_elgamal_pubkey(value: &str) -> Result<[u8; 32], String> {
+    let bytes = BASE64_STANDARD.decode(value).map_err(|err| err.to_string())?;
+    bytes.try_into().map_err(|bytes: Vec<u8>| format!("expected 32 bytes, got {}", bytes.len()))
+}
+
+/// An owner's Token-2022 associated token account
+pub fn holder_token_account(owner: &Pubkey, mint: &Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(owner, mint, &spl_token_2022::ID)
+}
+
+impl Program {
+    /// Resolve `--backend`, rejecting combinations the program can't serve
+    pub fn backend(self, requested: Option<Backend>) -> anyhow::Result<Backend> {
+        match (self, requested) {
+            (Program::Hook, backend) => Ok(backend.unwrap_or(Backend::Vec)),
+            (Program::Pda, None | Some(Backend::Pda)) => Ok(Backend::Pda),
+            (Program::Pda, Some(Backend::Vec)) => anyhow::bail!("the PDA vault only has a PDA whitelist"),
+        }
+    }
+}
@@ -0,0 +1,322 @@
+use anchor_lang::{solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE, Discriminator};
+use anyhow::bail;
+use serde_json::{json, Value};
+use transfer_hook_vault::{
+    client,
+    instructions::{MetadataField, TokenMetadataArgs},
+    state::{Tier, Whitelist, WhitelistEntryPda},
+};
+use crate::cli::{holder_token_account, Backend, Command, MetadataCommand, Program, WhitelistCommand};
+use crate::reconcile::{Change, WhitelistRecord};
+use crate::rpc::{Rpc, TIER_VAULT_CONFIG_OFFSET};
+use super::*;
+
+pub fn run(rpc: &Rpc, command: &Command) -> anyhow::Result<Value> {
+    let payer = rpc.payer();
+    match command {
+        Command::CreateMint(args) => {
+            if args.has_extensions() {
+                bail!("extension flags only apply to --program pda; the hook mint always uses TransferHook");
+            }
+            let mint = mint_keypair(args.mint_keypair.as_deref())?;
+            let metadata = args.metadata().map(|m| TokenMetadataArgs { name: m.name, symbol: m.symbol, uri: m.uri });
+            let ix = client::create_mint(&payer, &mint.pubkey(), args.decimals, args.enable_default_frozen, metadata);
+            let signature = rpc.send(&[ix], &[&mint])?;
+            Ok(sent(signature, json!({
+                "mint": mint.pubkey().to_string(),
+                "decimals": args.decimals,
+                "default_frozen": args.enable_default_frozen,
+                "name": args.name,
+            })))
+        }
+        Command::Init { mint, capacity } => {
+            let signature = rpc.send(&[client::initialize(&payer, mint, *capacity)], &[])?;
+            Ok(sent(signature, json!({
+                "vault_config": client::derive_vault_config(mint).0.to_string(),
+                "whitelist": client::derive_whitelist(mint).0.to_string(),
+                "capacity": capacity,
+            })))
+        }
+        Command::InitExtraMetas { mint } => {
+            let signature = rpc.send(&[client::initialize_extra_metas(&payer, &payer, mint)], &[])?;
+            Ok(sent(signature, json!({ "extra_metas": client::derive_extra_metas(mint).0.to_string() })))
+        }
+        Command::Clawback(_) | Command::SetClawbackAuthority { .. } => {
+            bail!("clawback needs the permanent delegate, which only the PDA vault configures")
+        }
+        Command::SetInterestRate { .. } => bail!("only the PDA vault's mint can be interest-bearing"),
+        Command::CreateCredentialMint { .. } => bail!("credential mints are only issued by the PDA vault"),
+        Command::CreateRegistry { .. } | Command::ListGroup { .. } => {
+            bail!("token groups are only configured on the PDA vault's mints")
+        }
+        Command::MultiVault(_) => bail!("multi-mint vaults only exist on the PDA vault"),
+        Command::Timelock(_) => bail!("timelocked withdrawals only exist on the PDA vault"),
+        Command::Vesting(_) => bail!("vesting schedules only exist on the PDA vault"),
+        Command::Approval(_) => bail!("withdrawal approvals only exist on the PDA vault"),
+        Command::ApproveConfidential { .. } | Command::ConfidentialDeposit { .. } => {
+            bail!("confidential transfers are only configured on the PDA vault's mint")
+        }
+        Command::Whitelist(command) => whitelist(rpc, command),
+        Command::Deposit(args) => {
+            let (account, setup) = token_account(rpc, &args.mint, args.token_account, &spl_token_2022::ID);
+            let ix = client::deposit(&payer, &args.mint, &account, args.amount, args.tier, None);
+            let signature = rpc.send(&with_setup(setup, ix), &[])?;
+            Ok(sent(signature, json!({ "from": account.to_string(), "amount": args.amount })))
+        }
+        Command::Withdraw(args) => {
+            let (account, setup) = token_account(rpc, &args.mint, args.token_account, &spl_token_2022::ID);
+            let ix = client::withdraw(&payer, &args.mint, &account, args.amount, args.tier, None);
+            let signature = rpc.send(&with_setup(setup, ix), &[])?;
+            Ok(sent(signature, json!({ "to": account.to_string(), "amount": args.amount })))
+        }
+        Command::Mint { mint, amount, destination } => {
+            let (account, setup) = token_account(rpc, mint, *destination, &spl_token_2022::ID);
+            let signature = rpc.send(&with_setup(setup, client::mint_tokens(&payer, mint, &account, *amount)), &[])?;
+            Ok(sent(signature, json!({ "to": account.to_string(), "amount": amount })))
+        }
+        Command::Freeze(args) => {
+            let account = args.token_account(&spl_token_2022::ID);
+            let signature = rpc.send(&[client::freeze_account(&payer, &args.mint, &account)], &[])?;
+            Ok(sent(signature, json!({ "frozen": account.to_string() })))
+        }
+        Command::Thaw(args) => {
+            let account = args.token_account(&spl_token_2022::ID);
+            let signature = rpc.send(&[client::thaw_account(&payer, &args.mint, &account)], &[])?;
+            Ok(sent(signature, json!({ "thawed": account.to_string() })))
+        }
+        Command::Metadata(command) => metadata(rpc, command),
+        Command::CloseVault { mint, batch_size } => {
+            let vault_config = client::derive_vault_config(mint).0;
+            let (records, _) = current_records(rpc, mint, Backend::Pda)?;
+            let users: Vec<_> = records.iter().map(|r| r.wallet).collect();
+            let tiers: Vec<_> = rpc
+                .vault_accounts(&transfer_hook_vault::ID, &Tier::DISCRIMINATOR, TIER_VAULT_CONFIG_OFFSET, &vault_config)?
+                .into_iter()
+                .map(|(address, _)| address)
+                .collect();
+            let mut signature = None;
+            for batch in &close_instructions(&payer, mint, &users, &tiers, *batch_size) {
+                signature = Some(rpc.send(batch, &[])?);
+            }
+            Ok(json!({
+                "signature": signature.map(|s| s.to_string()),
+                "entries_removed": users.len(),
+                "tiers_closed": tiers.len(),
+            }))
+        }
+        Command::ShowConfig { mint } => {
+            let (address, _) = client::derive_vault_config(mint);
+            let config = require_account(client::fetch_vault_config(rpc, mint)?, "vault config", &address)?;
+            let whitelist = client::fetch_whitelist(rpc, mint)?;
+            let extra_metas = rpc.account_data(&client::derive_extra_metas(mint).0).is_some();
+            Ok(json!({
+                "vault_config": address.to_string(),
+                "authority": config.authority.to_string(),
+                "mint": config.mint.to_string(),
+                "vault": client::derive_vault(mint).0.to_string(),
+                "whitelist_len": whitelist.as_ref().map(|(h, _)| h.len),
+                "whitelist_capacity": whitelist.as_ref().map(|(h, _)| h.capacity),
+                "merkle_root": if config.merkle_root == [0; 32] { "disabled".to_string() } else { hex(&config.merkle_root) },
+                "extra_metas_initialized": extra_metas,
+            }))
+        }
+    }
+}
+
+fn metadata(rpc: &Rpc, command: &MetadataCommand) -> anyhow::Result<Value> {
+    let payer = rpc.payer();
+    match command {
+        MetadataCommand::Set { mint, field, value } => {
+            let ix = client::update_token_metadata_field(&payer, mint, metadata_field(field), value.clone());
+            let signature = rpc.send(&[ix], &[])?;
+            Ok(sent(signature, json!({ "field": field, "value": value })))
+        }
+        MetadataCommand::Remove { mint, key, idempotent } => {
+            let signature = rpc.send(&[client::remove_metadata_key(&payer, mint, key.clone(), *idempotent)], &[])?;
+            Ok(sent(signature, json!({ "removed": key })))
+        }
+    }
+}
+
+/// The base fields by name; anything else is an additional key
+fn metadata_field(name: &str) -> MetadataField {
+    match name {
+        "name" => MetadataField::Name,
+        "symbol" => MetadataField::Symbol,
+        "uri" => MetadataField::Uri,
+        key => MetadataField::Key(key.to_string()),
+    }
+}
+
+fn whitelist(rpc: &Rpc, command: &WhitelistCommand) -> anyhow::Result<Value> {
+    let payer = rpc.payer();
+    match command {
+        WhitelistCommand::Add { mint, user, max_amount, tier, backend, thaw } => {
+            let thawed = thaw.then(|| holder_token_account(user, mint));
+            let ix = match Program::Hook.backend(*backend)? {
+                Backend::Vec => client::add_to_whitelist(&payer, mint, user, *max_amount, *tier, thawed.as_ref()),
+                Backend::Pda => client::pda_whitelist_add(&payer, mint, user, *max_amount, *tier, thawed.as_ref()),
+            };
+            let signature = rpc.send(&[ix], &[])?;
+            Ok(sent(signature, json!({
+                "user": user.to_string(),
+                "max_amount": max_amount,
+                "tier": tier,
+                "thawed": thawed.map(|a| a.to_string()),
+            })))
+        }
+        WhitelistCommand::Remove { mint, user, backend, freeze } => {
+            let frozen = freeze.then(|| holder_token_account(user, mint));
+            let ix = match Program::Hook.backend(*backend)? {
+                Backend::Vec => client::remove_from_whitelist(&payer, mint, user, frozen.as_ref()),
+                Backend::Pda => client::pda_whitelist_remove(&payer, mint, user, frozen.as_ref()),
+            };
+            let signature = rpc.send(&[ix], &[])?;
+            Ok(sent(signature, json!({ "user": user.to_string(), "frozen": frozen.map(|a| a.to_string()) })))
+        }
+        WhitelistCommand::List { mint } => {
+            let mut rows = Vec::new();
+            if let Some((_, entries)) = client::fetch_whitelist(rpc, mint)? {
+                rows.extend(entries.iter().map(|e| entry_row("vec", &e.user, e.max_amount, e.tier_id, e.usage.lifetime_used)));
+            }
+            let vault_config = client::derive_vault_config(mint).0;
+            for (_, data) in rpc.entry_pdas(&transfer_hook_vault::ID, &WhitelistEntryPda::DISCRIMINATOR, &vault_config)? {
+                let e = client::decode_whitelist_entry_pda(&data)?;
+                rows.push(entry_row("pda", &e.user, e.max_amount, e.tier_id, e.usage.lifetime_used));
+            }
+            Ok(Value::Array(rows))
+        }
+        WhitelistCommand::Check { mint, user } => {
+            let vec_entry = client::fetch_whitelist(rpc, mint)?
+                .and_then(|(_, entries)| Whitelist::get_entry(&entries, user).copied());
+            if let Some(e) = vec_entry {
+                return Ok(entry_row("vec", user, e.max_amount, e.tier_id, e.usage.lifetime_used));
+            }
+            match client::fetch_whitelist_entry_pda(rpc, mint, user)? {
+                Some(e) => Ok(entry_row("pda", user, e.max_amount, e.tier_id, e.usage.lifetime_used)),
+                None => Ok(json!({ "user": user.to_string(), "whitelisted": false })),
+            }
+        }
+        WhitelistCommand::Import { mint, file, format, backend, dry_run, batch_size } => {
+            let backend = Program::Hook.backend(*backend)?;
+            let (current, capacity) = current_records(rpc, mint, backend)?;
+            let options = ImportOptions { file, format: *format, dry_run: *dry_run, batch_size: *batch_size };
+            import(rpc, &current, capacity, options, |change| Ok(change_instructions(&payer, mint, backend, change)))
+        }
+        WhitelistCommand::Resize { mint, capacity } => {
+            let address = client::derive_whitelist(mint).0;
+            let (header, _) = require_account(client::fetch_whitelist(rpc, mint)?, "whitelist", &address)?;
+            let signatures = resize_steps(header.capacity, *capacity)
+                .into_iter()
+                .map(|step| Ok(rpc.send(&[client::resize_whitelist(&payer, mint, step)], &[])?.to_string()))
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            Ok(json!({
+                "whitelist": address.to_string(),
+                "old_capacity": header.capacity,
+                "new_capacity": capacity,
+                "signatures": signatures,
+            }))
+        }
+        WhitelistCommand::Sort { mint } => {
+            let address = client::derive_whitelist(mint).0;
+            let (_, entries) = require_account(client::fetch_whitelist(rpc, mint)?, "whitelist", &address)?;
+            if Whitelist::is_sorted(&entries) {
+                return Ok(json!({ "whitelist": address.to_string(), "sorted": true, "signature": null }));
+            }
+            let signature = rpc.send(&[client::sort_whitelist(&payer, mint)], &[])?;
+            Ok(sent(signature, json!({ "whitelist": address.to_string(), "sorted": true })))
+        }
+        WhitelistCommand::Export { mint, file, format, backend } => {
+            let (records, _) = current_records(rpc, mint, Program::Hook.backend(*backend)?)?;
+            export(&records, file.as_deref(), *format)
+        }
+    }
+}
+
+/// Capacities to pass to successive `resize_whitelist` calls going from
+/// `from` to `to`; each grows the account by at most
+/// MAX_PERMITTED_DATA_INCREASE, and shrinking is a single call
+pub fn resize_steps(from: u32, to: u32) -> Vec<u32> {
+    if to < from {
+        return vec![to];
+    }
+    let step = (MAX_PERMITTED_DATA_INCREASE / Whitelist::ENTRY_LEN) as u32;
+    std::iter::successors(Some(from), |&capacity| (capacity < to).then(|| capacity.saturating_add(step).min(to)))
+        .skip(1)
+        .collect()
+}
+
+/// Entries in one backend, plus the Vec whitelist's capacity
+fn current_records(rpc: &Rpc, mint: &Pubkey, backend: Backend) -> anyhow::Result<(Vec<WhitelistRecord>, Option<usize>)> {
+    match backend {
+        Backend::Vec => {
+            let address = client::derive_whitelist(mint).0;
+            let (header, entries) = require_account(client::fetch_whitelist(rpc, mint)?, "whitelist", &address)?;
+            let records = entries
+                .iter()
+                .map(|e| WhitelistRecord { wallet: e.user, limit: e.max_amount, tier: Some(e.tier_id) })
+                .collect();
+            Ok((records, Some(header.capacity as usize)))
+        }
+        Backend::Pda => {
+            let vault_config = client::derive_vault_config(mint).0;
+            let records = rpc
+                .entry_pdas(&transfer_hook_vault::ID, &WhitelistEntryPda::DISCRIMINATOR, &vault_config)?
+                .into_iter()
+                .map(|(_, data)| {
+                    let e = client::decode_whitelist_entry_pda(&data)?;
+                    Ok(WhitelistRecord { wallet: e.user, limit: e.max_amount, tier: Some(e.tier_id) })
+                })
+                .collect::<anyhow::Result<_>>()?;
+            Ok((records, None))
+        }
+    }
+}
+
+/// Updates rewrite the limit and tier in place, keeping the entry's usage counters
+pub fn change_instructions(authority: &Pubkey, mint: &Pubkey, backend: Backend, change: &Change) -> Vec<Instruction> {
+    let add = |r: &WhitelistRecord| match backend {
+        Backend::Vec => client::add_to_whitelist(authority, mint, &r.wallet, r.limit, r.tier.unwrap_or(0), None),
+        Backend::Pda => client::pda_whitelist_add(authority, mint, &r.wallet, r.limit, r.tier.unwrap_or(0), None),
+    };
+    let remove = |r: &WhitelistRecord| match backend {
+        Backend::Vec => client::remove_from_whitelist(authority, mint, &r.wallet, None),
+        Backend::Pda => client::pda_whitelist_remove(authority, mint, &r.wallet, None),
+    };
+    let update = |r: &WhitelistRecord| match backend {
+        Backend::Vec => client::update_whitelist_entry(authority, mint, &r.wallet, r.limit, r.tier.unwrap_or(0)),
+        Backend::Pda => client::pda_whitelist_update(authority, mint, &r.wallet, r.limit, r.tier.unwrap_or(0)),
+    };
+    match change {
+        Change::Add(r) => vec![add(r)],
+        Change::Remove(r) => vec![remove(r)],
+        Change::Update { to, .. } => vec![update(to)],
+    }
+}
+
+/// Removal batches for the PDA-backend `users`, followed by `close_vault`
+/// closing `tiers`; the Vec whitelist is closed as a whole
+pub fn close_instructions(
+    authority: &Pubkey,
+    mint: &Pubkey,
+    users: &[Pubkey],
+    tiers: &[Pubkey],
+    batch_size: usize,
+) -> Vec<Vec<Instruction>> {
+    users
+        .chunks(batch_size.max(1))
+        .map(|batch| batch.iter().map(|user| client::pda_whitelist_remove(authority, mint, user, None)).collect())
+        .chain([vec![client::close_vault(authority, mint, tiers)]])
+        .collect()
+}
+
+fn entry_row(backend: &str, user: &Pubkey, max_amount: u64, tier: u8, lifetime_used: u64) -> Value {
+    json!({
+        "user": user.to_string(),
+        "whitelisted": true,
+        "backend": backend,
+        "max_amount": max_amount,
+        "tier": tier,
+        "lifetime_used": lifetime_used,
+    })
+}
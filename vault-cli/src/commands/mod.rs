@@ -0,0 +1,154 @@
+use std::fs::File;
+use std::path::Path;
+use anyhow::{bail, Context};
+use serde_json::{json, Value};
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signature, Signer},
+};
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id, instruction::create_associated_token_account_idempotent,
+};
+use crate::cli::{Cli, Program};
+use crate::reconcile::{diff, len_after, read_records, write_records, Change, Format, WhitelistRecord};
+use crate::rpc::{read_keypair, Rpc};
+
+pub mod hook;
+pub mod pda;
+
+pub fn run(cli: &Cli) -> anyhow::Result<Value> {
+    let rpc = Rpc::new(&cli.url, &cli.keypair)?;
+    match cli.program {
+        Program::Hook => hook::run(&rpc, &cli.command),
+        Program::Pda => pda::run(&rpc, &cli.command),
+    }
+}
+
+/// Keypair for a new mint, read from `path` or freshly generated
+pub fn mint_keypair(path: Option<&str>) -> anyhow::Result<Keypair> {
+    match path {
+        Some(path) => read_keypair(path),
+        None => Ok(Keypair::new()),
+    }
+}
+
+/// `explicit` if given, else the signer's associated account under
+/// `token_program`. When defaulting, also returns an idempotent create so the
+/// account exists.
+pub fn token_account(
+    rpc: &Rpc,
+    mint: &Pubkey,
+    explicit: Option<Pubkey>,
+    token_program: &Pubkey,
+) -> (Pubkey, Option<Instruction>) {
+    match explicit {
+        Some(account) => (account, None),
+        None => {
+            let owner = rpc.payer();
+            let ata = get_associated_token_address_with_program_id(&owner, mint, token_program);
+            let create = create_associated_token_account_idempotent(&owner, &owner, mint, token_program);
+            (ata, Some(create))
+        }
+    }
+}
+
+/// Token-2022 or legacy Token, whichever owns `mint`; Token-2022 if the mint
+/// can't be fetched, so the program reports the real error
+pub fn token_program(rpc: &Rpc, mint: &Pubkey) -> Pubkey {
+    rpc.account_owner(mint).unwrap_or(spl_token_2022::ID)
+}
+
+pub fn with_setup(setup: Option<Instruction>, ix: Instruction) -> Vec<Instruction> {
+    setup.into_iter().chain([ix]).collect()
+}
+
+pub fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Merge `fields` into a `{ "signature": .. }` object
+pub fn sent(signature: Signature, fields: Value) -> Value {
+    let mut out = json!({ "signature": signature.to_string() });
+    if let (Value::Object(out), Value::Object(fields)) = (&mut out, fields) {
+        out.extend(fields);
+    }
+    out
+}
+
+pub fn require_account<T>(value: Option<T>, what: &str, address: &Pubkey) -> anyhow::Result<T> {
+    value.with_context(|| format!("{what} {address} not found"))
+}
+
+/// Diff `current` against the snapshot at `file` and apply the result,
+/// batching `batch_size` changes per transaction. `capacity` is the Vec
+/// whitelist's slot count; PDA backends have none.
+pub fn import(
+    rpc: &Rpc,
+    current: &[WhitelistRecord],
+    capacity: Option<usize>,
+    options: ImportOptions,
+    build: impl Fn(&Change) -> anyhow::Result<Vec<Instruction>>,
+) -> anyhow::Result<Value> {
+    let format = Format::resolve(options.format, Some(options.file))?;
+    let file = File::open(options.file).with_context(|| format!("opening {}", options.file.display()))?;
+    let desired = read_records(file, format)?;
+    let changes = diff(current, &desired);
+    
+    if let Some(capacity) = capacity {
+        let needed = len_after(current.len(), &changes);
+        if needed > capacity {
+            bail!("import needs {needed} whitelist slots but only {capacity} exist; resize the whitelist first");
+        }
+    }
+    
+    // Build everything up front so a bad row fails before anything is sent
+    let instructions = changes.iter().map(build).collect::<anyhow::Result<Vec<_>>>()?;
+    let batch_size = options.batch_size.max(1);
+    
+    let mut rows = Vec::with_capacity(changes.len());
+    for (batch, instructions) in changes.chunks(batch_size).zip(instructions.chunks(batch_size)) {
+        let signature = if options.dry_run {
+            None
+        } else {
+            Some(rpc.send(&instructions.concat(), &[])?.to_string())
+        };
+        rows.extend(batch.iter().map(|change| {
+            let record = change.record();
+            json!({
+                "action": change.action(),
+                "wallet": record.wallet.to_string(),
+                "limit": record.limit,
+                "tier": record.tier,
+                "signature": signature,
+            })
+        }));
+    }
+    Ok(Value::Array(rows))
+}
+
+pub struct ImportOptions<'a> {
+    pub file: &'a Path,
+    pub format: Option<Format>,
+    pub dry_run: bool,
+    pub batch_size: usize,
+}
+
+/// Write `records` to `file`, or to stdout when there is none
+pub fn export(records: &[WhitelistRecord], file: Option<&Path>, format: Option<Format>) -> anyhow::Result<Value> {
+    let format = match (format, file) {
+        (None, None) => Format::Csv,
+        (format, file) => Format::resolve(format, file)?,
+    };
+    match file {
+        Some(path) => {
+            let out = File::create(path).with_context(|| format!("creating {}", path.display()))?;
+            write_records(out, records, format)?;
+            Ok(json!({ "file": path.display().to_string(), "entries": records.len() }))
+        }
+        None => {
+            write_records(std::io::stdout().lock(), records, format)?;
+            Ok(Value::Null)
+        }
+    }
+}
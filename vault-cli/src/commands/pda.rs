@@ -0,0 +1,628 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+use anchor_lang::Discriminator;
+use anyhow::bail;
+use serde_json::{json, Value};
+use pda_token_vault::{
+    client,
+    instructions::MetadataField,
+    state::{Proposal, VaultAsset, VestingSchedule, WhitelistEntry, WithdrawalTicket},
+};
+use crate::cli::{
+    holder_token_account, ApprovalCommand, Command, MetadataCommand, MultiVaultArgs, MultiVaultCommand, Program,
+    TimelockCommand, VestingCommand, WhitelistCommand,
+};
+use crate::reconcile::{Change, WhitelistRecord};
+use crate::rpc::Rpc;
+use super::*;
+
+pub fn run(rpc: &Rpc, command: &Command) -> anyhow::Result<Value> {
+    let payer = rpc.payer();
+    match command {
+        Command::CreateMint(args) => {
+            let mint = mint_keypair(args.mint_keypair.as_deref())?;
+            let ix = match &args.group_mint {
+                Some(group_mint) => {
+                    client::create_member_mint(&payer, &mint.pubkey(), group_mint, args.decimals, args.extension_args())
+                }
+                None => client::create_mint(&payer, &mint.pubkey(), args.decimals, args.extension_args()),
+            };
+            let signature = rpc.send(&[ix], &[&mint])?;
+            Ok(sent(signature, json!({
+                "mint": mint.pubkey().to_string(),
+                "decimals": args.decimals,
+                "transfer_fee_basis_points": args.enable_transfer_fee.then_some(args.transfer_fee_basis_points),
+                "permanent_delegate": args.enable_permanent_delegate,
+                "default_frozen": args.enable_default_frozen,
+                "interest_rate": args.interest_rate,
+                "mint_close_authority": args.enable_mint_close_authority,
+                "confidential_transfer": args.confidential,
+                "group_max_size": args.group_max_size,
+                "group": args.group_mint.map(|group_mint| group_mint.to_string()),
+                "name": args.name,
+            })))
+        }
+        Command::Init { mint, .. } => {
+            let signature = rpc.send(&[client::initialize(&payer, mint, &token_program(rpc, mint))], &[])?;
+            Ok(sent(signature, json!({ "vault_config": client::derive_vault_config(mint).0.to_string() })))
+        }
+        Command::InitExtraMetas { .. } => bail!("the PDA vault has no transfer hook"),
+        Command::Whitelist(command) => whitelist(rpc, command),
+        Command::Deposit(args) | Command::Withdraw(args) if args.tier != 0 => {
+            bail!("tiers only exist on the hook vault")
+        }
+        Command::Deposit(args) => {
+            let token_program = token_program(rpc, &args.mint);
+            let (account, setup) = token_account(rpc, &args.mint, args.token_account, &token_program);
+            let ix = client::deposit(&payer, &args.mint, &account, args.amount, &token_program);
+            let signature = rpc.send(&with_setup(setup, ix), &[])?;
+            Ok(sent(signature, json!({
+                "from": account.to_string(),
+                "amount": args.amount,
+                "ui_amount": ui_amount(rpc, &args.mint, args.amount),
+            })))
+        }
+        Command::Withdraw(args) => {
+            let token_program = token_program(rpc, &args.mint);
+            let (account, setup) = token_account(rpc, &args.mint, args.token_account, &token_program);
+            let ix = client::withdraw(&payer, &args.mint, &account, args.amount, &token_program);
+            let signature = rpc.send(&with_setup(setup, ix), &[])?;
+            Ok(sent(signature, json!({
+                "to": account.to_string(),
+                "amount": args.amount,
+                "ui_amount": ui_amount(rpc, &args.mint, args.amount),
+            })))
+        }
+        Command::Mint { mint, amount, destination } => {
+            // create_mint makes the authority the mint authority, so mint directly
+            let token_program = token_program(rpc, mint);
+            let (account, setup) = token_account(rpc, mint, *destination, &token_program);
+            let ix = spl_token_2022::instruction::mint_to(&token_program, mint, &account, &payer, &[], *amount)?;
+            let signature = rpc.send(&with_setup(setup, ix), &[])?;
+            Ok(sent(signature, json!({ "to": account.to_string(), "amount": amount })))
+        }
+        Command::Freeze(args) => {
+            let token_program = token_program(rpc, &args.mint);
+            let account = args.token_account(&token_program);
+            let signature = rpc.send(&[client::freeze_account(&payer, &args.mint, &account, &token_program)], &[])?;
+            Ok(sent(signature, json!({ "frozen": account.to_string() })))
+        }
+        Command::Thaw(args) => {
+            let token_program = token_program(rpc, &args.mint);
+            let account = args.token_account(&token_program);
+            let signature = rpc.send(&[client::thaw_account(&payer, &args.mint, &account, &token_program)], &[])?;
+            Ok(sent(signature, json!({ "thawed": account.to_string() })))
+        }
+        Command::Clawback(args) => {
+            // Clawback needs a Token-2022 permanent delegate
+            let source = args.holder.token_account(&spl_token_2022::ID);
+            let mint = &args.holder.mint;
+            let destination = args.destination.unwrap_or_else(|| client::derive_vault(mint).0);
+            let ix = client::clawback(&payer, mint, &source, Some(&destination), args.amount, args.reason);
+            let signature = rpc.send(&[ix], &[])?;
+            Ok(sent(signature, json!({
+                "from": source.to_string(),
+                "to": destination.to_string(),
+                "amount": args.amount,
+                "reason": args.reason,
+            })))
+        }
+        Command::SetClawbackAuthority { mint, new_authority } => {
+            let signature = rpc.send(&[client::set_clawback_authority(&payer, mint, new_authority)], &[])?;
+            Ok(sent(signature, json!({ "clawback_authority": new_authority.to_string() })))
+        }
+        Command::CreateCredentialMint { mint } => {
+            let signature = rpc.send(&[client::create_credential_mint(&payer, mint)], &[])?;
+            let credential_mint = client::derive_credential_mint(&client::derive_vault_config(mint).0).0;
+            Ok(sent(signature, json!({ "credential_mint": credential_mint.to_string() })))
+        }
+        Command::ApproveConfidential { mint, owner, token_account } => {
+            let account = token_account.unwrap_or_else(|| holder_token_account(owner, mint));
+            let signature = rpc.send(&[client::approve_confidential_account(&payer, mint, owner, &account)], &[])?;
+            Ok(sent(signature, json!({ "approved": account.to_string() })))
+        }
+        Command::ConfidentialDeposit { mint, amount } => {
+            let signature = rpc.send(&[client::confidential_vault_deposit(&payer, mint, *amount)], &[])?;
+            Ok(sent(signature, json!({ "amount": amount })))
+        }
+        Command::CreateRegistry { mint } => {
+            let signature = rpc.send(&[client::create_vault_registry(&payer, mint)], &[])?;
+            Ok(sent(signature, json!({ "registry": client::derive_vault_registry(mint).0.to_string() })))
+        }
+        Command::ListGroup { mint } => {
+            let (address, _) = client::derive_vault_registry(mint);
+            let registry = require_account(client::fetch_vault_registry(rpc, mint)?, "vault registry", &address)?;
+            Ok(Value::Array(registry.members.iter().map(member_row).collect()))
+        }
+        Command::MultiVault(command) => multi_vault(rpc, command),
+        Command::Timelock(command) => timelock(rpc, command),
+        Command::Vesting(command) => vesting(rpc, command),
+        Command::Approval(command) => approval(rpc, command),
+        Command::SetInterestRate { mint, rate } => {
+            let signature = rpc.send(&[client::update_interest_rate(&payer, mint, *rate)], &[])?;
+            Ok(sent(signature, json!({ "interest_rate": rate })))
+        }
+        Command::Metadata(command) => metadata(rpc, command),
+        Command::CloseVault { mint, batch_size } => {
+            let users: Vec<_> = entries(rpc, mint)?.iter().map(|e| e.user).collect();
+            let (refunded, schedules) = open_claims(rpc, mint)?;
+            let claims = [refunded.as_slice(), &schedules].concat();
+            let credential_mint = credential_mint(rpc, mint)?;
+            let token_program = token_program(rpc, mint);
+            let batches =
+                close_instructions(&payer, mint, credential_mint.as_ref(), &users, &claims, *batch_size, &token_program);
+            let mut signature = None;
+            for batch in &batches {
+                signature = Some(rpc.send(batch, &[])?);
+            }
+            Ok(json!({
+                "signature": signature.map(|s| s.to_string()),
+                "entries_removed": users.len(),
+                "claims_closed": refunded.len() / 2 + schedules.len(),
+                "mint_closed": rpc.account_data(mint).is_none(),
+            }))
+        }
+        Command::ShowConfig { mint } => {
+            let (address, _) = client::derive_vault_config(mint);
+            let config = require_account(client::fetch_vault_config(rpc, mint)?, "vault config", &address)?;
+            Ok(json!({
+                "vault_config": address.to_string(),
+                "authority": config.authority.to_string(),
+                "clawback_authority": config.clawback_authority.to_string(),
+                "credential_mint": (config.credential_mint != Pubkey::default()).then(|| config.credential_mint.to_string()),
+                "guardian": config.guardian.to_string(),
+                "withdrawal_delay": config.withdrawal_delay,
+                "vesting_reserved": config.vesting_reserved,
+                "approvers": config.approvers.iter().map(|a| a.to_string()).collect::<Vec<_>>(),
+                "approval_quorum": config.approval_quorum,
+                "approval_threshold": config.approval_threshold,
+                "mint": config.mint.to_string(),
+                "vault": client::derive_vault(mint).0.to_string(),
+                "interest_rate": rpc.account_data(mint).and_then(|data| client::decode_interest_rate(&data).ok()).flatten(),
+            }))
+        }
+    }
+}
+
+/// `amount` as wallets display it right now, including accrued interest
+fn ui_amount(rpc: &Rpc, mint: &Pubkey, amount: u64) -> Option<String> {
+    client::decode_ui_amount(&rpc.account_data(mint)?, amount, now()?).ok()
+}
+
+fn now() -> Option<i64> {
+    Some(SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs() as i64)
+}
+
+fn metadata(rpc: &Rpc, command: &MetadataCommand) -> anyhow::Result<Value> {
+    let payer = rpc.payer();
+    match command {
+        MetadataCommand::Set { mint, field, value } => {
+            let ix = client::update_token_metadata_field(&payer, mint, metadata_field(field), value.clone());
+            let signature = rpc.send(&[ix], &[])?;
+            Ok(sent(signature, json!({ "field": field, "value": value })))
+        }
+        MetadataCommand::Remove { mint, key, idempotent } => {
+            let signature = rpc.send(&[client::remove_metadata_key(&payer, mint, key.clone(), *idempotent)], &[])?;
+            Ok(sent(signature, json!({ "removed": key })))
+        }
+    }
+}
+
+fn approval(rpc: &Rpc, command: &ApprovalCommand) -> anyhow::Result<Value> {
+    let payer = rpc.payer();
+    match command {
+        ApprovalCommand::Set { mint, approvers, quorum, threshold } => {
+            let signature = rpc.send(&[client::set_approvers(&payer, mint, approvers, *quorum, *threshold)], &[])?;
+            Ok(sent(signature, json!({
+                "approvers": approvers.iter().map(|a| a.to_string()).collect::<Vec<_>>(),
+                "quorum": quorum,
+                "threshold": threshold,
+            })))
+        }
+        ApprovalCommand::Propose { mint, amount, token_account: explicit } => {
+            let token_program = token_program(rpc, mint);
+            let (account, setup) = token_account(rpc, mint, *explicit, &token_program);
+            let ix = client::propose_withdrawal(&payer, mint, &account, *amount);
+            let signature = rpc.send(&with_setup(setup, ix), &[])?;
+            let proposal = client::derive_proposal(&client::derive_vault_config(mint).0, &payer).0;
+            Ok(sent(signature, json!({ "proposal": proposal.to_string(), "amount": amount })))
+        }
+        ApprovalCommand::Approve { mint, withdrawer } => {
+            let proposal = require_account(client::fetch_proposal(rpc, mint, withdrawer)?, "proposal for", withdrawer)?;
+            let ix = client::approve_withdrawal(&payer, mint, withdrawer, &proposal.destination, &token_program(rpc, mint));
+            let signature = rpc.send(&[ix], &[])?;
+            // The approval that reaches the quorum closes the proposal, unless
+            // it is still locked
+            let executed = client::fetch_proposal(rpc, mint, withdrawer)?.is_none();
+            Ok(sent(signature, json!({ "withdrawer": withdrawer.to_string(), "executed": executed })))
+        }
+        ApprovalCommand::Cancel { mint } => {
+            let signature = rpc.send(&[client::cancel_proposal(&payer, mint)], &[])?;
+            Ok(sent(signature, json!({ "cancelled": payer.to_string() })))
+        }
+        ApprovalCommand::Execute { mint } => {
+            let proposal = require_account(client::fetch_proposal(rpc, mint, &payer)?, "proposal for", &payer)?;
+            let ix = client::execute_proposal(&payer, mint, &proposal.destination, &token_program(rpc, mint));
+            let signature = rpc.send(&[ix], &[])?;
+            Ok(sent(signature, json!({ "withdrawer": payer.to_string(), "amount": proposal.amount })))
+        }
+        ApprovalCommand::Veto { mint, withdrawer } => {
+            let signature = rpc.send(&[client::veto_proposal(&payer, mint, withdrawer)], &[])?;
+            Ok(sent(signature, json!({ "vetoed": withdrawer.to_string() })))
+        }
+        ApprovalCommand::Show { mint, withdrawer } => {
+            let withdrawer = withdrawer.unwrap_or(payer);
+            Ok(match client::fetch_proposal(rpc, mint, &withdrawer)? {
+                Some(proposal) => json!({
+                    "withdrawer": withdrawer.to_string(),
+                    "pending": true,
+                    "amount": proposal.amount,
+                    "destination": proposal.destination.to_string(),
+                    "approvals": proposal.approvals.iter().map(|a| a.to_string()).collect::<Vec<_>>(),
+                    "unlock_at": proposal.unlock_at,
+                }),
+                None => json!({ "withdrawer": withdrawer.to_string(), "pending": false }),
+            })
+        }
+    }
+}
+
+fn vesting(rpc: &Rpc, command: &VestingCommand) -> anyhow::Result<Value> {
+    let payer = rpc.payer();
+    match command {
+        VestingCommand::Create { mint, beneficiary, schedule_id, total, start, cliff, duration } => {
+            let start = start.or_else(now).unwrap_or_default();
+            let (cliff, end) = (start + cliff, start + duration);
+            let ix = client::create_vesting(&payer, mint, beneficiary, *schedule_id, *total, start, cliff, end);
+            let signature = rpc.send(&[ix], &[])?;
+            Ok(sent(signature, json!({
+                "beneficiary": beneficiary.to_string(),
+                "schedule_id": schedule_id,
+                "total": total,
+                "start": start,
+                "cliff": cliff,
+                "end": end,
+            })))
+        }
+        VestingCommand::Withdraw { mint, schedule_id, token_account: explicit } => {
+            let token_program = token_program(rpc, mint);
+            let (account, setup) = token_account(rpc, mint, *explicit, &token_program);
+            let released =
+                || anyhow::Ok(client::fetch_vesting_schedule(rpc, mint, &payer, *schedule_id)?.map(|s| s.released));
+            let before = released()?;
+            let ix = client::withdraw_vested(&payer, mint, *schedule_id, &account, &token_program);
+            let signature = rpc.send(&with_setup(setup, ix), &[])?;
+            let after = released()?;
+            Ok(sent(signature, json!({
+                "to": account.to_string(),
+                "amount": after.zip(before).map(|(after, before)| after - before),
+                "released": after,
+            })))
+        }
+        VestingCommand::Show { mint, beneficiary, schedule_id } => {
+            let beneficiary = beneficiary.unwrap_or(payer);
+            let vault_config = client::derive_vault_config(mint).0;
+            let (address, _) = client::derive_vesting_schedule(&vault_config, &beneficiary, *schedule_id);
+            let schedule = client::fetch_vesting_schedule(rpc, mint, &beneficiary, *schedule_id)?;
+            let schedule = require_account(schedule, "vesting schedule", &address)?;
+            Ok(json!({
+                "beneficiary": beneficiary.to_string(),
+                "schedule_id": schedule_id,
+                "total": schedule.total,
+                "start": schedule.start,
+                "cliff": schedule.cliff,
+                "end": schedule.end,
+                "released": schedule.released,
+                "releasable": now().map(|now| schedule.releasable(now)),
+            }))
+        }
+        VestingCommand::Close { mint, beneficiary, schedule_id } => {
+            let signature = rpc.send(&[client::close_vesting(&payer, mint, beneficiary, *schedule_id)], &[])?;
+            Ok(sent(signature, json!({ "beneficiary": beneficiary.to_string(), "schedule_id": schedule_id })))
+        }
+    }
+}
+
+fn timelock(rpc: &Rpc, command: &TimelockCommand) -> anyhow::Result<Value> {
+    let payer = rpc.payer();
+    match command {
+        TimelockCommand::Set { mint, delay, guardian } => {
+            let guardian = guardian.unwrap_or(payer);
+            let signature = rpc.send(&[client::set_withdrawal_timelock(&payer, mint, *delay, &guardian)], &[])?;
+            Ok(sent(signature, json!({ "withdrawal_delay": delay, "guardian": guardian.to_string() })))
+        }
+        TimelockCommand::Request { mint, amount } => {
+            let signature = rpc.send(&[client::request_withdrawal(&payer, mint, *amount)], &[])?;
+            let ticket = client::fetch_withdrawal_ticket(rpc, mint, &payer)?;
+            Ok(sent(signature, json!({
+                "amount": amount,
+                "unlock_time": ticket.map(|t| t.unlock_time),
+            })))
+        }
+        TimelockCommand::Claim { mint, token_account: explicit } => {
+            let token_program = token_program(rpc, mint);
+            let (account, setup) = token_account(rpc, mint, *explicit, &token_program);
+            let amount = client::fetch_withdrawal_ticket(rpc, mint, &payer)?.map(|t| t.amount);
+            let ix = client::claim_withdrawal(&payer, mint, &account, &token_program);
+            let signature = rpc.send(&with_setup(setup, ix), &[])?;
+            Ok(sent(signature, json!({ "to": account.to_string(), "amount": amount })))
+        }
+        TimelockCommand::Cancel { mint } => {
+            let signature = rpc.send(&[client::cancel_withdrawal(&payer, mint)], &[])?;
+            Ok(sent(signature, json!({ "cancelled": payer.to_string() })))
+        }
+        TimelockCommand::Veto { mint, user } => {
+            let signature = rpc.send(&[client::veto_withdrawal(&payer, mint, user)], &[])?;
+            Ok(sent(signature, json!({ "vetoed": user.to_string() })))
+        }
+        TimelockCommand::Show { mint, user } => {
+            let user = user.unwrap_or(payer);
+            Ok(match client::fetch_withdrawal_ticket(rpc, mint, &user)? {
+                Some(ticket) => json!({
+                    "user": user.to_string(),
+                    "pending": true,
+                    "amount": ticket.amount,
+                    "unlock_time": ticket.unlock_time,
+                }),
+                None => json!({ "user": user.to_string(), "pending": false }),
+            })
+        }
+    }
+}
+
+fn multi_vault(rpc: &Rpc, command: &MultiVaultCommand) -> anyhow::Result<Value> {
+    let payer = rpc.payer();
+    let address = |vault: &MultiVaultArgs| client::derive_multi_vault(&vault.authority(&payer), vault.vault_id).0;
+    match command {
+        MultiVaultCommand::Create { vault } => {
+            let signature = rpc.send(&[client::create_multi_vault(&payer, vault.vault_id)], &[])?;
+            Ok(sent(signature, json!({ "multi_vault": address(vault).to_string() })))
+        }
+        MultiVaultCommand::AddMint { vault, mint, max_amount } => {
+            let multi_vault = address(vault);
+            let token_program = token_program(rpc, mint);
+            let signature = rpc.send(&[client::add_vault_mint(&payer, &multi_vault, mint, &token_program, *max_amount)], &[])?;
+            Ok(sent(signature, json!({
+                "mint": mint.to_string(),
+                "token_program": token_program.to_string(),
+                "vault": client::derive_multi_vault_account(&multi_vault, mint).0.to_string(),
+                "max_amount": max_amount,
+            })))
+        }
+        MultiVaultCommand::SetLimit { vault, mint, max_amount } => {
+            let signature = rpc.send(&[client::set_vault_mint_limit(&payer, &address(vault), mint, *max_amount)], &[])?;
+            Ok(sent(signature, json!({ "mint": mint.to_string(), "max_amount": max_amount })))
+        }
+        MultiVaultCommand::AddMember { vault, user } => {
+            let signature = rpc.send(&[client::add_multi_vault_member(&payer, &address(vault), user)], &[])?;
+            Ok(sent(signature, json!({ "user": user.to_string() })))
+        }
+        MultiVaultCommand::RemoveMember { vault, user } => {
+            let signature = rpc.send(&[client::remove_multi_vault_member(&payer, &address(vault), user)], &[])?;
+            Ok(sent(signature, json!({ "removed": user.to_string() })))
+        }
+        MultiVaultCommand::Deposit { vault, transfer } => {
+            let token_program = token_program(rpc, &transfer.mint);
+            let (account, setup) = token_account(rpc, &transfer.mint, transfer.token_account, &token_program);
+            let multi_vault = address(vault);
+            let ix = client::multi_vault_deposit(&payer, &multi_vault, &transfer.mint, &account, transfer.amount, &token_program);
+            let signature = rpc.send(&with_setup(setup, ix), &[])?;
+            Ok(sent(signature, json!({ "from": account.to_string(), "amount": transfer.amount })))
+        }
+        MultiVaultCommand::Withdraw { vault, transfer } => {
+            let token_program = token_program(rpc, &transfer.mint);
+            let (account, setup) = token_account(rpc, &transfer.mint, transfer.token_account, &token_program);
+            let multi_vault = address(vault);
+            let ix = client::multi_vault_withdraw(&payer, &multi_vault, &transfer.mint, &account, transfer.amount, &token_program);
+            let signature = rpc.send(&with_setup(setup, ix), &[])?;
+            Ok(sent(signature, json!({ "to": account.to_string(), "amount": transfer.amount })))
+        }
+        MultiVaultCommand::Show { vault } => {
+            let multi_vault = address(vault);
+            require_account(client::fetch_multi_vault(rpc, &multi_vault)?, "multi vault", &multi_vault)?;
+            // VaultAsset leads with its multi_vault
+            let assets = rpc.vault_accounts(&pda_token_vault::ID, &VaultAsset::DISCRIMINATOR, 8, &multi_vault)?;
+            assets
+                .into_iter()
+                .map(|(_, data)| Ok(asset_row(&client::decode_vault_asset(&data)?)))
+                .collect::<anyhow::Result<Vec<_>>>()
+                .map(Value::Array)
+        }
+    }
+}
+
+/// The base fields by name; anything else is an additional key
+fn metadata_field(name: &str) -> MetadataField {
+    match name {
+        "name" => MetadataField::Name,
+        "symbol" => MetadataField::Symbol,
+        "uri" => MetadataField::Uri,
+        key => MetadataField::Key(key.to_string()),
+    }
+}
+
+fn whitelist(rpc: &Rpc, command: &WhitelistCommand) -> anyhow::Result<Value> {
+    let payer = rpc.payer();
+    match command {
+        WhitelistCommand::Add { mint, user, max_amount, tier, backend, thaw } => {
+            Program::Pda.backend(*backend)?;
+            if *tier != 0 {
+                bail!("tiers only exist on the hook vault");
+            }
+            let token_program = token_program(rpc, mint);
+            let thawed = thaw.then(|| get_associated_token_address_with_program_id(user, mint, &token_program));
+            let credential = credential_mint(rpc, mint)?.map(|c| credential_account(&payer, user, &c));
+            let credential_account = credential.as_ref().map(|c| &c.0);
+            let add =
+                client::add_to_whitelist(&payer, mint, user, *max_amount, thawed.as_ref(), credential_account, &token_program);
+            let signature = rpc.send(&with_setup(credential.as_ref().map(|c| c.1.clone()), add), &[])?;
+            Ok(sent(signature, json!({
+                "user": user.to_string(),
+                "max_amount": max_amount,
+                "thawed": thawed.map(|a| a.to_string()),
+                "credential": credential.map(|c| c.0.to_string()),
+            })))
+        }
+        WhitelistCommand::Remove { mint, user, backend, freeze } => {
+            Program::Pda.backend(*backend)?;
+            let token_program = token_program(rpc, mint);
+            let frozen = freeze.then(|| get_associated_token_address_with_program_id(user, mint, &token_program));
+            let credential = credential_mint(rpc, mint)?.map(|c| holder_token_account(user, &c));
+            let remove = client::remove_from_whitelist(&payer, mint, user, frozen.as_ref(), credential.as_ref(), &token_program);
+            let signature = rpc.send(&[remove], &[])?;
+            Ok(sent(signature, json!({
+                "user": user.to_string(),
+                "frozen": frozen.map(|a| a.to_string()),
+                "credential_burned": credential.map(|a| a.to_string()),
+            })))
+        }
+        WhitelistCommand::List { mint } => Ok(Value::Array(entries(rpc, mint)?.iter().map(entry_row).collect())),
+        WhitelistCommand::Check { mint, user } => match client::fetch_whitelist_entry(rpc, mint, user)? {
+            Some(entry) => Ok(entry_row(&entry)),
+            None => Ok(json!({ "user": user.to_string(), "whitelisted": false })),
+        },
+        WhitelistCommand::Import { mint, file, format, backend, dry_run, batch_size } => {
+            Program::Pda.backend(*backend)?;
+            let current = records(rpc, mint)?;
+            let credential_mint = credential_mint(rpc, mint)?;
+            let options = ImportOptions { file, format: *format, dry_run: *dry_run, batch_size: *batch_size };
+            import(rpc, &current, None, options, |change| {
+                change_instructions(&payer, mint, credential_mint.as_ref(), change)
+            })
+        }
+        WhitelistCommand::Resize { .. } => bail!("only the hook vault's Vec whitelist has a capacity"),
+        WhitelistCommand::Sort { .. } => bail!("only the hook vault's Vec whitelist needs sorting"),
+        WhitelistCommand::Export { mint, file, format, backend } => {
+            Program::Pda.backend(*backend)?;
+            export(&records(rpc, mint)?, file.as_deref(), *format)
+        }
+    }
+}
+
+fn entries(rpc: &Rpc, mint: &Pubkey) -> anyhow::Result<Vec<WhitelistEntry>> {
+    let vault_config = client::derive_vault_config(mint).0;
+    rpc.entry_pdas(&pda_token_vault::ID, &WhitelistEntry::DISCRIMINATOR, &vault_config)?
+        .into_iter()
+        .map(|(_, data)| Ok(client::decode_whitelist_entry(&data)?))
+        .collect()
+}
+
+fn records(rpc: &Rpc, mint: &Pubkey) -> anyhow::Result<Vec<WhitelistRecord>> {
+    Ok(entries(rpc, mint)?
+        .iter()
+        .map(|e| WhitelistRecord { wallet: e.user, limit: e.max_amount, tier: None })
+        .collect())
+}
+
+/// The vault's open withdrawal tickets and proposals, each followed by its
+/// user, and its fully released vesting schedules, as `close_vault` takes
+/// them. Schedules still vesting are left out and block the close.
+fn open_claims(rpc: &Rpc, mint: &Pubkey) -> anyhow::Result<(Vec<Pubkey>, Vec<Pubkey>)> {
+    let vault_config = client::derive_vault_config(mint).0;
+    let mut refunded = Vec::new();
+    for (address, data) in rpc.vault_accounts(&pda_token_vault::ID, &WithdrawalTicket::DISCRIMINATOR, 8, &vault_config)? {
+        refunded.extend([address, client::decode_withdrawal_ticket(&data)?.user]);
+    }
+    for (address, data) in rpc.vault_accounts(&pda_token_vault::ID, &Proposal::DISCRIMINATOR, 8, &vault_config)? {
+        refunded.extend([address, client::decode_proposal(&data)?.withdrawer]);
+    }
+    let mut schedules = Vec::new();
+    for (address, data) in rpc.vault_accounts(&pda_token_vault::ID, &VestingSchedule::DISCRIMINATOR, 8, &vault_config)? {
+        if client::decode_vesting_schedule(&data)?.is_fully_released() {
+            schedules.push(address);
+        }
+    }
+    Ok((refunded, schedules))
+}
+
+/// The vault's credential mint, if it issues credentials
+fn credential_mint(rpc: &Rpc, mint: &Pubkey) -> anyhow::Result<Option<Pubkey>> {
+    let (address, _) = client::derive_vault_config(mint);
+    let config = require_account(client::fetch_vault_config(rpc, mint)?, "vault config", &address)?;
+    Ok((config.credential_mint != Pubkey::default()).then_some(config.credential_mint))
+}
+
+/// The user's credential account, and an idempotent create paid by `payer`
+fn credential_account(payer: &Pubkey, user: &Pubkey, credential_mint: &Pubkey) -> (Pubkey, Instruction) {
+    let create = create_associated_token_account_idempotent(payer, user, credential_mint, &spl_token_2022::ID);
+    (holder_token_account(user, credential_mint), create)
+}
+
+/// Updates change the limit in place, leaving any credential alone. With a
+/// `credential_mint`, adds create the user's credential account first and
+/// removals burn from it.
+pub fn change_instructions(
+    authority: &Pubkey,
+    mint: &Pubkey,
+    credential_mint: Option<&Pubkey>,
+    change: &Change,
+) -> anyhow::Result<Vec<Instruction>> {
+    if change.record().tier.is_some_and(|t| t != 0) {
+        bail!("{} has a tier, but tiers only exist on the hook vault", change.record().wallet);
+    }
+    let credential = |r: &WhitelistRecord| credential_mint.map(|c| credential_account(authority, &r.wallet, c));
+    let add = |r: &WhitelistRecord| {
+        let credential = credential(r);
+        let credential_account = credential.as_ref().map(|c| &c.0);
+        let add = client::add_to_whitelist(authority, mint, &r.wallet, r.limit, None, credential_account, &spl_token_2022::ID);
+        with_setup(credential.map(|c| c.1), add)
+    };
+    let remove = |r: &WhitelistRecord| {
+        let credential = credential(r).map(|c| c.0);
+        client::remove_from_whitelist(authority, mint, &r.wallet, None, credential.as_ref(), &spl_token_2022::ID)
+    };
+    Ok(match change {
+        Change::Add(r) => add(r),
+        Change::Remove(r) => vec![remove(r)],
+        Change::Update { to, .. } => vec![client::update_whitelist_entry(authority, mint, &to.wallet, to.limit)],
+    })
+}
+
+/// Removal batches for `users`, burning their credentials, followed by
+/// `close_vault` on its own, which also closes `claims` (see `open_claims`);
+/// outstanding credentials would block the close. `token_program` owns `mint`.
+pub fn close_instructions(
+    authority: &Pubkey,
+    mint: &Pubkey,
+    credential_mint: Option<&Pubkey>,
+    users: &[Pubkey],
+    claims: &[Pubkey],
+    batch_size: usize,
+    token_program: &Pubkey,
+) -> Vec<Vec<Instruction>> {
+    let remove = |user: &Pubkey| {
+        let credential = credential_mint.map(|c| holder_token_account(user, c));
+        client::remove_from_whitelist(authority, mint, user, None, credential.as_ref(), &spl_token_2022::ID)
+    };
+    users
+        .chunks(batch_size.max(1))
+        .map(|batch| batch.iter().map(remove).collect())
+        .chain([vec![client::close_vault(authority, mint, credential_mint, claims, token_program)]])
+        .collect()
+}
+
+/// A member mint with the vault addresses derived from it
+fn member_row(mint: &Pubkey) -> Value {
+    json!({
+        "mint": mint.to_string(),
+        "vault_config": client::derive_vault_config(mint).0.to_string(),
+        "vault": client::derive_vault(mint).0.to_string(),
+    })
+}
+
+fn asset_row(asset: &VaultAsset) -> Value {
+    json!({
+        "mint": asset.mint.to_string(),
+        "token_program": asset.token_program.to_string(),
+        "vault": client::derive_multi_vault_account(&asset.multi_vault, &asset.mint).0.to_string(),
+        "max_amount": asset.max_amount,
+    })
+}
+
+fn entry_row(entry: &WhitelistEntry) -> Value {
+    json!({
+        "user": entry.user.to_string(),
+        "whitelisted": true,
+        "backend": "pda",
+        "max_amount": entry.max_amount,
+    })
+}
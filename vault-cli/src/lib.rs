@@ -0,0 +1,10 @@
+//! Admin CLI for the transfer hook and PDA whitelist vaults.
+//!
+//! Commands return a `serde_json::Value`, which `output` renders either as
+//! JSON or as a plain table.
+
+pub mod cli;
+pub mod commands;
+pub mod output;
+pub mod reconcile;
+pub mod rpc;
@@ -0,0 +1,14 @@
+use clap::Parser;
+use vault_cli::{cli::Cli, commands, output};
+
+fn main() {
+    let cli = Cli::parse();
+    match commands::run(&cli) {
+        Ok(value) if value.is_null() => {}
+        Ok(value) => println!("{}", output::render(&value, cli.output)),
+        Err(err) => {
+            eprintln!("error: {err:#}");
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use serde_json::Value;
+use crate::cli::OutputFormat;
+
+pub fn render(value: &Value, format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Json => serde_json::to_string_pretty(value).unwrap_or_default(),
+        OutputFormat::Table => table(value),
+    }
+}
+
+fn cell(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => "-".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Objects print as aligned key/value pairs, arrays of objects as a grid
+/// whose columns come from the first row
+fn table(value: &Value) -> String {
+    match value {
+        Value::Object(map) => {
+            let width = map.keys().map(|k| k.len()).max().unwrap_or(0);
+            map.iter()
+                .map(|(k, v)| format!("{k:<width$}  {}", cell(v)))
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+        Value::Array(rows) if rows.is_empty() => "(none)".to_string(),
+        Value::Array(rows) => {
+            let Some(Value::Object(first)) = rows.first() else {
+                return rows.iter().map(cell).collect::<Vec<_>>().join("\n");
+            };
+            let columns: Vec<&String> = first.keys().collect();
+            let grid: Vec<Vec<String>> = rows
+                .iter()
+                .map(|row| columns.iter().map(|c| cell(&row[c.as_str()])).collect())
+                .collect();
+            let widths: Vec<usize> = columns
+                .iter()
+                .enumerate()
+                .map(|(i, c)| grid.iter().map(|r| r[i].len()).chain([c.len()]).max().unwrap_or(0))
+                .collect();
+            let line = |cells: Vec<String>| {
+                cells.iter()
+                    .zip(&widths)
+                    .map(|(c, w)| format!("{c:<w$}"))
+                    .collect::<Vec<_>>()
+                    .join("  ")
+                    .trim_end()
+                    .to_string()
+            };
+            std::iter::once(line(columns.iter().map(|c| c.to_uppercase()).collect()))
+                .chain(grid.into_iter().map(line))
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+        other => cell(other),
+    }
+}
@@ -0,0 +1,152 @@
+//! Whitelist snapshots in CSV or JSON, and the minimal set of changes needed
+//! to bring an on-chain whitelist in line with one.
+
+use std::collections::{BTreeMap, HashSet};
+use std::io::{Read, Write};
+use std::path::Path;
+use anyhow::{bail, Context};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Csv,
+    Json,
+}
+
+impl Format {
+    /// `explicit` if given, else guessed from the file extension
+    pub fn resolve(explicit: Option<Format>, path: Option<&Path>) -> anyhow::Result<Format> {
+        if let Some(format) = explicit {
+            return Ok(format);
+        }
+        match path.and_then(|p| p.extension()).and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("csv") => Ok(Format::Csv),
+            Some(ext) if ext.eq_ignore_ascii_case("json") => Ok(Format::Json),
+            _ => bail!("can't infer the format; pass --format csv or --format json"),
+        }
+    }
+}
+
+/// One whitelisted wallet. `tier` is hook-only; when a snapshot leaves it
+/// blank, imports keep whatever tier the entry already has.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WhitelistRecord {
+    pub wallet: Pubkey,
+    pub limit: u64,
+    pub tier: Option<u8>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct RawRecord {
+    wallet: String,
+    limit: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tier: Option<u8>,
+}
+
+pub fn read_records(reader: impl Read, format: Format) -> anyhow::Result<Vec<WhitelistRecord>> {
+    let raw: Vec<RawRecord> = match format {
+        Format::Csv => csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_reader(reader)
+            .deserialize()
+            .enumerate()
+            .map(|(i, row)| row.with_context(|| format!("CSV row {}", i + 2)))
+            .collect::<anyhow::Result<_>>()?,
+        Format::Json => serde_json::from_reader(reader).context("parsing JSON")?,
+    };
+    
+    let mut seen = HashSet::new();
+    raw.into_iter()
+        .map(|r| {
+            let wallet: Pubkey = r.wallet.parse().with_context(|| format!("invalid wallet {}", r.wallet))?;
+            if !seen.insert(wallet) {
+                bail!("{wallet} is listed more than once");
+            }
+            Ok(WhitelistRecord { wallet, limit: r.limit, tier: r.tier })
+        })
+        .collect()
+}
+
+pub fn write_records(writer: impl Write, records: &[WhitelistRecord], format: Format) -> anyhow::Result<()> {
+    // Always write the tier column so CSV rows stay rectangular
+    let raw: Vec<RawRecord> = records
+        .iter()
+        .map(|r| RawRecord { wallet: r.wallet.to_string(), limit: r.limit, tier: Some(r.tier.unwrap_or(0)) })
+        .collect();
+    match format {
+        Format::Csv => {
+            let mut csv = csv::Writer::from_writer(writer);
+            for record in &raw {
+                csv.serialize(record)?;
+            }
+            csv.flush()?;
+        }
+        Format::Json => {
+            let mut writer = writer;
+            serde_json::to_writer_pretty(&mut writer, &raw)?;
+            writeln!(writer)?;
+        }
+    }
+    Ok(())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Change {
+    Add(WhitelistRecord),
+    /// Applied on-chain as a remove followed by an add
+    Update { from: WhitelistRecord, to: WhitelistRecord },
+    Remove(WhitelistRecord),
+}
+
+impl Change {
+    pub fn action(&self) -> &'static str {
+        match self {
+            Change::Add(_) => "add",
+            Change::Update { .. } => "update",
+            Change::Remove(_) => "remove",
+        }
+    }
+
+    /// The record as it will be on-chain afterwards (or was, for removals)
+    pub fn record(&self) -> &WhitelistRecord {
+        match self {
+            Change::Add(r) | Change::Remove(r) | Change::Update { to: r, .. } => r,
+        }
+    }
+}
+
+/// Changes turning `current` into `desired`: removals first so they free
+/// capacity, then updates, then additions, each ordered by wallet
+pub fn diff(current: &[WhitelistRecord], desired: &[WhitelistRecord]) -> Vec<Change> {
+    let current: BTreeMap<Pubkey, &WhitelistRecord> = current.iter().map(|r| (r.wallet, r)).collect();
+    let desired: BTreeMap<Pubkey, &WhitelistRecord> = desired.iter().map(|r| (r.wallet, r)).collect();
+    
+    let removes = current
+        .iter()
+        .filter(|(wallet, _)| !desired.contains_key(wallet))
+        .map(|(_, r)| Change::Remove(**r));
+    let mut updates = Vec::new();
+    let mut adds = Vec::new();
+    for (wallet, want) in &desired {
+        match current.get(wallet) {
+            None => adds.push(Change::Add(WhitelistRecord { tier: Some(want.tier.unwrap_or(0)), ..**want })),
+            Some(have) => {
+                let to = WhitelistRecord { tier: want.tier.or(have.tier), ..**want };
+                if to != **have {
+                    updates.push(Change::Update { from: **have, to });
+                }
+            }
+        }
+    }
+    removes.chain(updates).chain(adds).collect()
+}
+
+/// Entry count once `changes` are applied to a whitelist of `len` entries
+pub fn len_after(len: usize, changes: &[Change]) -> usize {
+    let adds = changes.iter().filter(|c| matches!(c, Change::Add(_))).count();
+    let removes = changes.iter().filter(|c| matches!(c, Change::Remove(_))).count();
+    len + adds - removes
+}
@@ -0,0 +1,126 @@
+use anyhow::{anyhow, Context};
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::{
+    rpc_client::RpcClient,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signature, Signer},
+    transaction::Transaction,
+};
+
+/// Both programs' whitelist entry PDAs start with user and max_amount,
+/// so vault_config sits at the same offset in each
+pub const ENTRY_VAULT_CONFIG_OFFSET: usize = 8 + 32 + 8;
+
+/// The hook vault's `Tier` accounts lead with vault_config
+pub const TIER_VAULT_CONFIG_OFFSET: usize = 8;
+
+pub struct Rpc {
+    pub client: RpcClient,
+    pub payer: Keypair,
+}
+
+pub fn expand_home(path: &str) -> String {
+    match (path.strip_prefix("~/"), std::env::var("HOME")) {
+        (Some(rest), Ok(home)) => format!("{home}/{rest}"),
+        _ => path.to_string(),
+    }
+}
+
+pub fn read_keypair(path: &str) -> anyhow::Result<Keypair> {
+    let path = expand_home(path);
+    read_keypair_file(&path).map_err(|e| anyhow!("reading keypair {path}: {e}"))
+}
+
+impl Rpc {
+    pub fn new(url: &str, keypair: &str) -> anyhow::Result<Self> {
+        Ok(Self {
+            client: RpcClient::new_with_commitment(url.to_string(), CommitmentConfig::confirmed()),
+            payer: read_keypair(keypair)?,
+        })
+    }
+
+    pub fn payer(&self) -> Pubkey {
+        self.payer.pubkey()
+    }
+
+    /// Sign with the payer plus `extra_signers` and wait for confirmation
+    pub fn send(&self, instructions: &[Instruction], extra_signers: &[&Keypair]) -> anyhow::Result<Signature> {
+        let blockhash = self.client.get_latest_blockhash().context("fetching blockhash")?;
+        let mut signers: Vec<&Keypair> = vec![&self.payer];
+        signers.extend_from_slice(extra_signers);
+        let tx = Transaction::new_signed_with_payer(instructions, Some(&self.payer()), &signers, blockhash);
+        self.client.send_and_confirm_transaction(&tx).context("sending transaction")
+    }
+
+    pub fn account_data(&self, address: &Pubkey) -> Option<Vec<u8>> {
+        self.client
+            .get_account_with_commitment(address, self.client.commitment())
+            .ok()
+            .and_then(|r| r.value)
+            .map(|a| a.data)
+    }
+
+    /// Program that owns `address`, e.g. the token program behind a mint
+    pub fn account_owner(&self, address: &Pubkey) -> Option<Pubkey> {
+        self.client
+            .get_account_with_commitment(address, self.client.commitment())
+            .ok()
+            .and_then(|r| r.value)
+            .map(|a| a.owner)
+    }
+
+    /// Every whitelist entry PDA of `program_id` that belongs to `vault_config`
+    pub fn entry_pdas(
+        &self,
+        program_id: &Pubkey,
+        discriminator: &[u8],
+        vault_config: &Pubkey,
+    ) -> anyhow::Result<Vec<(Pubkey, Vec<u8>)>> {
+        self.vault_accounts(program_id, discriminator, ENTRY_VAULT_CONFIG_OFFSET, vault_config)
+    }
+
+    /// Every account of `program_id` with `discriminator` whose vault_config,
+    /// stored at `offset`, is `vault_config`
+    pub fn vault_accounts(
+        &self,
+        program_id: &Pubkey,
+        discriminator: &[u8],
+        offset: usize,
+        vault_config: &Pubkey,
+    ) -> anyhow::Result<Vec<(Pubkey, Vec<u8>)>> {
+        let config = RpcProgramAccountsConfig {
+            filters: Some(vec![
+                RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, discriminator.to_vec())),
+                RpcFilterType::Memcmp(Memcmp::new_raw_bytes(offset, vault_config.to_bytes().to_vec())),
+            ]),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let accounts = self
+            .client
+            .get_program_accounts_with_config(program_id, config)
+            .context("listing vault accounts")?;
+        Ok(accounts.into_iter().map(|(k, a)| (k, a.data)).collect())
+    }
+}
+
+impl transfer_hook_vault::client::AccountFetcher for Rpc {
+    fn get_account_data(&self, address: &Pubkey) -> Option<Vec<u8>> {
+        self.account_data(address)
+    }
+}
+
+impl pda_token_vault::client::AccountFetcher for Rpc {
+    fn get_account_data(&self, address: &Pubkey) -> Option<Vec<u8>> {
+        self.account_data(address)
+    }
+}
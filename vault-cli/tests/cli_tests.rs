@@ -0,0 +1,580 @@
+//! Tests for vault-cli argument parsing and output
+
+use clap::Parser;
+use serde_json::json;
+use solana_sdk::pubkey::Pubkey;
+use vault_cli::{
+    cli::{
+        holder_token_account, ApprovalCommand, Backend, Cli, Command, MetadataCommand, MultiVaultCommand, OutputFormat,
+        Program, TimelockCommand, VestingCommand, WhitelistCommand,
+    },
+    output,
+    reconcile::{self, Change, Format, WhitelistRecord},
+};
+
+fn parse(args: &[&str]) -> Cli {
+    Cli::try_parse_from(std::iter::once("vault-cli").chain(args.iter().copied())).unwrap()
+}
+
+#[cfg(test)]
+mod test_parsing {
+    use super::*;
+
+    #[test]
+    fn defaults_to_local_validator_and_hook_vault() {
+        let mint = Pubkey::new_unique();
+        let cli = parse(&["show-config", "--mint", &mint.to_string()]);
+        
+        assert_eq!(cli.url, "http://127.0.0.1:8899");
+        assert_eq!(cli.program, Program::Hook);
+        assert_eq!(cli.output, OutputFormat::Table);
+        assert!(matches!(cli.command, Command::ShowConfig { mint: m } if m == mint));
+    }
+
+    #[test]
+    fn create_mint_maps_every_extension_arg() {
+        let cli = parse(&[
+            "-p", "pda", "create-mint",
+            "--decimals", "6",
+            "--enable-transfer-fee",
+            "--transfer-fee-basis-points", "250",
+            "--max-fee", "1000",
+            "--enable-permanent-delegate",
+            "--enable-default-frozen",
+        ]);
+        let Command::CreateMint(args) = cli.command else { panic!("expected create-mint") };
+        let extension_args = args.extension_args();
+        
+        assert_eq!(args.decimals, 6);
+        assert!(extension_args.enable_transfer_fee);
+        assert_eq!(extension_args.transfer_fee_basis_points, 250);
+        assert_eq!(extension_args.max_fee, 1000);
+        assert!(extension_args.enable_permanent_delegate);
+        assert!(extension_args.enable_default_frozen);
+        assert!(args.has_extensions());
+    }
+
+    #[test]
+    fn default_frozen_is_not_pda_only() {
+        let cli = parse(&["create-mint", "--enable-default-frozen"]);
+        let Command::CreateMint(args) = cli.command else { panic!("expected create-mint") };
+        
+        assert!(args.enable_default_frozen);
+        assert!(!args.has_extensions());
+    }
+
+    #[test]
+    fn interest_rate_is_pda_only_and_may_be_negative() {
+        let cli = parse(&["-p", "pda", "create-mint", "--interest-rate", "-50"]);
+        let Command::CreateMint(args) = cli.command else { panic!("expected create-mint") };
+        let extension_args = args.extension_args();
+        
+        assert!(args.has_extensions());
+        assert!(extension_args.enable_interest_bearing);
+        assert_eq!(extension_args.interest_rate, -50);
+        
+        let mint = Pubkey::new_unique().to_string();
+        let cli = parse(&["set-interest-rate", "--mint", &mint, "--rate", "-10"]);
+        assert!(matches!(cli.command, Command::SetInterestRate { rate: -10, .. }));
+    }
+
+    #[test]
+    fn close_vault_parses_with_mint_close_authority() {
+        let cli = parse(&["-p", "pda", "create-mint", "--enable-mint-close-authority"]);
+        let Command::CreateMint(args) = cli.command else { panic!("expected create-mint") };
+        
+        assert!(args.has_extensions());
+        assert!(args.extension_args().enable_mint_close_authority);
+        
+        let mint = Pubkey::new_unique();
+        let cli = parse(&["close-vault", "--mint", &mint.to_string()]);
+        assert!(matches!(cli.command, Command::CloseVault { mint: m, batch_size: 5 } if m == mint));
+    }
+
+    #[test]
+    fn confidential_flags_build_transfer_args() {
+        let auditor = "BwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwc=";
+        let cli = parse(&["-p", "pda", "create-mint", "--confidential", "--auto-approve", "--auditor-elgamal-pubkey", auditor]);
+        let Command::CreateMint(args) = cli.command else { panic!("expected create-mint") };
+        let confidential = args.extension_args().confidential_transfer.unwrap();
+        
+        assert!(args.has_extensions());
+        assert!(confidential.auto_approve_new_accounts);
+        assert_eq!(confidential.auditor_elgamal_pubkey, Some([7; 32]));
+        assert!(Cli::try_parse_from(["vault-cli", "create-mint", "--auto-approve"]).is_err());
+        assert!(Cli::try_parse_from(["vault-cli", "create-mint", "--confidential", "--auditor-elgamal-pubkey", "AAAA"]).is_err());
+    }
+
+    #[test]
+    fn group_flags_are_pda_only() {
+        let group_mint = Pubkey::new_unique();
+        let cli = parse(&["-p", "pda", "create-mint", "--group-max-size", "8"]);
+        let Command::CreateMint(args) = cli.command else { panic!("expected create-mint") };
+        assert!(args.has_extensions());
+        assert_eq!(args.extension_args().group_max_size, Some(8));
+        
+        let cli = parse(&["-p", "pda", "create-mint", "--group-mint", &group_mint.to_string()]);
+        let Command::CreateMint(args) = cli.command else { panic!("expected create-mint") };
+        assert!(args.has_extensions());
+        assert_eq!(args.group_mint, Some(group_mint));
+        assert!(args.extension_args().group_max_size.is_none());
+        
+        let cli = parse(&["list-group", "--mint", &group_mint.to_string()]);
+        assert!(matches!(cli.command, Command::ListGroup { mint } if mint == group_mint));
+    }
+
+    #[test]
+    fn metadata_flags_are_required_together() {
+        assert!(Cli::try_parse_from(["vault-cli", "create-mint", "--name", "Vault"]).is_err());
+        assert!(Cli::try_parse_from(["vault-cli", "create-mint", "--uri", "https://x"]).is_err());
+        
+        let cli = parse(&["create-mint", "--name", "Vault", "--symbol", "VLT", "--uri", "https://x"]);
+        let Command::CreateMint(args) = cli.command else { panic!("expected create-mint") };
+        let metadata = args.extension_args().metadata.expect("metadata set");
+        assert_eq!((metadata.name.as_str(), metadata.symbol.as_str()), ("Vault", "VLT"));
+        assert!(!args.has_extensions());
+    }
+
+    #[test]
+    fn metadata_remove_parses_idempotent() {
+        let mint = Pubkey::new_unique();
+        let cli = parse(&["metadata", "remove", "--mint", &mint.to_string(), "--key", "region", "--idempotent"]);
+        
+        assert!(matches!(
+            cli.command,
+            Command::Metadata(MetadataCommand::Remove { key, idempotent: true, .. }) if key == "region"
+        ));
+    }
+
+    #[test]
+    fn global_flags_follow_subcommands() {
+        let mint = Pubkey::new_unique();
+        let user = Pubkey::new_unique();
+        let cli = parse(&[
+            "whitelist", "add",
+            "--mint", &mint.to_string(),
+            "--user", &user.to_string(),
+            "--max-amount", "500",
+            "--backend", "pda",
+            "-o", "json",
+        ]);
+        
+        assert_eq!(cli.output, OutputFormat::Json);
+        let Command::Whitelist(WhitelistCommand::Add { max_amount, backend, tier, .. }) = cli.command else {
+            panic!("expected whitelist add");
+        };
+        assert_eq!(max_amount, 500);
+        assert_eq!(tier, 0);
+        assert_eq!(backend, Some(Backend::Pda));
+    }
+
+    #[test]
+    fn freeze_defaults_to_owner_associated_account() {
+        let mint = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let cli = parse(&["freeze", "--mint", &mint.to_string(), "--owner", &owner.to_string()]);
+        let Command::Freeze(args) = cli.command else { panic!("expected freeze") };
+        
+        assert_eq!(args.token_account(&spl_token_2022::ID), holder_token_account(&owner, &mint));
+    }
+
+    #[test]
+    fn freeze_needs_exactly_one_holder() {
+        let mint = Pubkey::new_unique().to_string();
+        let key = Pubkey::new_unique().to_string();
+        
+        assert!(Cli::try_parse_from(["vault-cli", "thaw", "--mint", &mint]).is_err());
+        assert!(Cli::try_parse_from([
+            "vault-cli", "thaw", "--mint", &mint, "--owner", &key, "--token-account", &key,
+        ])
+        .is_err());
+        let cli = parse(&["thaw", "--mint", &mint, "--token-account", &key]);
+        let Command::Thaw(args) = cli.command else { panic!("expected thaw") };
+        assert_eq!(args.token_account(&spl_token_2022::ID).to_string(), key);
+    }
+
+    #[test]
+    fn whitelist_remove_can_freeze() {
+        let mint = Pubkey::new_unique().to_string();
+        let user = Pubkey::new_unique().to_string();
+        let cli = parse(&["whitelist", "remove", "--mint", &mint, "--user", &user, "--freeze"]);
+        
+        assert!(matches!(cli.command, Command::Whitelist(WhitelistCommand::Remove { freeze: true, .. })));
+    }
+
+    #[test]
+    fn whitelist_add_can_thaw() {
+        let mint = Pubkey::new_unique().to_string();
+        let user = Pubkey::new_unique().to_string();
+        let cli = parse(&["whitelist", "add", "--mint", &mint, "--user", &user, "--thaw"]);
+        
+        assert!(matches!(cli.command, Command::Whitelist(WhitelistCommand::Add { thaw: true, .. })));
+    }
+
+    #[test]
+    fn clawback_requires_a_reason() {
+        let mint = Pubkey::new_unique().to_string();
+        let owner = Pubkey::new_unique().to_string();
+        let base = ["vault-cli", "-p", "pda", "clawback", "--mint", &mint, "--owner", &owner, "--amount", "5"];
+        
+        assert!(Cli::try_parse_from(base).is_err());
+        assert!(Cli::try_parse_from(base.iter().chain(&["--reason", "0"])).is_err());
+        let cli = parse(&[&base[1..], &["--reason", "2"]].concat());
+        let Command::Clawback(args) = cli.command else { panic!("expected clawback") };
+        assert_eq!((args.amount, args.reason, args.destination), (5, 2, None));
+    }
+
+    #[test]
+    fn multi_vault_defaults_to_the_signers_first_vault() {
+        let mint = Pubkey::new_unique().to_string();
+        let signer = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let cli = parse(&["-p", "pda", "multi-vault", "add-mint", "--mint", &mint]);
+        let Command::MultiVault(MultiVaultCommand::AddMint { vault, max_amount, .. }) = cli.command else {
+            panic!("expected multi-vault add-mint");
+        };
+        
+        assert_eq!((vault.vault_id, max_amount), (0, 0));
+        assert_eq!(vault.authority(&signer), signer);
+        let cli = parse(&[
+            "multi-vault", "withdraw",
+            "--vault-id", "3",
+            "--authority", &authority.to_string(),
+            "--mint", &mint,
+            "--amount", "7",
+        ]);
+        let Command::MultiVault(MultiVaultCommand::Withdraw { vault, transfer }) = cli.command else {
+            panic!("expected multi-vault withdraw");
+        };
+        assert_eq!((vault.vault_id, vault.authority(&signer)), (3, authority));
+        assert_eq!((transfer.amount, transfer.token_account), (7, None));
+    }
+
+    #[test]
+    fn timelock_guardian_defaults_to_the_signer() {
+        let mint = Pubkey::new_unique().to_string();
+        let cli = parse(&["-p", "pda", "timelock", "set", "--mint", &mint, "--delay", "86400"]);
+        
+        assert!(matches!(
+            cli.command,
+            Command::Timelock(TimelockCommand::Set { delay: 86400, guardian: None, .. })
+        ));
+        assert!(Cli::try_parse_from(["vault-cli", "timelock", "veto", "--mint", &mint]).is_err());
+        let cli = parse(&["timelock", "show", "--mint", &mint]);
+        assert!(matches!(cli.command, Command::Timelock(TimelockCommand::Show { user: None, .. })));
+    }
+
+    #[test]
+    fn vesting_needs_a_duration() {
+        let mint = Pubkey::new_unique().to_string();
+        let beneficiary = Pubkey::new_unique().to_string();
+        let base = [
+            "vault-cli", "-p", "pda", "vesting", "create", "--mint", &mint, "--beneficiary", &beneficiary, "--total", "9",
+        ];
+        
+        assert!(Cli::try_parse_from(base).is_err());
+        let cli = parse(&[&base[1..], &["--duration", "100"]].concat());
+        assert!(matches!(
+            cli.command,
+            Command::Vesting(VestingCommand::Create { schedule_id: 0, total: 9, start: None, cliff: 0, duration: 100, .. })
+        ));
+    }
+
+    #[test]
+    fn approval_set_takes_repeated_approvers() {
+        let mint = Pubkey::new_unique().to_string();
+        let (first, second) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let cli = parse(&[
+            "-p", "pda", "approval", "set",
+            "--mint", &mint,
+            "--approver", &first.to_string(),
+            "--approver", &second.to_string(),
+            "--quorum", "2",
+            "--threshold", "1000",
+        ]);
+        let Command::Approval(ApprovalCommand::Set { approvers, quorum, threshold, .. }) = cli.command else {
+            panic!("expected approval set");
+        };
+        
+        assert_eq!(approvers, vec![first, second]);
+        assert_eq!((quorum, threshold), (2, 1000));
+        assert!(Cli::try_parse_from(["vault-cli", "approval", "approve", "--mint", &mint]).is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_pubkey() {
+        assert!(Cli::try_parse_from(["vault-cli", "init", "--mint", "not-a-key"]).is_err());
+    }
+
+    #[test]
+    fn backend_defaults_per_program() {
+        assert_eq!(Program::Hook.backend(None).unwrap(), Backend::Vec);
+        assert_eq!(Program::Hook.backend(Some(Backend::Pda)).unwrap(), Backend::Pda);
+        assert_eq!(Program::Pda.backend(None).unwrap(), Backend::Pda);
+        assert!(Program::Pda.backend(Some(Backend::Vec)).is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_output {
+    use super::*;
+
+    #[test]
+    fn object_renders_as_aligned_pairs() {
+        let value = json!({ "mint": "abc", "decimals": 9, "merkle_root": null });
+        let table = output::render(&value, OutputFormat::Table);
+        
+        assert_eq!(table, "mint         abc\ndecimals     9\nmerkle_root  -");
+    }
+
+    #[test]
+    fn rows_render_as_grid() {
+        let value = json!([
+            { "user": "alice", "max_amount": 100 },
+            { "user": "bob", "max_amount": 0 },
+        ]);
+        let table = output::render(&value, OutputFormat::Table);
+        
+        assert_eq!(table, "USER   MAX_AMOUNT\nalice  100\nbob    0");
+    }
+
+    #[test]
+    fn empty_list() {
+        assert_eq!(output::render(&json!([]), OutputFormat::Table), "(none)");
+    }
+
+    #[test]
+    fn json_is_parseable() {
+        let value = json!({ "signature": "sig", "amount": 5 });
+        let rendered = output::render(&value, OutputFormat::Json);
+        
+        assert_eq!(serde_json::from_str::<serde_json::Value>(&rendered).unwrap(), value);
+    }
+}
+
+fn record(wallet: Pubkey, limit: u64, tier: Option<u8>) -> WhitelistRecord {
+    WhitelistRecord { wallet, limit, tier }
+}
+
+#[cfg(test)]
+mod test_reconcile_formats {
+    use super::*;
+    use std::path::Path;
+
+    #[test]
+    fn reads_vendor_csv() {
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let csv = format!("wallet,limit\n{a}, 1000\n{b},0\n");
+        let records = reconcile::read_records(csv.as_bytes(), Format::Csv).unwrap();
+        
+        assert_eq!(records, vec![record(a, 1000, None), record(b, 0, None)]);
+    }
+
+    #[test]
+    fn reads_optional_tier_column() {
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let csv = format!("wallet,limit,tier\n{a},5,2\n{b},5,\n");
+        let records = reconcile::read_records(csv.as_bytes(), Format::Csv).unwrap();
+        
+        assert_eq!(records[0].tier, Some(2));
+        assert_eq!(records[1].tier, None);
+    }
+
+    #[test]
+    fn reads_json() {
+        let a = Pubkey::new_unique();
+        let json = format!(r#"[{{"wallet": "{a}", "limit": 7}}]"#);
+        
+        assert_eq!(reconcile::read_records(json.as_bytes(), Format::Json).unwrap(), vec![record(a, 7, None)]);
+    }
+
+    #[test]
+    fn rejects_bad_rows() {
+        let a = Pubkey::new_unique();
+        let duplicate = format!("wallet,limit\n{a},1\n{a},2\n");
+        let bad_wallet = "wallet,limit\nnope,1\n";
+        let bad_limit = format!("wallet,limit\n{a},-1\n");
+        
+        assert!(reconcile::read_records(duplicate.as_bytes(), Format::Csv).is_err());
+        assert!(reconcile::read_records(bad_wallet.as_bytes(), Format::Csv).is_err());
+        assert!(reconcile::read_records(bad_limit.as_bytes(), Format::Csv).is_err());
+    }
+
+    #[test]
+    fn export_round_trips_through_import() {
+        let records = vec![record(Pubkey::new_unique(), 10, Some(1)), record(Pubkey::new_unique(), 0, Some(0))];
+        for format in [Format::Csv, Format::Json] {
+            let mut out = Vec::new();
+            reconcile::write_records(&mut out, &records, format).unwrap();
+            
+            assert_eq!(reconcile::read_records(out.as_slice(), format).unwrap(), records);
+        }
+    }
+
+    #[test]
+    fn format_from_extension() {
+        assert_eq!(Format::resolve(None, Some(Path::new("kyc.CSV"))).unwrap(), Format::Csv);
+        assert_eq!(Format::resolve(None, Some(Path::new("snap.json"))).unwrap(), Format::Json);
+        assert_eq!(Format::resolve(Some(Format::Json), Some(Path::new("kyc.csv"))).unwrap(), Format::Json);
+        assert!(Format::resolve(None, Some(Path::new("kyc.txt"))).is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_reconcile_diff {
+    use super::*;
+
+    #[test]
+    fn identical_snapshot_is_a_no_op() {
+        let current = vec![record(Pubkey::new_unique(), 10, Some(0))];
+        
+        assert!(reconcile::diff(&current, &current).is_empty());
+    }
+
+    #[test]
+    fn minimal_changes_removes_first() {
+        let (keep, change, drop, new) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let current = vec![record(keep, 1, Some(0)), record(change, 1, Some(0)), record(drop, 1, Some(0))];
+        let desired = vec![record(new, 3, None), record(change, 2, None), record(keep, 1, None)];
+        let changes = reconcile::diff(&current, &desired);
+        
+        assert_eq!(changes, vec![
+            Change::Remove(record(drop, 1, Some(0))),
+            Change::Update { from: record(change, 1, Some(0)), to: record(change, 2, Some(0)) },
+            Change::Add(record(new, 3, Some(0))),
+        ]);
+        assert_eq!(reconcile::len_after(current.len(), &changes), 3);
+    }
+
+    #[test]
+    fn blank_tier_keeps_existing_tier() {
+        let wallet = Pubkey::new_unique();
+        let current = vec![record(wallet, 5, Some(2))];
+        
+        assert!(reconcile::diff(&current, &[record(wallet, 5, None)]).is_empty());
+        assert_eq!(
+            reconcile::diff(&current, &[record(wallet, 5, Some(3))]),
+            vec![Change::Update { from: record(wallet, 5, Some(2)), to: record(wallet, 5, Some(3)) }]
+        );
+    }
+
+    #[test]
+    fn empty_snapshot_removes_everything() {
+        let current: Vec<_> = (0..3).map(|_| record(Pubkey::new_unique(), 1, Some(0))).collect();
+        let changes = reconcile::diff(&current, &[]);
+        
+        assert_eq!(changes.len(), 3);
+        assert!(changes.iter().all(|c| matches!(c, Change::Remove(_))));
+    }
+}
+
+#[cfg(test)]
+mod test_import_instructions {
+    use super::*;
+    use vault_cli::commands::{hook, pda};
+
+    #[test]
+    fn hook_update_keeps_the_entry() {
+        let (authority, mint, wallet) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let change = Change::Update { from: record(wallet, 1, Some(0)), to: record(wallet, 2, Some(1)) };
+        
+        assert_eq!(
+            hook::change_instructions(&authority, &mint, Backend::Vec, &change),
+            vec![transfer_hook_vault::client::update_whitelist_entry(&authority, &mint, &wallet, 2, 1)]
+        );
+        assert_eq!(
+            hook::change_instructions(&authority, &mint, Backend::Pda, &change),
+            vec![transfer_hook_vault::client::pda_whitelist_update(&authority, &mint, &wallet, 2, 1)]
+        );
+    }
+
+    #[test]
+    fn large_resizes_are_split_into_steps() {
+        let step = (10 * 1024 / transfer_hook_vault::state::Whitelist::ENTRY_LEN) as u32;
+        
+        assert_eq!(hook::resize_steps(100, 100 + step), vec![100 + step]);
+        assert_eq!(hook::resize_steps(100, 100 + 2 * step + 1), vec![100 + step, 100 + 2 * step, 100 + 2 * step + 1]);
+        assert_eq!(hook::resize_steps(100, 10), vec![10]);
+        assert!(hook::resize_steps(100, 100).is_empty());
+    }
+
+    #[test]
+    fn hook_pda_backend_uses_entry_pdas() {
+        let (authority, mint, wallet) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let ixs = hook::change_instructions(&authority, &mint, Backend::Pda, &Change::Add(record(wallet, 9, Some(1))));
+        
+        assert_eq!(ixs, vec![transfer_hook_vault::client::pda_whitelist_add(&authority, &mint, &wallet, 9, 1, None)]);
+    }
+
+    #[test]
+    fn pda_vault_rejects_tiers() {
+        let (authority, mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        
+        assert!(pda::change_instructions(&authority, &mint, None, &Change::Add(record(Pubkey::new_unique(), 1, Some(2)))).is_err());
+        assert_eq!(
+            pda::change_instructions(&authority, &mint, None, &Change::Add(record(Pubkey::new_unique(), 1, Some(0))))
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn pda_credentials_are_created_before_issuing() {
+        let (authority, mint, wallet, credential_mint) =
+            (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let ixs = pda::change_instructions(&authority, &mint, Some(&credential_mint), &Change::Add(record(wallet, 2, None)))
+            .unwrap();
+        let credential = holder_token_account(&wallet, &credential_mint);
+        
+        assert_eq!(ixs.len(), 2);
+        assert_eq!(ixs[0].program_id, spl_associated_token_account::ID);
+        let add = pda_token_vault::client::add_to_whitelist;
+        let add = add(&authority, &mint, &wallet, 2, None, Some(&credential), &spl_token_2022::ID);
+        assert_eq!(ixs[1], add);
+    }
+
+    #[test]
+    fn pda_update_leaves_the_credential_alone() {
+        let (authority, mint, wallet, credential_mint) =
+            (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let change = Change::Update { from: record(wallet, 1, None), to: record(wallet, 2, None) };
+        
+        assert_eq!(
+            pda::change_instructions(&authority, &mint, Some(&credential_mint), &change).unwrap(),
+            vec![pda_token_vault::client::update_whitelist_entry(&authority, &mint, &wallet, 2)]
+        );
+    }
+
+    #[test]
+    fn close_removes_entries_before_closing_the_vault() {
+        let (authority, mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let users: Vec<_> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        let tier = Pubkey::new_unique();
+        let batches = hook::close_instructions(&authority, &mint, &users, &[tier], 2);
+        
+        assert_eq!(batches.iter().map(Vec::len).collect::<Vec<_>>(), vec![2, 1, 1]);
+        assert_eq!(batches[1][0], transfer_hook_vault::client::pda_whitelist_remove(&authority, &mint, &users[2], None));
+        assert_eq!(batches[2][0], transfer_hook_vault::client::close_vault(&authority, &mint, &[tier]));
+    }
+
+    #[test]
+    fn pda_close_burns_credentials_first() {
+        let (authority, mint, wallet, credential_mint) =
+            (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let claims = [Pubkey::new_unique(), wallet];
+        let batches =
+            pda::close_instructions(&authority, &mint, Some(&credential_mint), &[wallet], &claims, 5, &spl_token_2022::ID);
+        let credential = holder_token_account(&wallet, &credential_mint);
+        let remove = pda_token_vault::client::remove_from_whitelist;
+        let close = pda_token_vault::client::close_vault;
+        
+        assert_eq!(
+            batches,
+            vec![
+                vec![remove(&authority, &mint, &wallet, None, Some(&credential), &spl_token_2022::ID)],
+                vec![close(&authority, &mint, Some(&credential_mint), &claims, &spl_token_2022::ID)],
+            ]
+        );
+    }
+}